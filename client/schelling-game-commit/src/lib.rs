@@ -0,0 +1,62 @@
+//! Vote commits of schelling games for clients
+//! Jurors commit the hash in `Period::Commit` and reveal the choice and the salt in `Period::Vote`
+//! Format of the commits of a game is `SchellingGameShared::game_commit_version`
+
+use codec::Encode;
+pub use schelling_game_shared::types::CommitVersion;
+use schelling_game_shared::vote_commit;
+use sortition_sum_game::types::SumTreeName;
+
+#[cfg(test)]
+mod tests;
+
+/// Commit of the choice of two choice schelling game, `0` is no and `1` is yes
+pub fn two_choice_commit<Hash, AccountId, BlockNumber>(
+	version: CommitVersion,
+	genesis_hash: &Hash,
+	key: &SumTreeName<AccountId, BlockNumber>,
+	juror: &AccountId,
+	choice: u128,
+	salt: &[u8],
+) -> [u8; 32]
+where
+	Hash: Encode,
+	AccountId: Encode,
+	BlockNumber: Encode,
+{
+	vote_commit(version, genesis_hash, key, juror, choice, salt)
+}
+
+/// Commit of the score of score schelling game
+pub fn score_commit<Hash, AccountId, BlockNumber>(
+	version: CommitVersion,
+	genesis_hash: &Hash,
+	key: &SumTreeName<AccountId, BlockNumber>,
+	juror: &AccountId,
+	choice: i64,
+	salt: &[u8],
+) -> [u8; 32]
+where
+	Hash: Encode,
+	AccountId: Encode,
+	BlockNumber: Encode,
+{
+	vote_commit(version, genesis_hash, key, juror, choice, salt)
+}
+
+/// Commit of the index of the choice of multi choice schelling game
+pub fn multi_choice_commit<Hash, AccountId, BlockNumber>(
+	version: CommitVersion,
+	genesis_hash: &Hash,
+	key: &SumTreeName<AccountId, BlockNumber>,
+	juror: &AccountId,
+	choice: u32,
+	salt: &[u8],
+) -> [u8; 32]
+where
+	Hash: Encode,
+	AccountId: Encode,
+	BlockNumber: Encode,
+{
+	vote_commit(version, genesis_hash, key, juror, choice, salt)
+}
@@ -0,0 +1,42 @@
+use crate::*;
+use sp_core::H256;
+
+fn return_key_profile(citizen_id: u64) -> SumTreeName<u64, u64> {
+	SumTreeName::ProfileValidation { citizen_address: citizen_id, block_number: 10 }
+}
+
+#[test]
+fn legacy_commit_test() {
+	let commit =
+		two_choice_commit(CommitVersion::V0, &H256::zero(), &return_key_profile(1), &4, 1, b"salt");
+	assert_eq!(sp_core::hashing::keccak_256(b"1salt"), commit);
+	let commit =
+		score_commit(CommitVersion::V0, &H256::zero(), &return_key_profile(1), &4, -5, b"salt");
+	assert_eq!(sp_core::hashing::keccak_256(b"-5salt"), commit);
+}
+
+#[test]
+fn commit_is_bound_to_juror_game_and_chain_test() {
+	let genesis_hash = H256::repeat_byte(1);
+	let key = return_key_profile(1);
+	let commit = multi_choice_commit(CommitVersion::V1, &genesis_hash, &key, &4, 2, b"salt");
+	let payload = (genesis_hash, key.clone(), 4u64, 2u32, b"salt".to_vec()).encode();
+	assert_eq!(sp_core::hashing::keccak_256(&payload), commit);
+
+	assert_ne!(commit, multi_choice_commit(CommitVersion::V1, &genesis_hash, &key, &5, 2, b"salt"));
+	assert_ne!(
+		commit,
+		multi_choice_commit(
+			CommitVersion::V1,
+			&genesis_hash,
+			&return_key_profile(2),
+			&4,
+			2,
+			b"salt"
+		)
+	);
+	assert_ne!(
+		commit,
+		multi_choice_commit(CommitVersion::V1, &H256::repeat_byte(2), &key, &4, 2, b"salt")
+	);
+}
@@ -21,8 +21,9 @@ impl<T: Config> DepartmentRequiredFund<T> {
 }
 
 impl<T: Config> Pallet<T> {
-	pub(super) fn get_phase_data() -> PhaseData<T> {
-		T::SchellingGameSharedSource::create_phase_data(50, 5, 3, 100, (100, 100))
+	/// Phase data of the game, copied from the game type when the game was created
+	pub(super) fn get_phase_data(key: SumTreeNameType<T>) -> PhaseData<T> {
+		T::SchellingGameSharedSource::get_phase_data_of_game_link(key)
 	}
 
 	pub fn ensure_validation_to_do(
@@ -158,3 +159,78 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 }
+
+impl<T: Config> OnGameVoided<SumTreeNameType<T>> for Pallet<T> {
+	/// Remove the block of the voided game and the `Processing` status of the department
+	/// The staking period of the department can be applied again, the game did not decide on the fund
+	fn on_game_voided(key: SumTreeNameType<T>) {
+		let (department_required_fund_id, block_number) = match key {
+			SumTreeName::DepartmentRequiredFund { department_required_fund_id, block_number } =>
+				(department_required_fund_id, block_number),
+			_ => return,
+		};
+		if <ValidationDepartmentRequiredFundsBlock<T>>::get(department_required_fund_id) !=
+			Some(block_number)
+		{
+			return
+		}
+		let department_id = match Self::get_department_id_from_department_required_fund_id(
+			department_required_fund_id,
+		) {
+			Ok(department_id) => department_id,
+			Err(_) => return,
+		};
+		<ValidationDepartmentRequiredFundsBlock<T>>::remove(department_required_fund_id);
+		let is_processing = DepartmentFundingStatusForDepartmentId::<T>::get(department_id)
+			.map_or(false, |funding_status| funding_status.status == FundingStatus::Processing);
+		if is_processing {
+			DepartmentFundingStatusForDepartmentId::<T>::remove(department_id);
+		}
+		Self::deposit_event(Event::FundingValidationVoided {
+			department_required_fund_id,
+			department_id,
+		});
+	}
+}
+
+impl<T: Config> OnGameResolved<SumTreeNameType<T>, SchellingGameType, GameOutcome> for Pallet<T> {
+	/// Funding status of the department is `Success` when the jurors approved the required fund, otherwise `Failed`
+	fn on_game_resolved(
+		key: SumTreeNameType<T>,
+		_game_type: SchellingGameType,
+		outcome: GameOutcome,
+	) {
+		let (department_required_fund_id, block_number) = match key {
+			SumTreeName::DepartmentRequiredFund { department_required_fund_id, block_number } =>
+				(department_required_fund_id, block_number),
+			_ => return,
+		};
+		let validation_block =
+			<ValidationDepartmentRequiredFundsBlock<T>>::get(department_required_fund_id);
+		let decision = match outcome {
+			GameOutcome::TwoChoice(decision) if validation_block == Some(block_number) => decision,
+			_ => return,
+		};
+		let department_id = match Self::get_department_id_from_department_required_fund_id(
+			department_required_fund_id,
+		) {
+			Ok(department_id) => department_id,
+			Err(_) => return,
+		};
+		let status = if decision == WinningDecision::WinnerYes {
+			FundingStatus::Success
+		} else {
+			FundingStatus::Failed
+		};
+		let now = <frame_system::Pallet<T>>::block_number();
+		DepartmentFundingStatusForDepartmentId::<T>::insert(
+			department_id,
+			DepartmentFundingStatus { block_number: now, status },
+		);
+		Self::deposit_event(Event::FundingStatusChanged {
+			department_required_fund_id,
+			department_id,
+			status,
+		});
+	}
+}
@@ -28,15 +28,19 @@ use frame_support::{
 	ensure,
 };
 use frame_support::{
-	traits::{Currency, ExistenceRequirement, Get, ReservableCurrency, WithdrawReasons},
+	traits::{
+		Currency, EnsureOrigin, ExistenceRequirement, Get, ReservableCurrency, WithdrawReasons,
+	},
 	PalletId,
 };
 use pallet_support::{
 	ensure_content_is_valid, new_who_and_when, remove_from_vec, Content, PositiveExternalityPostId,
 	WhoAndWhen, WhoAndWhenOf,
 };
-use schelling_game_shared::types::{Period, PhaseData, RangePoint, SchellingGameType};
-use schelling_game_shared_link::SchellingGameSharedLink;
+use schelling_game_shared::types::{
+	CourtId, GameOutcome, Period, PhaseData, RangePoint, SchellingGameType, WinningDecision,
+};
+use schelling_game_shared_link::{OnGameResolved, OnGameVoided, SchellingGameSharedLink};
 use shared_storage_link::SharedStorageLink;
 use sortition_sum_game::types::SumTreeName;
 pub use types::DEPARTMENT_REQUIRED_FUND_ID;
@@ -136,6 +140,11 @@ pub mod pallet {
 		DepartmentFundingStatus<BlockNumberOf<T>, FundingStatus>,
 	>;
 
+	/// Court of the department, jurors of the department required funds are drawn from the court and its ancestors
+	#[pallet::storage]
+	#[pallet::getter(fn department_court)]
+	pub type DepartmentCourts<T: Config> = StorageMap<_, Twox64Concat, DepartmentId, CourtId>;
+
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
 	#[pallet::event]
@@ -157,6 +166,19 @@ pub mod pallet {
 			block_number: BlockNumberOf<T>,
 			account: T::AccountId,
 		},
+		/// Jurors of the department required funds of the department are drawn from the court
+		DepartmentCourtSet { department_id: DepartmentId, court_id: CourtId },
+		/// Schelling game of the department required fund was resolved
+		FundingStatusChanged {
+			department_required_fund_id: DepartmentRequiredFundId,
+			department_id: DepartmentId,
+			status: FundingStatus,
+		},
+		/// Schelling game of the department required fund was voided, the staking period can be applied again
+		FundingValidationVoided {
+			department_required_fund_id: DepartmentRequiredFundId,
+			department_id: DepartmentId,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -176,6 +198,7 @@ pub mod pallet {
 		FundingStatusProcessing,
 		ReapplicationTimeNotReached,
 		ConditionDontMatch,
+		NotAllowedToAppeal,
 	}
 
 	// Check deparment exists, it will done using loose coupling
@@ -247,8 +270,18 @@ pub mod pallet {
 				department_required_fund_id,
 				now.clone(),
 			);
-			T::SchellingGameSharedSource::set_to_staking_period_pe_link(key.clone(), now.clone())?;
-			T::SchellingGameSharedSource::create_tree_helper_link(key, 3)?;
+			T::SchellingGameSharedSource::set_to_staking_period_pe_link(
+				key.clone(),
+				SchellingGameType::DepartmentScore,
+				now.clone(),
+			)?;
+			// Departments with a court draw the jurors staked in the court, others draw the jurors who applied
+			match DepartmentCourts::<T>::get(department_id) {
+				Some(court_id) => {
+					T::SchellingGameSharedSource::set_court_of_game_link(key, court_id)?
+				},
+				None => T::SchellingGameSharedSource::create_tree_helper_link(key, 3)?,
+			}
 
 			Self::deposit_event(Event::StakingPeriodStarted {
 				department_required_fund_id,
@@ -298,7 +331,7 @@ pub mod pallet {
 				block_number: block_number.clone(),
 			};
 
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 
 			T::SchellingGameSharedSource::apply_jurors_helper_link(
 				key,
@@ -332,7 +365,7 @@ pub mod pallet {
 			};
 
 			let now = <frame_system::Pallet<T>>::block_number();
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 			T::SchellingGameSharedSource::change_period_link(key, phase_data, now)?;
 			Ok(())
 		}
@@ -354,7 +387,7 @@ pub mod pallet {
 				block_number: block_number.clone(),
 			};
 
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 
 			T::SchellingGameSharedSource::draw_jurors_helper_link(key, phase_data, iterations)?;
 
@@ -437,11 +470,51 @@ pub mod pallet {
 				block_number: block_number.clone(),
 			};
 
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 			T::SchellingGameSharedSource::get_incentives_two_choice_helper_link(
 				key, phase_data, who,
 			)?;
 			Ok(())
 		}
+
+		/// Appeal the decision of the jurors
+		/// Only the creator of the department required fund can appeal
+		#[pallet::call_index(9)]
+		#[pallet::weight(0)]
+		pub fn appeal(
+			origin: OriginFor<T>,
+			department_required_fund_id: DepartmentRequiredFundId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let department_required_fund =
+				DepartmentRequiredFunds::<T>::get(department_required_fund_id)
+					.ok_or(Error::<T>::DepartmentRequiredFundDontExits)?;
+			ensure!(department_required_fund.creator == who, Error::<T>::NotAllowedToAppeal);
+			let block_number =
+				Self::get_block_number_of_schelling_game(department_required_fund_id)?;
+			let key = SumTreeName::DepartmentRequiredFund {
+				department_required_fund_id,
+				block_number: block_number.clone(),
+			};
+			let now = <frame_system::Pallet<T>>::block_number();
+			let phase_data = Self::get_phase_data(key.clone());
+			T::SchellingGameSharedSource::appeal_helper_link(key, phase_data, who, now)?;
+			Ok(())
+		}
+
+		/// Draw the jurors of the department required funds of the department from the court
+		/// Games that already started keep drawing the jurors who applied
+		#[pallet::call_index(10)]
+		#[pallet::weight(0)]
+		pub fn set_department_court(
+			origin: OriginFor<T>,
+			department_id: DepartmentId,
+			court_id: CourtId,
+		) -> DispatchResult {
+			<T as schelling_game_shared::Config>::PhaseDataOrigin::ensure_origin(origin)?;
+			DepartmentCourts::<T>::insert(department_id, court_id);
+			Self::deposit_event(Event::DepartmentCourtSet { department_id, court_id });
+			Ok(())
+		}
 	}
 }
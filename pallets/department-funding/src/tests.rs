@@ -1,5 +1,13 @@
-use crate::{mock::*, Error, Event};
+use crate::{
+	mock::*,
+	types::{FundingStatus, TippingName},
+	Error, Event, ValidateDepartmentRequiredFund,
+};
 use frame_support::{assert_noop, assert_ok};
+use pallet_support::Content;
+use schelling_game_shared::types::PhaseData;
+use sortition_sum_game::types::SumTreeName;
+use sp_runtime::traits::BadOrigin;
 
 #[test]
 fn it_works_for_default_value() {
@@ -18,3 +26,132 @@ fn correct_error_for_none_value() {
 		
 	});
 }
+
+#[test]
+fn department_court_draws_jurors_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let phase_data = PhaseData::<Test>::create_with_data(50, 5, 3, 100, (100, 100));
+		assert_ok!(SchellingGameShared::create_court(
+			RuntimeOrigin::root(),
+			1,
+			None,
+			100,
+			phase_data
+		));
+		assert_noop!(
+			DepartmentFunding::set_department_court(RuntimeOrigin::signed(1), 0, 1),
+			BadOrigin
+		);
+		assert_ok!(DepartmentFunding::set_department_court(RuntimeOrigin::root(), 0, 1));
+		System::assert_last_event(
+			Event::DepartmentCourtSet { department_id: 0, court_id: 1 }.into(),
+		);
+
+		assert_ok!(DepartmentFunding::create_department_required_fund(
+			RuntimeOrigin::signed(2),
+			0,
+			TippingName::SmallTipper,
+			5000
+		));
+		ValidateDepartmentRequiredFund::<Test>::insert(1, true);
+		assert_ok!(DepartmentFunding::apply_staking_period(RuntimeOrigin::signed(2), 1));
+		let key =
+			SumTreeName::DepartmentRequiredFund { department_required_fund_id: 1, block_number: 1 };
+		assert_eq!(Some(1), SchellingGameShared::game_court(key));
+	});
+}
+
+#[test]
+fn voided_game_lets_department_apply_staking_period_again_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(DepartmentFunding::create_department_required_fund(
+			RuntimeOrigin::signed(2),
+			0,
+			TippingName::SmallTipper,
+			5000
+		));
+		ValidateDepartmentRequiredFund::<Test>::insert(1, true);
+		assert_ok!(DepartmentFunding::apply_staking_period(RuntimeOrigin::signed(2), 1));
+		assert_eq!(
+			Some(FundingStatus::Processing),
+			DepartmentFunding::department_funding_status(0).map(|funding| funding.status)
+		);
+		let key =
+			SumTreeName::DepartmentRequiredFund { department_required_fund_id: 1, block_number: 1 };
+		let phase_data = DepartmentFunding::get_phase_data(key.clone());
+
+		// No juror applied, the game is voided after the drawing deadline
+		System::set_block_number(1 + phase_data.staking_length);
+		assert_ok!(DepartmentFunding::pass_period(RuntimeOrigin::signed(5), 1));
+		let now = 1 + phase_data.staking_length + phase_data.drawing_length;
+		System::set_block_number(now);
+		assert_ok!(DepartmentFunding::pass_period(RuntimeOrigin::signed(5), 1));
+		System::assert_has_event(
+			<schelling_game_shared::Event<Test>>::GameVoided { key: key.clone() }.into(),
+		);
+		System::assert_has_event(
+			Event::FundingValidationVoided { department_required_fund_id: 1, department_id: 0 }
+				.into(),
+		);
+		assert_eq!(None, DepartmentFunding::department_funding_status(0));
+		assert_eq!(None, DepartmentFunding::validation_department_required_funds_block_number(1));
+
+		// Status is not `Processing` anymore, the staking period starts again
+		assert_ok!(DepartmentFunding::apply_staking_period(RuntimeOrigin::signed(2), 1));
+		assert_eq!(
+			Some(now),
+			DepartmentFunding::validation_department_required_funds_block_number(1)
+		);
+	});
+}
+
+#[test]
+fn submit_evidence_of_department_required_fund_through_shared_game_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		assert_ok!(DepartmentFunding::create_department_required_fund(
+			RuntimeOrigin::signed(2),
+			0,
+			TippingName::SmallTipper,
+			5000
+		));
+		ValidateDepartmentRequiredFund::<Test>::insert(1, true);
+		assert_ok!(DepartmentFunding::apply_staking_period(RuntimeOrigin::signed(2), 1));
+		let key =
+			SumTreeName::DepartmentRequiredFund { department_required_fund_id: 1, block_number: 1 };
+		let phase_data = DepartmentFunding::get_phase_data(key.clone());
+
+		// `EvidenceDeposit` is 100
+		assert_ok!(SchellingGameShared::submit_evidence(
+			RuntimeOrigin::signed(5),
+			key.clone(),
+			content.clone(),
+			None
+		));
+		assert_eq!(100, Balances::reserved_balance(5));
+		let evidence = SchellingGameShared::get_game_evidence(key.clone(), 0, 10);
+		assert_eq!(vec![1], evidence.iter().map(|(id, _)| *id).collect::<Vec<_>>());
+		assert_eq!(5, evidence[0].1.submitter);
+		assert_eq!(content, evidence[0].1.content);
+
+		// Evidence is closed after the staking period
+		System::set_block_number(1 + phase_data.staking_length);
+		assert_ok!(DepartmentFunding::pass_period(RuntimeOrigin::signed(5), 1));
+		assert_noop!(
+			SchellingGameShared::submit_evidence(
+				RuntimeOrigin::signed(5),
+				key.clone(),
+				content.clone(),
+				None
+			),
+			<schelling_game_shared::Error<Test>>::PeriodDontMatch
+		);
+	});
+}
@@ -2,34 +2,11 @@ use frame_support::dispatch::DispatchResult;
 
 use super::*;
 
-impl<T: Config> PositiveExternalityPost<T> {
-	pub fn new(id: PositiveExternalityPostId, created_by: T::AccountId, content: Content) -> Self {
-		PositiveExternalityPost {
-			id,
-			created: new_who_and_when::<T>(created_by.clone()),
-			edited: false,
-			owner: created_by,
-			content,
-			hidden: false,
-			upvotes_count: 0,
-			downvotes_count: 0,
-		}
-	}
-
-	pub fn ensure_owner(&self, account: &T::AccountId) -> DispatchResult {
-		ensure!(self.is_owner(account), Error::<T>::NotAPostOwner);
-		Ok(())
-	}
-
-	pub fn is_owner(&self, account: &T::AccountId) -> bool {
-		self.owner == *account
-	}
-}
-
 impl<T: Config> Pallet<T> {
 
-	pub(super) fn get_phase_data() -> PhaseData<T> {
-		T::SchellingGameSharedSource::create_phase_data(50, 5, 3, 100, (100, 100))
+	/// Phase data of the game, copied from the game type when the game was created
+	pub(super) fn get_phase_data(key: SumTreeNameType<T>) -> PhaseData<T> {
+		T::SchellingGameSharedSource::get_phase_data_of_game_link(key)
 	}
 
 	pub fn ensure_validation_on_positive_externality(account: T::AccountId) -> DispatchResult {
@@ -69,3 +46,46 @@ impl<T: Config> Pallet<T> {
 		T::SchellingGameSharedSource::get_drawn_jurors(key)
 	}
 }
+
+impl<T: Config> OnGameVoided<SumTreeNameType<T>> for Pallet<T> {
+	/// Score of the user is not changed, the game of the period can not be applied again
+	/// Stake of the user is kept for the game of the next period
+	fn on_game_voided(key: SumTreeNameType<T>) {
+		let (user_address, block_number) = match key {
+			SumTreeName::PositiveExternality { user_address, block_number } =>
+				(user_address, block_number),
+			_ => return,
+		};
+		if <ValidationPositiveExternalityBlock<T>>::get(&user_address) == block_number {
+			Self::deposit_event(Event::PositiveExternalityGameVoided {
+				user: user_address,
+				block_number,
+			});
+		}
+	}
+}
+
+impl<T: Config> OnGameResolved<SumTreeNameType<T>, SchellingGameType, GameOutcome> for Pallet<T> {
+	/// Set the score of the schelling game as the positive externality score of the user
+	fn on_game_resolved(
+		key: SumTreeNameType<T>,
+		_game_type: SchellingGameType,
+		outcome: GameOutcome,
+	) {
+		let (user_address, block_number) = match key {
+			SumTreeName::PositiveExternality { user_address, block_number } =>
+				(user_address, block_number),
+			_ => return,
+		};
+		let validation_block = <ValidationPositiveExternalityBlock<T>>::get(&user_address);
+		let score = match outcome {
+			GameOutcome::Score(score) if validation_block == block_number => score,
+			_ => return,
+		};
+		if T::SharedStorageSource::set_positive_externality_link(user_address.clone(), score)
+			.is_ok()
+		{
+			Self::deposit_event(Event::PositiveExternalityScoreSet { user: user_address, score });
+		}
+	}
+}
@@ -17,8 +17,7 @@ pub mod weights;
 pub use weights::*;
 
 mod extras;
-pub mod types;
-pub use types::{PositiveExternalityPost, FIRST_POST_ID};
+pub mod migrations;
 
 use frame_support::sp_runtime::traits::Saturating;
 use frame_support::sp_runtime::SaturatedConversion;
@@ -31,18 +30,16 @@ use frame_support::{
 	traits::{Currency, ExistenceRequirement, Get, ReservableCurrency, WithdrawReasons},
 	PalletId,
 };
-use pallet_support::{
-	ensure_content_is_valid, new_who_and_when, remove_from_vec, Content, PositiveExternalityPostId,
-	WhoAndWhen, WhoAndWhenOf,
-};
-use schelling_game_shared::types::{Period, RangePoint, SchellingGameType, PhaseData};
-use schelling_game_shared_link::SchellingGameSharedLink;
+use pallet_support::{Content, PositiveExternalityPostId, WhoAndWhenOf};
+use schelling_game_shared::types::{GameOutcome, Period, PhaseData, RangePoint, SchellingGameType};
+use schelling_game_shared_link::{OnGameResolved, OnGameVoided, SchellingGameSharedLink};
 use shared_storage_link::SharedStorageLink;
 use sortition_sum_game::types::SumTreeName;
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
 pub type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
 pub type SumTreeNameType<T> = SumTreeName<AccountIdOf<T>, BlockNumberOf<T>>;
+type EvidenceId = u64;
 
 
 #[frame_support::pallet(dev_mode)]
@@ -51,7 +48,11 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// Evidence of the games is kept in the shared schelling game pallet since version 1
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -73,6 +74,7 @@ pub mod pallet {
 			RangePoint = RangePoint,
 			Period = Period,
 			PhaseData = PhaseData<Self>,
+			Content = Content,
 		>;
 		type Currency: ReservableCurrency<Self::AccountId>;
 	}
@@ -85,32 +87,6 @@ pub mod pallet {
 	// https://docs.substrate.io/main-docs/build/runtime-storage/#declaring-storage-items
 	pub type Something<T> = StorageValue<_, u32>;
 
-	#[pallet::type_value]
-	pub fn DefaultForNextPositiveExternalityPostId() -> PositiveExternalityPostId {
-		FIRST_POST_ID
-	}
-
-	/// The next post id.
-	#[pallet::storage]
-	#[pallet::getter(fn next_positive_externality_post_id)]
-	pub type NextPositiveExternalityPostId<T: Config> = StorageValue<
-		_,
-		PositiveExternalityPostId,
-		ValueQuery,
-		DefaultForNextPositiveExternalityPostId,
-	>;
-
-	/// Get the details of a post by its' id.
-	#[pallet::storage]
-	#[pallet::getter(fn positive_externality_post_by_id)]
-	pub type PositiveExternalityPostById<T: Config> =
-		StorageMap<_, Twox64Concat, PositiveExternalityPostId, PositiveExternalityPost<T>>;
-
-	#[pallet::storage]
-	#[pallet::getter(fn positive_externality_evidence)]
-	pub type PositiveExternalityEvidence<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, Vec<PositiveExternalityPostId>, ValueQuery>;
-
 	#[pallet::type_value]
 	pub fn MinimumPositiveExternalityStake<T: Config>() -> BalanceOf<T> {
 		10000u128.saturated_into::<BalanceOf<T>>()
@@ -140,6 +116,10 @@ pub mod pallet {
 		/// Event documentation should end with an array that provides descriptive names for event
 		/// parameters. [something, who]
 		SomethingStored { something: u32, who: T::AccountId },
+		/// Schelling game of the user was resolved, the score is set in shared storage
+		PositiveExternalityScoreSet { user: T::AccountId, score: i64 },
+		/// Schelling game of the user was voided, the score is kept till the game of the next period
+		PositiveExternalityGameVoided { user: T::AccountId, block_number: BlockNumberOf<T> },
 	}
 
 	// Errors inform users that something went wrong.
@@ -149,7 +129,6 @@ pub mod pallet {
 		NoneValue,
 		/// Errors should have helpful documentation associated with them.
 		StorageOverflow,
-		NotAPostOwner,
 		ValidationPositiveExternalityIsOff,
 		LessThanMinStake,
 		CannotStakeNow,
@@ -162,32 +141,25 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 
+		/// Submit evidence for the game of the user, or a reply to an evidence of the game
+		/// Evidence and replies are kept in the shared schelling game pallet
 		#[pallet::call_index(0)]
 		#[pallet::weight(0)]
-		pub fn create_positive_externality_post(
+		pub fn submit_evidence(
 			origin: OriginFor<T>,
+			user_to_calculate: T::AccountId,
 			content: Content,
+			reply_to: Option<EvidenceId>,
 		) -> DispatchResult {
-			let creator = ensure_signed(origin)?;
-
-			ensure_content_is_valid(content.clone())?;
-			T::SharedStorageSource::check_citizen_is_approved_link(creator.clone())?;
-
-			let new_post_id = Self::next_positive_externality_post_id();
-
-			let new_post: PositiveExternalityPost<T> =
-				PositiveExternalityPost::new(new_post_id, creator.clone(), content.clone());
-
-			PositiveExternalityEvidence::<T>::mutate(creator, |ids| ids.push(new_post_id));
-
-			PositiveExternalityPostById::insert(new_post_id, new_post);
-			NextPositiveExternalityPostId::<T>::mutate(|n| {
-				*n += 1;
-			});
+			let who = ensure_signed(origin)?;
+			let pe_block_number = <ValidationPositiveExternalityBlock<T>>::get(&user_to_calculate);
 
-			// emit event
+			let key = SumTreeName::PositiveExternality {
+				user_address: user_to_calculate,
+				block_number: pe_block_number,
+			};
 
-			Ok(())
+			T::SchellingGameSharedSource::submit_evidence_link(key, who, content, reply_to)
 		}
 
 		#[pallet::call_index(1)]
@@ -262,7 +234,12 @@ pub mod pallet {
 					storage_main_block,
 				);
 				// check what if called again
-				T::SchellingGameSharedSource::set_to_staking_period_pe_link(key.clone(), now)?;
+				T::SchellingGameSharedSource::set_to_staking_period_pe_link(
+					key.clone(),
+					SchellingGameType::PositiveExternality,
+					now,
+				)?;
+				T::SchellingGameSharedSource::set_score_game_link(key.clone())?;
 				T::SchellingGameSharedSource::create_tree_helper_link(key, 3)?;
 
 			//  println!("{:?}", data);
@@ -293,7 +270,7 @@ pub mod pallet {
 				block_number: pe_block_number.clone(),
 			};
 
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 
 			T::SchellingGameSharedSource::apply_jurors_helper_link(key, phase_data, who, stake)?;
 
@@ -317,7 +294,7 @@ pub mod pallet {
 			};
 
 			let now = <frame_system::Pallet<T>>::block_number();
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 			T::SchellingGameSharedSource::change_period_link(key, phase_data, now)?;
 
 			Ok(())
@@ -340,7 +317,7 @@ pub mod pallet {
 				block_number: pe_block_number.clone(),
 			};
 
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 
 			T::SchellingGameSharedSource::draw_jurors_helper_link(key, phase_data, iterations)?;
 
@@ -425,7 +402,7 @@ pub mod pallet {
 				block_number: pe_block_number.clone(),
 			};
 
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 			T::SchellingGameSharedSource::get_incentives_score_schelling_helper_link(
 				key.clone(),
 				phase_data,
@@ -0,0 +1,72 @@
+use crate::*;
+use frame_support::pallet_prelude::*;
+
+/// Posts of the users kept in the pallet before version 1, evidence of the games is kept in the shared schelling game
+/// pallet since
+pub mod v0 {
+	use super::*;
+	use scale_info::TypeInfo;
+
+	#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct PositiveExternalityPost<T: Config> {
+		pub id: PositiveExternalityPostId,
+		pub created: WhoAndWhenOf<T>,
+		pub edited: bool,
+		pub owner: T::AccountId,
+		pub content: Content,
+		pub hidden: bool,
+		pub upvotes_count: u32,
+		pub downvotes_count: u32,
+	}
+
+	#[frame_support::storage_alias]
+	pub type NextPositiveExternalityPostId<T: Config> =
+		StorageValue<Pallet<T>, PositiveExternalityPostId>;
+
+	#[frame_support::storage_alias]
+	pub type PositiveExternalityPostById<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, PositiveExternalityPostId, PositiveExternalityPost<T>>;
+
+	#[frame_support::storage_alias]
+	pub type PositiveExternalityEvidence<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, Vec<PositiveExternalityPostId>>;
+}
+
+/// Remove the posts kept in the pallet, no deposit was reserved for them and they were not part of a game
+pub mod v1 {
+	use super::*;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version >= 1 {
+				return T::DbWeight::get().reads(1)
+			}
+			let mut removed = 1u64;
+			removed += v0::PositiveExternalityPostById::<T>::clear(u32::MAX, None).unique as u64;
+			removed += v0::PositiveExternalityEvidence::<T>::clear(u32::MAX, None).unique as u64;
+			v0::NextPositiveExternalityPostId::<T>::kill();
+			StorageVersion::new(1).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(removed, removed.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			ensure!(
+				v0::PositiveExternalityPostById::<T>::iter_keys().next().is_none() &&
+					v0::PositiveExternalityEvidence::<T>::iter_keys().next().is_none() &&
+					!v0::NextPositiveExternalityPostId::<T>::exists(),
+				"Posts are left in the pallet"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == StorageVersion::new(1),
+				"Storage version is not updated"
+			);
+			Ok(())
+		}
+	}
+}
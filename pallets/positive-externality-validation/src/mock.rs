@@ -1,9 +1,11 @@
 use crate as pallet_template;
+use schelling_game_shared::types::CommitVersion;
 use frame_support::{parameter_types, traits::{ConstU16, ConstU64, GenesisBuild}};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	Perbill,
 };
 use frame_support_test::TestRandomness;
 
@@ -87,14 +89,37 @@ impl pallet_balances::Config for Test {
 	type AccountStore = System;
 }
 
+parameter_types! {
+	pub const NonCommitSlashFraction: Perbill = Perbill::from_percent(50);
+	pub const NonRevealSlashFraction: Perbill = Perbill::from_percent(50);
+	pub const MaxPeriodChangesPerBlock: u32 = 10;
+	pub const MaxVoteRemovalsPerBlock: u32 = 100;
+	pub const VoteCommitVersion: CommitVersion = CommitVersion::V0;
+	pub const EvidenceDeposit: u64 = 100;
+	pub const MaxEvidencePerGame: u32 = 100;
+	pub const MaxDelegatorsPerJuror: u32 = 100;
+}
+
 impl schelling_game_shared::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type Currency = Balances; // New code
 	type RandomnessSource = TestRandomness<Self>;
+	type DrawRandomness = schelling_game_shared::JurorBeacon<Self>;
+	type ReputationMultiplier = ();
+	type OnGameVoided = TemplateModule;
+	type OnGameResolved = TemplateModule;
 	type Slash = ();
-	type Reward = ();
 	type SortitionSumGameSource = SortitionSumGame;
+	type NonCommitSlashFraction = NonCommitSlashFraction;
+	type NonRevealSlashFraction = NonRevealSlashFraction;
+	type MaxPeriodChangesPerBlock = MaxPeriodChangesPerBlock;
+	type MaxVoteRemovalsPerBlock = MaxVoteRemovalsPerBlock;
+	type PhaseDataOrigin = frame_system::EnsureRoot<u64>;
+	type VoteCommitVersion = VoteCommitVersion;
+	type EvidenceDeposit = EvidenceDeposit;
+	type MaxEvidencePerGame = MaxEvidencePerGame;
+	type MaxDelegatorsPerJuror = MaxDelegatorsPerJuror;
 }
 
 impl sortition_sum_game::Config for Test {
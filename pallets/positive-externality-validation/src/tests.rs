@@ -1,17 +1,70 @@
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	migrations::{v0, v1::MigrateToV1},
+	mock::*,
+	Error, Event,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
 use pallet_support::{Content, WhoAndWhen};
-use crate::types::PositiveExternalityPost;
+use sortition_sum_game::types::SumTreeName;
 
 #[test]
-fn test_positive_externality_post() {
+fn submit_evidence_of_positive_externality_test() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(TemplateModule::create_positive_externality_post(
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		assert_ok!(TemplateModule::set_validate_positive_externality(RuntimeOrigin::signed(1), true));
+		assert_ok!(TemplateModule::add_positive_externality_stake(RuntimeOrigin::signed(1), 10000));
+		System::set_block_number(1298000);
+		assert_noop!(
+			TemplateModule::submit_evidence(RuntimeOrigin::signed(1), 1, content.clone(), None),
+			<schelling_game_shared::Error<Test>>::PeriodDoesNotExists
+		);
+		assert_ok!(TemplateModule::apply_staking_period(RuntimeOrigin::signed(2), 1));
+		let key = SumTreeName::PositiveExternality { user_address: 1, block_number: 1296000 };
+
+		// Evidence is kept in the shared schelling game pallet, `EvidenceDeposit` is 100
+		assert_ok!(TemplateModule::submit_evidence(
 			RuntimeOrigin::signed(1),
-			Content::None
+			1,
+			content.clone(),
+			None
+		));
+		assert_eq!(100, Balances::reserved_balance(1));
+		System::assert_last_event(
+			<schelling_game_shared::Event<Test>>::EvidenceSubmitted {
+				key: key.clone(),
+				evidence_id: 1,
+				submitter: 1,
+				reply_to: None,
+			}
+			.into(),
+		);
+		assert_ok!(TemplateModule::submit_evidence(
+			RuntimeOrigin::signed(4),
+			1,
+			content.clone(),
+			Some(1)
 		));
-		let post = TemplateModule::positive_externality_post_by_id(1);
-		let post_compare = Some(PositiveExternalityPost {
+		let evidence = SchellingGameShared::get_game_evidence(key.clone(), 0, 10);
+		assert_eq!(vec![1], evidence.iter().map(|(id, _)| *id).collect::<Vec<_>>());
+		assert_eq!(1, evidence[0].1.submitter);
+		assert_eq!(content, evidence[0].1.content);
+		let replies = SchellingGameShared::get_evidence_replies(key.clone(), 1, 0, 10);
+		assert_eq!(vec![2], replies.iter().map(|(id, _)| *id).collect::<Vec<_>>());
+		assert_eq!(4, replies[0].1.submitter);
+	});
+}
+
+#[test]
+fn migrate_posts_out_of_the_pallet() {
+	new_test_ext().execute_with(|| {
+		let post = v0::PositiveExternalityPost::<Test> {
 			id: 1,
 			created: WhoAndWhen { account: 1, block: 0, time: 0 },
 			edited: false,
@@ -20,9 +73,23 @@ fn test_positive_externality_post() {
 			hidden: false,
 			upvotes_count: 0,
 			downvotes_count: 0,
-		});
-		assert_eq!(post, post_compare);
-		//    assert_ok!(TemplateModule::apply_jurors_positive_externality(Origin::signed(1), 2, 60));
+		};
+		v0::PositiveExternalityPostById::<Test>::insert(1, post);
+		v0::PositiveExternalityEvidence::<Test>::insert(1, vec![1]);
+		v0::NextPositiveExternalityPostId::<Test>::put(2);
+		StorageVersion::new(0).put::<TemplateModule>();
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), StorageVersion::new(1));
+		assert_eq!(v0::PositiveExternalityPostById::<Test>::get(1), None);
+		assert_eq!(v0::PositiveExternalityEvidence::<Test>::get(1), None);
+		assert_eq!(v0::NextPositiveExternalityPostId::<Test>::get(), None);
+
+		// Migration runs once
+		v0::NextPositiveExternalityPostId::<Test>::put(2);
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(v0::NextPositiveExternalityPostId::<Test>::get(), Some(2));
 	});
 }
 
@@ -201,7 +268,45 @@ fn test_commit_and_incentives_vote() {
 		));
 		System::set_block_number(12980260);
 		assert_ok!(TemplateModule::pass_period(RuntimeOrigin::signed(4), 1));
+		System::set_block_number(12980310);
+		assert_ok!(TemplateModule::pass_period(RuntimeOrigin::signed(4), 1));
+		// Score is set when the game is resolved, votes of 5 are outside the standard deviation
+		System::assert_has_event(Event::PositiveExternalityScoreSet { user: 1, score: 1000 }.into());
+		assert_eq!(1000, SharedStorage::positive_externality_score(1));
 
 		assert_ok!(TemplateModule::get_incentives(RuntimeOrigin::signed(4), 1));
 	})
 }
+
+#[test]
+fn voided_game_keeps_positive_externality_score_test() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::set_validate_positive_externality(RuntimeOrigin::signed(1), true));
+		assert_ok!(TemplateModule::add_positive_externality_stake(RuntimeOrigin::signed(1), 10000));
+		System::set_block_number(1298000);
+		assert_ok!(TemplateModule::apply_staking_period(RuntimeOrigin::signed(2), 1));
+		let key = SumTreeName::PositiveExternality { user_address: 1, block_number: 1296000 };
+		let phase_data = TemplateModule::get_phase_data(key.clone());
+
+		// No juror applied, the game is voided after the drawing deadline
+		System::set_block_number(1298000 + phase_data.staking_length);
+		assert_ok!(TemplateModule::pass_period(RuntimeOrigin::signed(4), 1));
+		System::set_block_number(1298000 + phase_data.staking_length + phase_data.drawing_length);
+		assert_ok!(TemplateModule::pass_period(RuntimeOrigin::signed(4), 1));
+		System::assert_has_event(
+			<schelling_game_shared::Event<Test>>::GameVoided { key: key.clone() }.into(),
+		);
+		System::assert_last_event(
+			Event::PositiveExternalityGameVoided { user: 1, block_number: 1296000 }.into(),
+		);
+		assert_eq!(10000, TemplateModule::positive_externality_user_stake(1));
+
+		// Game of the period is archived, the next period has a new game
+		assert_noop!(
+			TemplateModule::apply_staking_period(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::CannotStakeNow
+		);
+		System::set_block_number(1298000 + 1296000);
+		assert_ok!(TemplateModule::apply_staking_period(RuntimeOrigin::signed(2), 1));
+	});
+}
@@ -24,37 +24,10 @@ impl<T: Config> CitizenDetailsPost<T> {
 	}
 }
 
-impl<T: Config> ChallengeEvidencePost<T> {
-	pub fn new(
-		kyc_profile_id: T::AccountId,
-		created_by: T::AccountId,
-		content: Content,
-		post_id_if_comment: Option<ChallengePostId>,
-	) -> Self {
-		ChallengeEvidencePost {
-			created: new_who_and_when::<T>(created_by.clone()),
-			owner: created_by,
-			kyc_profile_id,
-			content,
-			post_id_if_comment,
-			is_comment: false,
-		}
-	}
-
-	pub fn ensure_owner(&self, account: &T::AccountId) -> DispatchResult {
-		ensure!(self.is_owner(account), Error::<T>::NotAPostOwner);
-		Ok(())
-	}
-
-	pub fn is_owner(&self, account: &T::AccountId) -> bool {
-		self.owner == *account
-	}
-}
-
 impl<T: Config> Pallet<T> {
-	pub(super) fn get_phase_data() -> PhaseData<T> {
-		T::SchellingGameSharedSource::create_phase_with_all_data(10, 100, 100, 100, 100, 100, 100, 5, 5, 100, (100, 100))
-		// T::SchellingGameSharedSource::create_phase_data(100, 5, 3, 100, (100, 100))
+	/// Phase data of the game, copied from the game type when the game was created
+	pub(super) fn get_phase_data(key: SumTreeName<T::AccountId, BlockNumberOf<T>>) -> PhaseData<T> {
+		T::SchellingGameSharedSource::get_phase_data_of_game_link(key)
 	}
 
 	// pub(super) fn get_citizen_accountid(
@@ -80,16 +53,43 @@ impl<T: Config> Pallet<T> {
 		input.saturated_into::<BlockNumberOf<T>>()
 	}
 
+	/// Recovery expires `RECOVERY_EXPIRY_DELAYS` times `RecoveryDelay` after it started
+	pub(super) fn is_recovery_expired(
+		request: &RecoveryRequestOf<T>,
+		now: BlockNumberOf<T>,
+	) -> bool {
+		let expiry = T::RecoveryDelay::get().saturating_mul(RECOVERY_EXPIRY_DELAYS.into());
+		now >= request.created.saturating_add(expiry)
+	}
+
+	/// Profile of the citizen moves to the new account, the lock on updating the profile moves with it
+	/// Validation game of the profile is kept by the lost account, funders and challengers use it
+	pub(super) fn move_citizen_profile(
+		citizen_id: CitizenId,
+		lost_account: &T::AccountId,
+		new_account: &T::AccountId,
+	) {
+		<GetCitizenId<T>>::remove(lost_account);
+		<GetCitizenId<T>>::insert(new_account, citizen_id);
+		if let Some(mut profile) = <CitizenProfile<T>>::take(lost_account) {
+			profile.owner = new_account.clone();
+			<CitizenProfile<T>>::insert(new_account, profile);
+		}
+		let total_funded = <ProfileTotalFundCollected<T>>::take(lost_account);
+		<ProfileTotalFundCollected<T>>::insert(new_account, total_funded);
+	}
+
+	/// Ids of the evidence of the challenge of the profile in the shared schelling game pallet, newest first
+	/// Replies to the evidence are not included
 	pub fn get_challengers_evidence(
 		profile_user_account: T::AccountId,
 		offset: u64,
 		limit: u16,
-	) -> Vec<ChallengePostId> {
-		let mut data = <ChallengerEvidenceId<T>>::iter_prefix_values(&profile_user_account)
-			.skip(offset as usize)
-			.take(limit as usize)
-			.collect::<Vec<_>>();
-		data.sort();
+	) -> Vec<EvidenceId> {
+		let block_number = <ProfileValidationBlock<T>>::get(&profile_user_account);
+		let key =
+			SumTreeName::ProfileValidation { citizen_address: profile_user_account, block_number };
+		let mut data = T::SchellingGameSharedSource::get_game_evidence_ids_link(key, offset, limit);
 		data.reverse();
 		data
 	}
@@ -103,7 +103,7 @@ impl<T: Config> Pallet<T> {
 			block_number,
 		};
 
-		let phase_data = Self::get_phase_data();
+		let phase_data = Self::get_phase_data(key.clone());
 
 		let result = T::SchellingGameSharedSource::get_evidence_period_end_block_helper_link(
 			key, phase_data, now,
@@ -123,7 +123,7 @@ impl<T: Config> Pallet<T> {
 			block_number,
 		};
 
-		let phase_data = Self::get_phase_data();
+		let phase_data = Self::get_phase_data(key.clone());
 
 		let result = T::SchellingGameSharedSource::get_staking_period_end_block_helper_link(
 			key, phase_data, now,
@@ -138,7 +138,7 @@ impl<T: Config> Pallet<T> {
 			citizen_address: profile_user_account.clone(),
 			block_number,
 		};
-		let phase_data = Self::get_phase_data();
+		let phase_data = Self::get_phase_data(key.clone());
 
 		let result =
 			T::SchellingGameSharedSource::get_drawing_period_end_helper_link(key, phase_data);
@@ -153,7 +153,7 @@ impl<T: Config> Pallet<T> {
 			citizen_address: profile_user_account.clone(),
 			block_number,
 		};
-		let phase_data = Self::get_phase_data();
+		let phase_data = Self::get_phase_data(key.clone());
 
 		let result = T::SchellingGameSharedSource::get_commit_period_end_block_helper_link(
 			key, phase_data, now,
@@ -169,7 +169,7 @@ impl<T: Config> Pallet<T> {
 			citizen_address: profile_user_account.clone(),
 			block_number,
 		};
-		let phase_data = Self::get_phase_data();
+		let phase_data = Self::get_phase_data(key.clone());
 
 		let result = T::SchellingGameSharedSource::get_vote_period_end_block_helper_link(
 			key, phase_data, now,
@@ -198,3 +198,60 @@ impl<T: Config> Pallet<T> {
 		fund_required
 	}
 }
+
+impl<T: Config> OnGameVoided<SumTreeName<T::AccountId, BlockNumberOf<T>>> for Pallet<T> {
+	/// Return the fee of the challenger when the schelling game of the challenge is voided
+	fn on_game_voided(key: SumTreeName<T::AccountId, BlockNumberOf<T>>) {
+		let profile_user_account = match key {
+			SumTreeName::ProfileValidation { citizen_address, block_number }
+				if <ProfileValidationBlock<T>>::get(&citizen_address) == block_number =>
+				citizen_address,
+			_ => return,
+		};
+		if let Some(mut challenger_fund_info) =
+			<ChallengerFundDetails<T>>::get(&profile_user_account)
+		{
+			if challenger_fund_info.challenge_completed {
+				return
+			}
+			if let Ok(r) = <T as pallet::Config>::Currency::deposit_into_existing(
+				&challenger_fund_info.challengerid,
+				challenger_fund_info.deposit,
+			) {
+				<T as pallet::Config>::Reward::on_unbalanced(r);
+			}
+			challenger_fund_info.challenge_completed = true;
+			<ChallengerFundDetails<T>>::insert(&profile_user_account, challenger_fund_info.clone());
+			Self::deposit_event(Event::ChallengeFeeReturned {
+				profile: profile_user_account,
+				challenger: challenger_fund_info.challengerid,
+			});
+		}
+	}
+}
+
+impl<T: Config>
+	OnGameResolved<SumTreeName<T::AccountId, BlockNumberOf<T>>, SchellingGameType, GameOutcome>
+	for Pallet<T>
+{
+	/// Keep the decision of the schelling game of the challenge
+	fn on_game_resolved(
+		key: SumTreeName<T::AccountId, BlockNumberOf<T>>,
+		_game_type: SchellingGameType,
+		outcome: GameOutcome,
+	) {
+		let profile_user_account = match key {
+			SumTreeName::ProfileValidation { citizen_address, block_number }
+				if <ProfileValidationBlock<T>>::get(&citizen_address) == block_number =>
+				citizen_address,
+			_ => return,
+		};
+		if let GameOutcome::TwoChoice(decision) = outcome {
+			<ChallengeDecision<T>>::insert(&profile_user_account, decision.clone());
+			Self::deposit_event(Event::ChallengeResolved {
+				profile: profile_user_account,
+				decision,
+			});
+		}
+	}
+}
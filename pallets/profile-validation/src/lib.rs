@@ -7,8 +7,8 @@
 /// To Do:
 /// Add profile ✅
 /// Crowdfund for profile stake ✅
-/// Add another account in case you loose account access
-/// Appeal in case of fradulent account
+/// Add another account in case you loose account access ✅
+/// Appeal in case of fradulent account ✅
 /// Clean the storage after are incentives are given
 pub use pallet::*;
 
@@ -24,18 +24,20 @@ pub mod weights;
 pub use weights::*;
 
 mod extras;
+pub mod migrations;
 mod permissions;
 mod types;
 
-use crate::types::{ChallengeEvidencePost, ChallengerFundInfo, ProfileFundInfo};
+use crate::types::{ChallengerFundInfo, ProfileFundInfo, RecoveryConfig, RecoveryRequest};
 use frame_support::sp_runtime::traits::AccountIdConversion;
-use frame_support::sp_runtime::traits::{CheckedAdd, CheckedSub};
+use frame_support::sp_runtime::traits::{CheckedAdd, CheckedSub, Saturating};
 use frame_support::sp_runtime::SaturatedConversion;
 use frame_support::sp_std::prelude::*;
 use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
 use frame_support::{
 	traits::{
-		Currency, ExistenceRequirement, Get, OnUnbalanced, ReservableCurrency, WithdrawReasons,
+		BalanceStatus, Currency, ExistenceRequirement, Get, OnUnbalanced, ReservableCurrency,
+		WithdrawReasons,
 	},
 	PalletId,
 };
@@ -44,19 +46,23 @@ use pallet_support::{
 	ensure_content_is_valid, new_who_and_when, remove_from_vec, Content, WhoAndWhen, WhoAndWhenOf,
 };
 use schelling_game_shared::types::{
-	Period, PhaseData, RangePoint, SchellingGameType, WinningDecision,
+	GameOutcome, Period, PhaseData, RangePoint, SchellingGameType, WinningDecision,
 };
-use schelling_game_shared_link::SchellingGameSharedLink;
+use schelling_game_shared_link::{OnGameResolved, OnGameVoided, SchellingGameSharedLink};
+use shared_storage_link::{OnCitizenRecovered, SharedStorageLink};
 use sortition_sum_game::types::SumTreeName;
-pub use types::{CitizenDetailsPost, FIRST_CHALLENGE_POST_ID, FIRST_CITIZEN_ID};
+pub use types::{CitizenDetailsPost, FIRST_CITIZEN_ID};
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
 type ProfileFundInfoOf<T> = ProfileFundInfo<BalanceOf<T>, AccountIdOf<T>>;
 type ChallengerFundInfoOf<T> =
 	ChallengerFundInfo<BalanceOf<T>, <T as frame_system::Config>::BlockNumber, AccountIdOf<T>>;
+type RecoveryConfigOf<T> = RecoveryConfig<AccountIdOf<T>>;
+type RecoveryRequestOf<T> =
+	RecoveryRequest<AccountIdOf<T>, BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
 pub type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
 type CitizenId = u64;
-type ChallengePostId = u64;
+type EvidenceId = u64;
 type PositiveImbalanceOf<T> = <<T as Config>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::PositiveImbalance;
@@ -66,13 +72,20 @@ type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
 
 const PALLET_ID: PalletId = PalletId(*b"ex/cfund");
 
+/// Recovery not claimed within this many `RecoveryDelay` expires, then a new recovery can be started
+const RECOVERY_EXPIRY_DELAYS: u32 = 3;
+
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// Challenge evidence is kept in the shared schelling game pallet since version 1
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -96,6 +109,7 @@ pub mod pallet {
 			Period = Period,
 			WinningDecision = WinningDecision,
 			PhaseData = PhaseData<Self>,
+			Content = Content,
 		>;
 		type Currency: ReservableCurrency<Self::AccountId>;
 		/// Handler for the unbalanced increment when rewarding (minting rewards)
@@ -103,6 +117,26 @@ pub mod pallet {
 
 		/// Handler for the unbalanced decrement when slashing (burning collateral)
 		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		type SharedStorageSource: SharedStorageLink<AccountId = AccountIdOf<Self>>;
+
+		/// Handler of the storage of other pallets kept by the account of the recovered citizen
+		type OnCitizenRecovered: OnCitizenRecovered<Self::AccountId>;
+
+		/// Deposit reserved from the account that starts a recovery
+		/// It is returned when the profile is recovered, the lost account gets it if the recovery is challenged
+		/// It is slashed if the recovery expires, so holding the recovery of a citizen is not free
+		#[pallet::constant]
+		type RecoveryDeposit: Get<BalanceOf<Self>>;
+
+		/// Blocks after the recovery starts before the profile can be recovered
+		/// The lost account can challenge the recovery till it is claimed
+		#[pallet::constant]
+		type RecoveryDelay: Get<Self::BlockNumber>;
+
+		/// Maximum number of friends of a citizen who can vouch for the recovery
+		#[pallet::constant]
+		type MaxRecoveryFriends: Get<u32>;
 	}
 
 	// The pallet's runtime storage items.
@@ -180,37 +214,23 @@ pub mod pallet {
 	pub type ChallengerFundDetails<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, ChallengerFundInfoOf<T>>; // Profile account id and challenger fund info
 
-	/// There is a single challenger, but they can have multiple posts
-	#[pallet::storage]
-	#[pallet::getter(fn challenger_evidence_query)]
-	pub type ChallengerEvidenceId<T: Config> = StorageDoubleMap<
-		_,
-		Blake2_128Concat,
-		T::AccountId,
-		Blake2_128Concat,
-		T::AccountId,
-		ChallengePostId,
-	>; // profile accountid, challenger accountid => Challenge post id
-
-	#[pallet::type_value]
-	pub fn DefaultForNextChallengePostId() -> ChallengePostId {
-		FIRST_CHALLENGE_POST_ID
-	}
-
+	/// Decision of the schelling game of the challenge, set when the game is resolved
 	#[pallet::storage]
-	#[pallet::getter(fn next_challenge_post_count)]
-	pub type NextChallengePostId<T: Config> =
-		StorageValue<_, ChallengePostId, ValueQuery, DefaultForNextChallengePostId>;
+	#[pallet::getter(fn challenge_decision)]
+	pub type ChallengeDecision<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, WinningDecision>; // Profile account id and decision
 
+	/// Friends of the citizen who can vouch for the recovery of the profile
 	#[pallet::storage]
-	#[pallet::getter(fn challenge_post_comment)]
-	pub type ChallengePostCommentIds<T: Config> =
-		StorageMap<_, Blake2_128Concat, ChallengePostId, Vec<ChallengePostId>, ValueQuery>; // challenge post id => Vec<Comment Post It>
+	#[pallet::getter(fn recovery_config)]
+	pub type RecoveryConfigs<T: Config> =
+		StorageMap<_, Blake2_128Concat, CitizenId, RecoveryConfigOf<T>>;
 
+	/// Recovery of the profile that is not claimed or challenged yet, one at a time for a citizen
 	#[pallet::storage]
-	#[pallet::getter(fn challenge_post)]
-	pub type ChallengePost<T: Config> =
-		StorageMap<_, Blake2_128Concat, ChallengePostId, ChallengeEvidencePost<T>>; // challenge post id => post
+	#[pallet::getter(fn active_recovery)]
+	pub type ActiveRecoveries<T: Config> =
+		StorageMap<_, Blake2_128Concat, CitizenId, RecoveryRequestOf<T>>;
 
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
@@ -228,6 +248,49 @@ pub mod pallet {
 			profile: T::AccountId,
 			funder: T::AccountId,
 		},
+		/// Schelling game of the challenge was voided, the challenger got back the fee
+		ChallengeFeeReturned {
+			profile: T::AccountId,
+			challenger: T::AccountId,
+		},
+		/// Schelling game of the challenge was resolved
+		ChallengeResolved {
+			profile: T::AccountId,
+			decision: WinningDecision,
+		},
+		RecoveryConfigSet {
+			citizen_id: CitizenId,
+			account: T::AccountId,
+		},
+		RecoveryConfigRemoved {
+			citizen_id: CitizenId,
+			account: T::AccountId,
+		},
+		RecoveryInitiated {
+			citizen_id: CitizenId,
+			lost_account: T::AccountId,
+			new_account: T::AccountId,
+		},
+		RecoveryVouched {
+			citizen_id: CitizenId,
+			friend: T::AccountId,
+		},
+		/// Lost account stopped the recovery and got the deposit
+		RecoveryChallenged {
+			citizen_id: CitizenId,
+			new_account: T::AccountId,
+		},
+		/// Recovery was not claimed in time, the deposit of `new_account` is slashed
+		RecoveryExpired {
+			citizen_id: CitizenId,
+			new_account: T::AccountId,
+		},
+		/// Profile, approval and score of the citizen moved to the new account
+		CitizenRecovered {
+			citizen_id: CitizenId,
+			lost_account: T::AccountId,
+			new_account: T::AccountId,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -240,12 +303,9 @@ pub mod pallet {
 		NoMoreUpdates,
 		CitizenDoNotExists,
 		ProfileFundExists,
-		PostAlreadyExists,
 		ProfileIsAlreadyValidated,
 		ChallengeExits,
 		ChallengeDoesNotExists,
-		CommentExists,
-		IsComment,
 		ProfileFundNotExists,
 		ChallengerFundInfoExists,
 		NotProfileUser,
@@ -254,6 +314,21 @@ pub mod pallet {
 		NotAPostOwner,
 		AmountFundedGreaterThanRequired,
 		ProfileFundAlreadyReturned,
+		NotAllowedToAppeal,
+		/// Friends must not be empty or include the citizen, threshold must be at most the number of friends
+		InvalidRecoveryConfig,
+		RecoveryConfigDoesNotExists,
+		RecoveryAlreadyStarted,
+		RecoveryNotStarted,
+		NotARecoveryFriend,
+		AlreadyVouched,
+		/// Account of the recovery must not have a profile
+		AccountHasProfile,
+		NotRecoveryAccount,
+		NotEnoughVouchers,
+		RecoveryDelayNotOver,
+		/// Recovery was not claimed within `RECOVERY_EXPIRY_DELAYS` times `RecoveryDelay`
+		RecoveryExpired,
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -375,7 +450,11 @@ pub mod pallet {
 					<ProfileValidationBlock<T>>::insert(&profile_user_account, now);
 
 					// Set a link to the evidence period in the Schelling Game.
-					T::SchellingGameSharedSource::set_to_evidence_period_link(key, now)?;
+					T::SchellingGameSharedSource::set_to_evidence_period_link(
+						key,
+						SchellingGameType::ProfileApproval,
+						now,
+					)?;
 				}
 
 				// Withdraw funds from the funder's account.
@@ -475,115 +554,38 @@ pub mod pallet {
 				block_number,
 			};
 
-			let phase_data = Self::get_phase_data();
+			// Evidence of the challenge is submitted while the game is still in `Period::Evidence`
+			T::SchellingGameSharedSource::submit_evidence_link(key.clone(), who, content, None)?;
+
+			let phase_data = Self::get_phase_data(key.clone());
 
 			T::SchellingGameSharedSource::set_to_staking_period_link(key.clone(), phase_data, now)?;
 			T::SchellingGameSharedSource::create_tree_helper_link(key.clone(), 3)?;
 
-			let count = Self::next_challenge_post_count();
-
-			let challenge_evidence_post: ChallengeEvidencePost<T> = ChallengeEvidencePost::new(
-				profile_user_account.clone(),
-				who.clone(),
-				content,
-				None,
-			);
-
-			match <ChallengerEvidenceId<T>>::get(&profile_user_account, &who) {
-				None => {
-					<ChallengePost<T>>::insert(&count, challenge_evidence_post);
-					NextChallengePostId::<T>::mutate(|n| {
-						*n += 1;
-					});
-
-					<ChallengerEvidenceId<T>>::insert(&profile_user_account, &who, count);
-				},
-				Some(_hash) => Err(Error::<T>::PostAlreadyExists)?,
-			}
 			Ok(())
 		}
 
-		// #[pallet::call_index(2)]
-		// #[pallet::weight(Weight::from_parts(10_000, u64::MAX) + T::DbWeight::get().reads_writes(2,2))]
-		// pub fn challenge_evidence(
-		// 	origin: OriginFor<T>,
-		// 	profile_citizenid: CitizenId,
-		// 	content: Content,
-		// ) -> DispatchResult {
-		// 	let who = ensure_signed(origin)?;
-		// 	let citizen_account_id = Self::get_citizen_accountid(profile_citizenid)?;
-		// 	let count = Self::next_challenge_post_count();
-		// 	let challenge_evidence_post =
-		// 		ChallengeEvidencePost::new(citizen_account_id, who.clone(), content, None);
-		// 	match <ChallengerEvidenceId<T>>::get(&profile_citizenid, &who) {
-		// 		None => {
-		// 			<ChallengePost<T>>::insert(&count, challenge_evidence_post);
-		// 			NextChallengePostId::<T>::mutate(|n| {
-		// 				*n += 1;
-		// 			});
-
-		// 			<ChallengerEvidenceId<T>>::insert(&profile_citizenid, &who, count);
-		// 		},
-		// 		Some(_hash) => {
-		// 			Err(Error::<T>::PostAlreadyExists)?
-		// 			// match <ChallengerFundDetails<T>>::get(&profile_citizenid) {
-		// 			// 	Some(_challengerfundinfo) => {
-		// 			// 		Err(Error::<T>::ChallengerFundAddedCanNotUpdate)?
-		// 			// 	},
-		// 			// 	None => {
-		// 			// 		// Update challenger profile
-		// 			// 		<ChallengePost<T>>::insert(&count, challenge_evidence_post);
-		// 			// 		let newcount =
-		// 			// 			count.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
-		// 			// 		<ChallengePostCount<T>>::put(newcount);
-		// 			// 		<ChallengerEvidenceId<T>>::insert(&profile_citizenid, &who, count);
-		// 			// 	},
-		// 			// }
-		// 		},
-		// 	}
-		// 	Ok(())
-		// }
-
+		/// Reply to the evidence of the challenge of the profile, or to a reply
+		/// Evidence and replies are kept in the shared schelling game pallet
 		#[pallet::call_index(3)]
 		#[pallet::weight(0)]
 		pub fn challenge_comment_create(
 			origin: OriginFor<T>,
-			post_id: ChallengePostId,
+			profile_user_account: T::AccountId,
+			evidence_id: EvidenceId,
 			content: Content,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			let count = Self::next_challenge_post_count();
-			let main_evidence_post = Self::challenge_post(post_id).unwrap();
-			let challenge_evidence_post = ChallengeEvidencePost::new(
-				main_evidence_post.kyc_profile_id,
-				who,
-				content,
-				Some(post_id),
+			ensure!(
+				<ChallengerFundDetails<T>>::contains_key(&profile_user_account),
+				Error::<T>::ChallengeDoesNotExists
 			);
+			let block_number = <ProfileValidationBlock<T>>::get(&profile_user_account);
 
-			match <ChallengePost<T>>::get(&post_id) {
-				None => Err(Error::<T>::ChallengeDoesNotExists)?,
-				Some(challenge_evidence_post_c) => {
-					if challenge_evidence_post_c.is_comment == false {
-						<ChallengePost<T>>::insert(&count, challenge_evidence_post);
-						NextChallengePostId::<T>::mutate(|n| {
-							*n += 1;
-						});
-						let mut comment_ids = <ChallengePostCommentIds<T>>::get(&post_id);
-						match comment_ids.binary_search(&count) {
-							Ok(_) => Err(Error::<T>::CommentExists)?,
-							Err(index) => {
-								comment_ids.insert(index, count.clone());
-								<ChallengePostCommentIds<T>>::insert(&post_id, &comment_ids);
-							},
-						}
-					} else {
-						Err(Error::<T>::IsComment)?
-					}
-				},
-			}
+			let key =
+				SumTreeName::ProfileValidation { citizen_address: profile_user_account, block_number };
 
-			Ok(())
+			T::SchellingGameSharedSource::submit_evidence_link(key, who, content, Some(evidence_id))
 		}
 
 		// // Does citizen exists ✔️
@@ -667,7 +669,7 @@ pub mod pallet {
 			};
 
 			let now = <frame_system::Pallet<T>>::block_number();
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 
 			T::SchellingGameSharedSource::change_period_link(key, phase_data, now)?;
 
@@ -705,7 +707,7 @@ pub mod pallet {
 				block_number,
 			};
 
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 
 			T::SchellingGameSharedSource::apply_jurors_helper_link(key, phase_data, who, stake)?;
 
@@ -733,7 +735,7 @@ pub mod pallet {
 				citizen_address: profile_user_account.clone(),
 				block_number,
 			};
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 
 			T::SchellingGameSharedSource::draw_jurors_helper_link(key, phase_data, iterations)?;
 
@@ -813,7 +815,7 @@ pub mod pallet {
 				citizen_address: profile_user_account.clone(),
 				block_number,
 			};
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 			T::SchellingGameSharedSource::get_incentives_two_choice_helper_link(
 				key, phase_data, who,
 			)?;
@@ -833,12 +835,17 @@ pub mod pallet {
 				block_number,
 			};
 			let now = <frame_system::Pallet<T>>::block_number();
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 
 			let period = T::SchellingGameSharedSource::get_period_link(key.clone()).unwrap();
 			if period == Period::Execution {
 				let decision: WinningDecision =
-					T::SchellingGameSharedSource::get_winning_decision_value_link(key.clone());
+					match <ChallengeDecision<T>>::get(&profile_user_account) {
+						Some(decision) => decision,
+						None => T::SchellingGameSharedSource::get_winning_decision_value_link(
+							key.clone(),
+						),
+					};
 				if decision == WinningDecision::WinnerNo {
 					match <ProfileFundDetails<T>>::get(profile_user_account.clone(), who.clone()) {
 						Some(mut profile_fund_info) => {
@@ -897,5 +904,222 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Appeal the decision of the jurors
+		/// Only the profile user or the challenger can appeal
+		#[pallet::call_index(13)]
+		#[pallet::weight(0)]
+		pub fn appeal(origin: OriginFor<T>, profile_user_account: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let challenger_fund_info = <ChallengerFundDetails<T>>::get(&profile_user_account)
+				.ok_or(Error::<T>::ChallengeDoesNotExists)?;
+			ensure!(
+				who == profile_user_account || who == challenger_fund_info.challengerid,
+				Error::<T>::NotAllowedToAppeal
+			);
+			let block_number = <ProfileValidationBlock<T>>::get(&profile_user_account);
+			let key = SumTreeName::ProfileValidation {
+				citizen_address: profile_user_account.clone(),
+				block_number,
+			};
+			let now = <frame_system::Pallet<T>>::block_number();
+			let phase_data = Self::get_phase_data(key.clone());
+			T::SchellingGameSharedSource::appeal_helper_link(key, phase_data, who, now)?;
+			Ok(())
+		}
+
+		/// Set the friends who can vouch for the recovery of the profile of the caller
+		/// Friends are kept by the citizen id, so they stay with the profile after it is recovered
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_recovery(friends.len() as u32))]
+		pub fn create_recovery(
+			origin: OriginFor<T>,
+			friends: Vec<T::AccountId>,
+			threshold: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let citizen_id = Self::get_citizen_id(&who).ok_or(Error::<T>::CitizenDoNotExists)?;
+			ensure!(
+				!<ActiveRecoveries<T>>::contains_key(citizen_id),
+				Error::<T>::RecoveryAlreadyStarted
+			);
+			let mut friends = friends;
+			friends.sort();
+			friends.dedup();
+			ensure!(
+				!friends.is_empty() &&
+					friends.len() <= T::MaxRecoveryFriends::get() as usize &&
+					friends.binary_search(&who).is_err(),
+				Error::<T>::InvalidRecoveryConfig
+			);
+			ensure!(
+				threshold > 0 && threshold as usize <= friends.len(),
+				Error::<T>::InvalidRecoveryConfig
+			);
+			<RecoveryConfigs<T>>::insert(citizen_id, RecoveryConfig { friends, threshold });
+			Self::deposit_event(Event::RecoveryConfigSet { citizen_id, account: who });
+			Ok(())
+		}
+
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_recovery())]
+		pub fn remove_recovery(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let citizen_id = Self::get_citizen_id(&who).ok_or(Error::<T>::CitizenDoNotExists)?;
+			ensure!(
+				!<ActiveRecoveries<T>>::contains_key(citizen_id),
+				Error::<T>::RecoveryAlreadyStarted
+			);
+			<RecoveryConfigs<T>>::take(citizen_id)
+				.ok_or(Error::<T>::RecoveryConfigDoesNotExists)?;
+			Self::deposit_event(Event::RecoveryConfigRemoved { citizen_id, account: who });
+			Ok(())
+		}
+
+		/// Start the recovery of the profile of `lost_account` to the caller
+		/// `RecoveryDeposit` is reserved from the caller
+		/// An expired recovery is replaced, its deposit is slashed
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::initiate_recovery())]
+		pub fn initiate_recovery(
+			origin: OriginFor<T>,
+			lost_account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let citizen_id =
+				Self::get_citizen_id(&lost_account).ok_or(Error::<T>::CitizenDoNotExists)?;
+			ensure!(
+				<RecoveryConfigs<T>>::contains_key(citizen_id),
+				Error::<T>::RecoveryConfigDoesNotExists
+			);
+			ensure!(!<GetCitizenId<T>>::contains_key(&who), Error::<T>::AccountHasProfile);
+			let now = <frame_system::Pallet<T>>::block_number();
+			if let Some(expired) = <ActiveRecoveries<T>>::get(citizen_id) {
+				ensure!(
+					Self::is_recovery_expired(&expired, now),
+					Error::<T>::RecoveryAlreadyStarted
+				);
+				let (imbalance, _) = <T as pallet::Config>::Currency::slash_reserved(
+					&expired.new_account,
+					expired.deposit,
+				);
+				<T as pallet::Config>::Slash::on_unbalanced(imbalance);
+				Self::deposit_event(Event::RecoveryExpired {
+					citizen_id,
+					new_account: expired.new_account,
+				});
+			}
+			let deposit = T::RecoveryDeposit::get();
+			<T as pallet::Config>::Currency::reserve(&who, deposit)?;
+			let request = RecoveryRequest {
+				new_account: who.clone(),
+				deposit,
+				created: now,
+				vouchers: Vec::new(),
+			};
+			<ActiveRecoveries<T>>::insert(citizen_id, request);
+			Self::deposit_event(Event::RecoveryInitiated {
+				citizen_id,
+				lost_account,
+				new_account: who,
+			});
+			Ok(())
+		}
+
+		/// Friend of the citizen vouches that `new_account` belongs to the citizen
+		#[pallet::call_index(17)]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::vouch_recovery(T::MaxRecoveryFriends::get())
+		)]
+		pub fn vouch_recovery(
+			origin: OriginFor<T>,
+			lost_account: T::AccountId,
+			new_account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let citizen_id =
+				Self::get_citizen_id(&lost_account).ok_or(Error::<T>::CitizenDoNotExists)?;
+			let recovery_config = <RecoveryConfigs<T>>::get(citizen_id)
+				.ok_or(Error::<T>::RecoveryConfigDoesNotExists)?;
+			ensure!(
+				recovery_config.friends.binary_search(&who).is_ok(),
+				Error::<T>::NotARecoveryFriend
+			);
+			let mut request =
+				<ActiveRecoveries<T>>::get(citizen_id).ok_or(Error::<T>::RecoveryNotStarted)?;
+			ensure!(request.new_account == new_account, Error::<T>::NotRecoveryAccount);
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(!Self::is_recovery_expired(&request, now), Error::<T>::RecoveryExpired);
+			match request.vouchers.binary_search(&who) {
+				Ok(_) => Err(Error::<T>::AlreadyVouched)?,
+				Err(index) => request.vouchers.insert(index, who.clone()),
+			}
+			<ActiveRecoveries<T>>::insert(citizen_id, request);
+			Self::deposit_event(Event::RecoveryVouched { citizen_id, friend: who });
+			Ok(())
+		}
+
+		/// Citizen who still has the account stops the recovery of the profile
+		/// The deposit of the account that started the recovery goes to the citizen
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::challenge_recovery())]
+		pub fn challenge_recovery(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let citizen_id = Self::get_citizen_id(&who).ok_or(Error::<T>::CitizenDoNotExists)?;
+			let request =
+				<ActiveRecoveries<T>>::take(citizen_id).ok_or(Error::<T>::RecoveryNotStarted)?;
+			<T as pallet::Config>::Currency::repatriate_reserved(
+				&request.new_account,
+				&who,
+				request.deposit,
+				BalanceStatus::Free,
+			)?;
+			Self::deposit_event(Event::RecoveryChallenged {
+				citizen_id,
+				new_account: request.new_account,
+			});
+			Ok(())
+		}
+
+		/// Move the profile of `lost_account` to the caller once enough friends vouched and the delay is over
+		/// The recovery must be claimed before it expires
+		/// The validation game of the profile and its funds stay with `lost_account`
+		#[pallet::call_index(19)]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::claim_recovery(T::MaxRecoveryFriends::get())
+		)]
+		pub fn claim_recovery(origin: OriginFor<T>, lost_account: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let citizen_id =
+				Self::get_citizen_id(&lost_account).ok_or(Error::<T>::CitizenDoNotExists)?;
+			let request =
+				<ActiveRecoveries<T>>::get(citizen_id).ok_or(Error::<T>::RecoveryNotStarted)?;
+			ensure!(request.new_account == who, Error::<T>::NotRecoveryAccount);
+			let recovery_config = <RecoveryConfigs<T>>::get(citizen_id)
+				.ok_or(Error::<T>::RecoveryConfigDoesNotExists)?;
+			ensure!(
+				request.vouchers.len() >= recovery_config.threshold as usize,
+				Error::<T>::NotEnoughVouchers
+			);
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				now >= request.created.saturating_add(T::RecoveryDelay::get()),
+				Error::<T>::RecoveryDelayNotOver
+			);
+			ensure!(!Self::is_recovery_expired(&request, now), Error::<T>::RecoveryExpired);
+			ensure!(!<GetCitizenId<T>>::contains_key(&who), Error::<T>::AccountHasProfile);
+
+			T::SharedStorageSource::move_citizen_link(lost_account.clone(), who.clone())?;
+			Self::move_citizen_profile(citizen_id, &lost_account, &who);
+			T::OnCitizenRecovered::on_citizen_recovered(lost_account.clone(), who.clone());
+			<ActiveRecoveries<T>>::remove(citizen_id);
+			<T as pallet::Config>::Currency::unreserve(&who, request.deposit);
+			Self::deposit_event(Event::CitizenRecovered {
+				citizen_id,
+				lost_account,
+				new_account: who,
+			});
+			Ok(())
+		}
 	}
 }
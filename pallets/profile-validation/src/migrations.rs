@@ -0,0 +1,81 @@
+use crate::*;
+
+/// Challenge evidence kept in the pallet before version 1, it is kept in the shared schelling game pallet since
+pub mod v0 {
+	use super::*;
+	use scale_info::TypeInfo;
+
+	pub type ChallengePostId = u64;
+
+	#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct ChallengeEvidencePost<T: Config> {
+		pub created: WhoAndWhenOf<T>,
+		pub owner: T::AccountId,
+		pub kyc_profile_id: T::AccountId,
+		pub content: Content,
+		pub post_id_if_comment: Option<ChallengePostId>,
+		pub is_comment: bool,
+	}
+
+	#[frame_support::storage_alias]
+	pub type ChallengerEvidenceId<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		ChallengePostId,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type NextChallengePostId<T: Config> = StorageValue<Pallet<T>, ChallengePostId>;
+
+	#[frame_support::storage_alias]
+	pub type ChallengePostCommentIds<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ChallengePostId, Vec<ChallengePostId>>;
+
+	#[frame_support::storage_alias]
+	pub type ChallengePost<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ChallengePostId, ChallengeEvidencePost<T>>;
+}
+
+/// Remove the challenge evidence kept in the pallet, no deposit was reserved for it
+pub mod v1 {
+	use super::*;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version >= 1 {
+				return T::DbWeight::get().reads(1)
+			}
+			let mut removed = 1u64;
+			removed += v0::ChallengerEvidenceId::<T>::clear(u32::MAX, None).unique as u64;
+			removed += v0::ChallengePostCommentIds::<T>::clear(u32::MAX, None).unique as u64;
+			removed += v0::ChallengePost::<T>::clear(u32::MAX, None).unique as u64;
+			v0::NextChallengePostId::<T>::kill();
+			StorageVersion::new(1).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(removed, removed.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			ensure!(
+				v0::ChallengePost::<T>::iter_keys().next().is_none() &&
+					v0::ChallengePostCommentIds::<T>::iter_keys().next().is_none() &&
+					v0::ChallengerEvidenceId::<T>::iter_keys().next().is_none() &&
+					!v0::NextChallengePostId::<T>::exists(),
+				"Challenge evidence is left in the pallet"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == StorageVersion::new(1),
+				"Storage version is not updated"
+			);
+			Ok(())
+		}
+	}
+}
@@ -1,9 +1,11 @@
 use crate as pallet_template;
+use schelling_game_shared::types::CommitVersion;
 use frame_support::{parameter_types, traits::{ConstU16, ConstU64}};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	Perbill,
 };
 use frame_support_test::TestRandomness;
 
@@ -24,6 +26,7 @@ frame_support::construct_runtime!(
 		Timestamp: pallet_timestamp,
 		SchellingGameShared: schelling_game_shared,
 		SortitionSumGame: sortition_sum_game,
+		SharedStorage: shared_storage,
 	}
 );
 
@@ -83,6 +86,12 @@ impl pallet_balances::Config for Test {
 	type AccountStore = System;
 }
 
+parameter_types! {
+	pub const RecoveryDeposit: u64 = 500;
+	pub const RecoveryDelay: u64 = 10;
+	pub const MaxRecoveryFriends: u32 = 5;
+}
+
 impl pallet_template::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
@@ -90,6 +99,22 @@ impl pallet_template::Config for Test {
 	type SchellingGameSharedSource = SchellingGameShared;
 	type Slash = ();
 	type Reward = ();
+	type SharedStorageSource = SharedStorage;
+	type OnCitizenRecovered = ();
+	type RecoveryDeposit = RecoveryDeposit;
+	type RecoveryDelay = RecoveryDelay;
+	type MaxRecoveryFriends = MaxRecoveryFriends;
+}
+
+parameter_types! {
+	pub const NonCommitSlashFraction: Perbill = Perbill::from_percent(50);
+	pub const NonRevealSlashFraction: Perbill = Perbill::from_percent(50);
+	pub const MaxPeriodChangesPerBlock: u32 = 10;
+	pub const MaxVoteRemovalsPerBlock: u32 = 100;
+	pub const VoteCommitVersion: CommitVersion = CommitVersion::V0;
+	pub const EvidenceDeposit: u64 = 100;
+	pub const MaxEvidencePerGame: u32 = 100;
+	pub const MaxDelegatorsPerJuror: u32 = 100;
 }
 
 impl schelling_game_shared::Config for Test {
@@ -97,9 +122,21 @@ impl schelling_game_shared::Config for Test {
 	type WeightInfo = ();
 	type Currency = Balances; // New code
 	type RandomnessSource = TestRandomness<Self>;
+	type DrawRandomness = schelling_game_shared::JurorBeacon<Self>;
+	type ReputationMultiplier = ();
+	type OnGameVoided = ProfileValidation;
+	type OnGameResolved = ProfileValidation;
 	type Slash = ();
-	type Reward = ();
 	type SortitionSumGameSource = SortitionSumGame;
+	type NonCommitSlashFraction = NonCommitSlashFraction;
+	type NonRevealSlashFraction = NonRevealSlashFraction;
+	type MaxPeriodChangesPerBlock = MaxPeriodChangesPerBlock;
+	type MaxVoteRemovalsPerBlock = MaxVoteRemovalsPerBlock;
+	type PhaseDataOrigin = frame_system::EnsureRoot<u64>;
+	type VoteCommitVersion = VoteCommitVersion;
+	type EvidenceDeposit = EvidenceDeposit;
+	type MaxEvidencePerGame = MaxEvidencePerGame;
+	type MaxDelegatorsPerJuror = MaxDelegatorsPerJuror;
 }
 
 impl sortition_sum_game::Config for Test {
@@ -107,6 +144,11 @@ impl sortition_sum_game::Config for Test {
 	type WeightInfo = ();
 }
 
+impl shared_storage::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+}
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
@@ -151,5 +193,8 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	} // new code
 	.assimilate_storage(&mut t)
 	.unwrap();
+	shared_storage::GenesisConfig::<Test> { approved_citizen_address: vec![1, 2] }
+		.assimilate_storage(&mut t)
+		.unwrap();
 	t.into()
 }
@@ -1,6 +1,14 @@
 use crate::types::CitizenDetailsPost;
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	migrations::{v0, v1::MigrateToV1},
+	mock::*,
+	Error, Event,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
 use pallet_support::Content;
 use pallet_support::WhoAndWhen;
 use schelling_game_shared::types::Period;
@@ -136,7 +144,10 @@ fn challenge_evidence() {
 				.to_vec(),
 		);
 
-		let phase_data = ProfileValidation::get_phase_data();
+		let phase_data = ProfileValidation::get_phase_data(SumTreeName::ProfileValidation {
+			citizen_address: 1,
+			block_number: 1,
+		});
 
 		assert_noop!(
 			ProfileValidation::challenge_profile(
@@ -156,10 +167,55 @@ fn challenge_evidence() {
 			1,
 			challenge_content.clone()
 		));
+		// `EvidenceDeposit` is 100
 		let balance = Balances::free_balance(4);
-		assert_eq!(300000 - fees, balance);
+		assert_eq!(300000 - fees - 100, balance);
+		assert_eq!(100, Balances::reserved_balance(4));
 		let period = SchellingGameShared::get_period(key.clone());
 		assert_eq!(Some(Period::Staking), period);
+		assert_eq!(vec![1], ProfileValidation::get_challengers_evidence(1, 0, 10));
+		let evidence = SchellingGameShared::get_game_evidence(key.clone(), 0, 10);
+		assert_eq!(4, evidence[0].1.submitter);
+		assert_eq!(challenge_content, evidence[0].1.content);
+
+		// Replies to the evidence of the challenge
+		assert_ok!(ProfileValidation::challenge_comment_create(
+			RuntimeOrigin::signed(1),
+			1,
+			1,
+			content.clone()
+		));
+		assert_ok!(ProfileValidation::challenge_comment_create(
+			RuntimeOrigin::signed(4),
+			1,
+			2,
+			challenge_content.clone()
+		));
+		assert_noop!(
+			ProfileValidation::challenge_comment_create(
+				RuntimeOrigin::signed(1),
+				1,
+				4,
+				content.clone()
+			),
+			<schelling_game_shared::Error<Test>>::EvidenceDoesNotExist
+		);
+		assert_noop!(
+			ProfileValidation::challenge_comment_create(
+				RuntimeOrigin::signed(1),
+				2,
+				1,
+				content.clone()
+			),
+			Error::<Test>::ChallengeDoesNotExists
+		);
+		let replies = SchellingGameShared::get_evidence_replies(key.clone(), 1, 0, 10);
+		assert_eq!(vec![2], replies.iter().map(|(id, _)| *id).collect::<Vec<_>>());
+		assert_eq!(1, replies[0].1.submitter);
+		let replies = SchellingGameShared::get_evidence_replies(key.clone(), 2, 0, 10);
+		assert_eq!(vec![3], replies.iter().map(|(id, _)| *id).collect::<Vec<_>>());
+		// Replies are not evidence of the challenge
+		assert_eq!(vec![1], ProfileValidation::get_challengers_evidence(1, 0, 10));
 
 		assert_noop!(
 			ProfileValidation::challenge_profile(
@@ -193,7 +249,10 @@ fn challenge_profile_after_time_for_staking_over_test() {
 				.to_vec(),
 		);
 
-		let phase_data = ProfileValidation::get_phase_data();
+		let phase_data = ProfileValidation::get_phase_data(SumTreeName::ProfileValidation {
+			citizen_address: 1,
+			block_number: 1,
+		});
 
 		assert_noop!(
 			ProfileValidation::challenge_profile(
@@ -237,7 +296,10 @@ fn return_profile_stake_test() {
 		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 1 };
 		let period = SchellingGameShared::get_period(key.clone());
 		assert_eq!(Some(Period::Evidence), period);
-		let phase_data = ProfileValidation::get_phase_data();
+		let phase_data = ProfileValidation::get_phase_data(SumTreeName::ProfileValidation {
+			citizen_address: 1,
+			block_number: 1,
+		});
 		System::set_block_number(phase_data.evidence_length + phase_data.end_of_staking_time);
 		assert_noop!(
 			ProfileValidation::return_profile_stake(RuntimeOrigin::signed(3), 1),
@@ -278,7 +340,10 @@ fn schelling_game_test() {
 				.as_bytes()
 				.to_vec(),
 		);
-		let phase_data = ProfileValidation::get_phase_data();
+		let phase_data = ProfileValidation::get_phase_data(SumTreeName::ProfileValidation {
+			citizen_address: 1,
+			block_number: 1,
+		});
 		System::set_block_number(phase_data.evidence_length + 1);
 		assert_ok!(ProfileValidation::challenge_profile(
 			RuntimeOrigin::signed(4),
@@ -413,16 +478,35 @@ fn schelling_game_test() {
 				+ phase_data.vote_length,
 		);
 		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		assert_noop!(
+			ProfileValidation::get_incentives(RuntimeOrigin::signed(14), 1),
+			<schelling_game_shared::Error<Test>>::PeriodDontMatch
+		);
+		System::set_block_number(
+			phase_data.evidence_length
+				+ 1 + phase_data.staking_length
+				+ phase_data.commit_length
+				+ phase_data.vote_length
+				+ phase_data.appeal_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
 
+		// Juror 15 did not reveal the vote, half of the stake is slashed
+		let balance: u64 = Balances::free_balance(15);
+		assert_eq!(300000 - 15 * 100, balance);
+		assert_ok!(ProfileValidation::get_incentives(RuntimeOrigin::signed(15), 1));
+		let balance: u64 = Balances::free_balance(15);
+		assert_eq!(300000 - 15 * 50, balance);
 		assert_noop!(
 			ProfileValidation::get_incentives(RuntimeOrigin::signed(15), 1),
-			<schelling_game_shared::Error<Test>>::VoteNotRevealed
+			<schelling_game_shared::Error<Test>>::AlreadyGotIncentives
 		);
 		let balance: u64 = Balances::free_balance(14);
 		assert_eq!(300000 - 14 * 100, balance);
 		assert_ok!(ProfileValidation::get_incentives(RuntimeOrigin::signed(14), 1));
 		let balance: u64 = Balances::free_balance(14);
-		assert_eq!(300025, balance);
+		// 750 / 4 from the slash of juror 15
+		assert_eq!(300187, balance);
 	})
 }
 
@@ -443,7 +527,10 @@ fn test_draw_juror() {
 				.as_bytes()
 				.to_vec(),
 		);
-		let phase_data = ProfileValidation::get_phase_data();
+		let phase_data = ProfileValidation::get_phase_data(SumTreeName::ProfileValidation {
+			citizen_address: 1,
+			block_number: 1,
+		});
 		System::set_block_number(phase_data.evidence_length + 1);
 		assert_ok!(ProfileValidation::challenge_profile(
 			RuntimeOrigin::signed(4),
@@ -471,3 +558,255 @@ fn test_draw_juror() {
 
 	})
 }
+
+#[test]
+fn voided_challenge_returns_challenger_fee_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		assert_ok!(ProfileValidation::add_citizen(RuntimeOrigin::signed(1), content.clone()));
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 1, 1000));
+		let challenge_content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhabc"
+				.as_bytes()
+				.to_vec(),
+		);
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 1 };
+		let phase_data = ProfileValidation::get_phase_data(key.clone());
+		System::set_block_number(phase_data.evidence_length + 1);
+		assert_ok!(ProfileValidation::challenge_profile(
+			RuntimeOrigin::signed(4),
+			1,
+			challenge_content.clone()
+		));
+		let fee = ProfileValidation::profile_registration_challenge_fees();
+		// `EvidenceDeposit` is reserved till the storage of the game is removed
+		assert_eq!(300000 - fee - 100, Balances::free_balance(4));
+
+		// Fewer jurors than `min_number_juror_staked` applied
+		assert_ok!(ProfileValidation::apply_jurors(RuntimeOrigin::signed(5), 1, 500));
+		assert_ok!(ProfileValidation::apply_jurors(RuntimeOrigin::signed(6), 1, 600));
+		System::set_block_number(phase_data.evidence_length + 1 + phase_data.staking_length);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		assert_ok!(ProfileValidation::draw_jurors(RuntimeOrigin::signed(5), 1, 2));
+		assert_noop!(
+			ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1),
+			<schelling_game_shared::Error<Test>>::MaxJurorNotDrawn
+		);
+
+		System::set_block_number(
+			phase_data.evidence_length + 1 + phase_data.staking_length + phase_data.drawing_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		assert_eq!(Some(Period::Execution), SchellingGameShared::get_period(key.clone()));
+		System::assert_has_event(Event::ChallengeFeeReturned { profile: 1, challenger: 4 }.into());
+		assert_eq!(300000 - 100, Balances::free_balance(4));
+		// Stakes of the jurors are returned in `on_idle`
+		SchellingGameShared::on_idle(System::block_number(), Weight::MAX);
+		assert_eq!(300000, Balances::free_balance(5));
+		assert_eq!(300000, Balances::free_balance(6));
+		assert!(ProfileValidation::challenger_fund(1).unwrap().challenge_completed);
+	});
+}
+
+#[test]
+fn recover_citizen_profile_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		assert_ok!(ProfileValidation::add_citizen(RuntimeOrigin::signed(1), content.clone()));
+		shared_storage::PositiveExternalityScore::<Test>::insert(1, 7);
+		assert_noop!(
+			ProfileValidation::create_recovery(RuntimeOrigin::signed(1), vec![1, 3], 1),
+			Error::<Test>::InvalidRecoveryConfig
+		);
+		assert_noop!(
+			ProfileValidation::create_recovery(RuntimeOrigin::signed(1), vec![3, 4], 3),
+			Error::<Test>::InvalidRecoveryConfig
+		);
+		assert_ok!(ProfileValidation::create_recovery(
+			RuntimeOrigin::signed(1),
+			vec![5, 3, 4, 3],
+			2
+		));
+		assert_eq!(ProfileValidation::recovery_config(1).unwrap().friends, vec![3, 4, 5]);
+
+		assert_noop!(
+			ProfileValidation::initiate_recovery(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::AccountHasProfile
+		);
+		assert_ok!(ProfileValidation::initiate_recovery(RuntimeOrigin::signed(6), 1));
+		assert_eq!(Balances::reserved_balance(6), 500);
+		assert_noop!(
+			ProfileValidation::initiate_recovery(RuntimeOrigin::signed(7), 1),
+			Error::<Test>::RecoveryAlreadyStarted
+		);
+		assert_ok!(ProfileValidation::vouch_recovery(RuntimeOrigin::signed(3), 1, 6));
+		assert_noop!(
+			ProfileValidation::vouch_recovery(RuntimeOrigin::signed(3), 1, 6),
+			Error::<Test>::AlreadyVouched
+		);
+		assert_noop!(
+			ProfileValidation::vouch_recovery(RuntimeOrigin::signed(7), 1, 6),
+			Error::<Test>::NotARecoveryFriend
+		);
+		assert_noop!(
+			ProfileValidation::vouch_recovery(RuntimeOrigin::signed(4), 1, 7),
+			Error::<Test>::NotRecoveryAccount
+		);
+		assert_noop!(
+			ProfileValidation::claim_recovery(RuntimeOrigin::signed(6), 1),
+			Error::<Test>::NotEnoughVouchers
+		);
+		assert_ok!(ProfileValidation::vouch_recovery(RuntimeOrigin::signed(4), 1, 6));
+		assert_noop!(
+			ProfileValidation::claim_recovery(RuntimeOrigin::signed(6), 1),
+			Error::<Test>::RecoveryDelayNotOver
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			ProfileValidation::claim_recovery(RuntimeOrigin::signed(7), 1),
+			Error::<Test>::NotRecoveryAccount
+		);
+		assert_ok!(ProfileValidation::claim_recovery(RuntimeOrigin::signed(6), 1));
+		System::assert_last_event(
+			Event::CitizenRecovered { citizen_id: 1, lost_account: 1, new_account: 6 }.into(),
+		);
+		assert_eq!(ProfileValidation::get_citizen_id(1), None);
+		assert_eq!(ProfileValidation::get_citizen_id(6), Some(1));
+		assert_eq!(ProfileValidation::citizen_profile(1), None);
+		let profile = ProfileValidation::citizen_profile(6).unwrap();
+		assert_eq!(profile.owner, 6);
+		assert_eq!(profile.content, content);
+		assert_eq!(SharedStorage::approved_citizen_address(), vec![2, 6]);
+		assert_eq!(SharedStorage::positive_externality_score(1), 0);
+		assert_eq!(SharedStorage::positive_externality_score(6), 7);
+		assert_eq!(Balances::reserved_balance(6), 0);
+		assert_eq!(Balances::free_balance(6), 300000);
+		assert_eq!(ProfileValidation::active_recovery(1), None);
+
+		// Friends stay with the citizen id after the recovery
+		assert!(ProfileValidation::recovery_config(1).is_some());
+		assert_ok!(ProfileValidation::remove_recovery(RuntimeOrigin::signed(6)));
+		assert_eq!(ProfileValidation::recovery_config(1), None);
+	});
+}
+
+#[test]
+fn challenged_recovery_gives_deposit_to_citizen_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		assert_ok!(ProfileValidation::add_citizen(RuntimeOrigin::signed(2), content));
+		assert_ok!(ProfileValidation::create_recovery(RuntimeOrigin::signed(2), vec![3, 4], 1));
+		assert_ok!(ProfileValidation::initiate_recovery(RuntimeOrigin::signed(7), 2));
+		assert_ok!(ProfileValidation::vouch_recovery(RuntimeOrigin::signed(3), 2, 7));
+		assert_noop!(
+			ProfileValidation::remove_recovery(RuntimeOrigin::signed(2)),
+			Error::<Test>::RecoveryAlreadyStarted
+		);
+		System::set_block_number(11);
+		assert_ok!(ProfileValidation::challenge_recovery(RuntimeOrigin::signed(2)));
+		System::assert_last_event(
+			Event::RecoveryChallenged { citizen_id: 1, new_account: 7 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(7), 0);
+		assert_eq!(Balances::free_balance(7), 300000 - 500);
+		assert_eq!(Balances::free_balance(2), 200000 + 500);
+		assert_noop!(
+			ProfileValidation::claim_recovery(RuntimeOrigin::signed(7), 2),
+			Error::<Test>::RecoveryNotStarted
+		);
+		assert_eq!(ProfileValidation::get_citizen_id(2), Some(1));
+	});
+}
+
+#[test]
+fn expired_recovery_is_replaced_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		assert_ok!(ProfileValidation::add_citizen(RuntimeOrigin::signed(2), content));
+		assert_ok!(ProfileValidation::create_recovery(RuntimeOrigin::signed(2), vec![3, 4], 1));
+		// Any account can start the recovery, it can not be replaced before it expires
+		assert_ok!(ProfileValidation::initiate_recovery(RuntimeOrigin::signed(7), 2));
+		System::set_block_number(30);
+		assert_noop!(
+			ProfileValidation::initiate_recovery(RuntimeOrigin::signed(8), 2),
+			Error::<Test>::RecoveryAlreadyStarted
+		);
+
+		// Recovery expires 3 times `RecoveryDelay` after it started
+		System::set_block_number(31);
+		assert_noop!(
+			ProfileValidation::vouch_recovery(RuntimeOrigin::signed(3), 2, 7),
+			Error::<Test>::RecoveryExpired
+		);
+		assert_noop!(
+			ProfileValidation::claim_recovery(RuntimeOrigin::signed(7), 2),
+			Error::<Test>::RecoveryExpired
+		);
+		assert_ok!(ProfileValidation::initiate_recovery(RuntimeOrigin::signed(8), 2));
+		System::assert_has_event(Event::RecoveryExpired { citizen_id: 1, new_account: 7 }.into());
+		// Deposit of the expired recovery is slashed
+		assert_eq!(Balances::reserved_balance(7), 0);
+		assert_eq!(Balances::free_balance(7), 300000 - 500);
+		assert_eq!(Balances::reserved_balance(8), 500);
+		assert_eq!(ProfileValidation::active_recovery(1).unwrap().new_account, 8);
+
+		assert_ok!(ProfileValidation::vouch_recovery(RuntimeOrigin::signed(3), 2, 8));
+		System::set_block_number(41);
+		assert_ok!(ProfileValidation::claim_recovery(RuntimeOrigin::signed(8), 2));
+		assert_eq!(ProfileValidation::get_citizen_id(8), Some(1));
+	});
+}
+
+#[test]
+fn migrate_challenge_evidence_out_of_the_pallet() {
+	new_test_ext().execute_with(|| {
+		let post = |owner: u64, post_id_if_comment: Option<u64>| v0::ChallengeEvidencePost::<Test> {
+			created: WhoAndWhen { account: owner, block: 1, time: 0 },
+			owner,
+			kyc_profile_id: 1,
+			content: Content::None,
+			post_id_if_comment,
+			is_comment: post_id_if_comment.is_some(),
+		};
+		v0::ChallengePost::<Test>::insert(1, post(4, None));
+		v0::ChallengePost::<Test>::insert(2, post(5, Some(1)));
+		v0::ChallengePostCommentIds::<Test>::insert(1, vec![2]);
+		v0::ChallengerEvidenceId::<Test>::insert(1, 4, 1);
+		v0::NextChallengePostId::<Test>::put(3);
+		StorageVersion::new(0).put::<ProfileValidation>();
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(ProfileValidation::on_chain_storage_version(), StorageVersion::new(1));
+		assert_eq!(v0::ChallengePost::<Test>::iter_keys().count(), 0);
+		assert_eq!(v0::ChallengePostCommentIds::<Test>::get(1), None);
+		assert_eq!(v0::ChallengerEvidenceId::<Test>::get(1, 4), None);
+		assert_eq!(v0::NextChallengePostId::<Test>::get(), None);
+
+		// Migration runs once
+		v0::NextChallengePostId::<Test>::put(3);
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(v0::NextChallengePostId::<Test>::get(), Some(3));
+	});
+}
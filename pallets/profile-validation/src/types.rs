@@ -5,7 +5,6 @@ use scale_info::TypeInfo;
 use super::*;
 
 pub const FIRST_CITIZEN_ID: CitizenId  = 1;
-pub const FIRST_CHALLENGE_POST_ID: ChallengePostId = 1;
 
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
@@ -40,13 +39,22 @@ pub struct ChallengerFundInfo<Balance, BlockNumber, AccountId> {
     pub challenge_completed: bool,
 }
 
-#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
-#[scale_info(skip_type_params(T))]
-pub struct ChallengeEvidencePost<T: Config> {
-    pub created: WhoAndWhenOf<T>,
-    pub owner: T::AccountId,
-    pub kyc_profile_id: T::AccountId,
-    pub content:  Content,
-    pub post_id_if_comment: Option<ChallengePostId>,
-    pub is_comment: bool,
-}
\ No newline at end of file
+/// Friends of the citizen who vouch for the recovery of the profile, sorted by account
+/// `threshold` friends must vouch before the profile can be recovered
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct RecoveryConfig<AccountId> {
+    pub friends: Vec<AccountId>,
+    pub threshold: u32,
+}
+
+/// Recovery of the profile to `new_account`, `deposit` is reserved from `new_account`
+/// `vouchers` are the friends who vouched, sorted by account
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct RecoveryRequest<AccountId, Balance, BlockNumber> {
+    pub new_account: AccountId,
+    pub deposit: Balance,
+    pub created: BlockNumber,
+    pub vouchers: Vec<AccountId>,
+}
@@ -38,6 +38,12 @@ pub trait WeightInfo {
 	fn do_something() -> Weight;
 	fn cause_error() -> Weight;
 	fn add_citizen() -> Weight;
+	fn create_recovery(n: u32, ) -> Weight;
+	fn remove_recovery() -> Weight;
+	fn initiate_recovery() -> Weight;
+	fn vouch_recovery(n: u32, ) -> Weight;
+	fn challenge_recovery() -> Weight;
+	fn claim_recovery(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_template using the Substrate node and recommended hardware.
@@ -71,6 +77,67 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().writes(4))
         
 	}
+	/// Storage: TemplateModule GetCitizenId (r:1 w:0)
+	/// Storage: TemplateModule ActiveRecoveries (r:1 w:0)
+	/// Storage: TemplateModule RecoveryConfigs (r:0 w:1)
+	/// The range of component `n` is `[1, 9]`.
+	fn create_recovery(n: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 3000)
+			.saturating_add(Weight::from_parts(200_000, 32).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule GetCitizenId (r:1 w:0)
+	/// Storage: TemplateModule ActiveRecoveries (r:1 w:0)
+	/// Storage: TemplateModule RecoveryConfigs (r:1 w:1)
+	fn remove_recovery() -> Weight {
+		Weight::from_parts(15_000_000, 3000)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule GetCitizenId (r:2 w:0)
+	/// Storage: TemplateModule RecoveryConfigs (r:1 w:0)
+	/// Storage: TemplateModule ActiveRecoveries (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn initiate_recovery() -> Weight {
+		Weight::from_parts(30_000_000, 3000)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule GetCitizenId (r:1 w:0)
+	/// Storage: TemplateModule RecoveryConfigs (r:1 w:0)
+	/// Storage: TemplateModule ActiveRecoveries (r:1 w:1)
+	/// The range of component `n` is `[1, 9]`.
+	fn vouch_recovery(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3000)
+			.saturating_add(Weight::from_parts(200_000, 64).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule GetCitizenId (r:1 w:0)
+	/// Storage: TemplateModule ActiveRecoveries (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn challenge_recovery() -> Weight {
+		Weight::from_parts(30_000_000, 3000)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule GetCitizenId (r:2 w:2)
+	/// Storage: TemplateModule ActiveRecoveries (r:1 w:1)
+	/// Storage: TemplateModule RecoveryConfigs (r:1 w:0)
+	/// Storage: TemplateModule CitizenProfile (r:1 w:2)
+	/// Storage: TemplateModule ProfileTotalFundCollected (r:1 w:2)
+	/// Storage: SharedStorage ApprovedCitizenAddress (r:1 w:1)
+	/// Storage: SharedStorage PositiveExternalityScore (r:1 w:2)
+	/// Storage: Ubi CitizenUbiBlock (r:2 w:2)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `n` is `[1, 9]`.
+	fn claim_recovery(n: u32, ) -> Weight {
+		Weight::from_parts(60_000_000, 6000)
+			.saturating_add(Weight::from_parts(200_000, 64).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -103,4 +170,65 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(4))
         
 	}
+	/// Storage: TemplateModule GetCitizenId (r:1 w:0)
+	/// Storage: TemplateModule ActiveRecoveries (r:1 w:0)
+	/// Storage: TemplateModule RecoveryConfigs (r:0 w:1)
+	/// The range of component `n` is `[1, 9]`.
+	fn create_recovery(n: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 3000)
+			.saturating_add(Weight::from_parts(200_000, 32).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule GetCitizenId (r:1 w:0)
+	/// Storage: TemplateModule ActiveRecoveries (r:1 w:0)
+	/// Storage: TemplateModule RecoveryConfigs (r:1 w:1)
+	fn remove_recovery() -> Weight {
+		Weight::from_parts(15_000_000, 3000)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule GetCitizenId (r:2 w:0)
+	/// Storage: TemplateModule RecoveryConfigs (r:1 w:0)
+	/// Storage: TemplateModule ActiveRecoveries (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn initiate_recovery() -> Weight {
+		Weight::from_parts(30_000_000, 3000)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule GetCitizenId (r:1 w:0)
+	/// Storage: TemplateModule RecoveryConfigs (r:1 w:0)
+	/// Storage: TemplateModule ActiveRecoveries (r:1 w:1)
+	/// The range of component `n` is `[1, 9]`.
+	fn vouch_recovery(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3000)
+			.saturating_add(Weight::from_parts(200_000, 64).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule GetCitizenId (r:1 w:0)
+	/// Storage: TemplateModule ActiveRecoveries (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn challenge_recovery() -> Weight {
+		Weight::from_parts(30_000_000, 3000)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule GetCitizenId (r:2 w:2)
+	/// Storage: TemplateModule ActiveRecoveries (r:1 w:1)
+	/// Storage: TemplateModule RecoveryConfigs (r:1 w:0)
+	/// Storage: TemplateModule CitizenProfile (r:1 w:2)
+	/// Storage: TemplateModule ProfileTotalFundCollected (r:1 w:2)
+	/// Storage: SharedStorage ApprovedCitizenAddress (r:1 w:1)
+	/// Storage: SharedStorage PositiveExternalityScore (r:1 w:2)
+	/// Storage: Ubi CitizenUbiBlock (r:2 w:2)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `n` is `[1, 9]`.
+	fn claim_recovery(n: u32, ) -> Weight {
+		Weight::from_parts(60_000_000, 6000)
+			.saturating_add(Weight::from_parts(200_000, 64).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
 }
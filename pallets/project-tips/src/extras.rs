@@ -20,8 +20,9 @@ impl<T: Config> Project<T> {
 }
 
 impl<T: Config> Pallet<T> {
-	pub(super) fn get_phase_data() -> PhaseData<T> {
-		T::SchellingGameSharedSource::create_phase_data(50, 5, 3, 100, (100, 100))
+	/// Phase data of the game, copied from the game type when the game was created
+	pub(super) fn get_phase_data(key: SumTreeNameType<T>) -> PhaseData<T> {
+		T::SchellingGameSharedSource::get_phase_data_of_game_link(key)
 	}
 
 	pub fn ensure_user_is_project_creator_and_project_exists(
@@ -92,3 +93,40 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 }
+
+impl<T: Config> OnGameVoided<SumTreeNameType<T>> for Pallet<T> {
+	/// Remove the block of the voided game so that the project creator can apply the staking period again
+	/// Stake of the project is paid when the project is created, it is kept for the next game
+	fn on_game_voided(key: SumTreeNameType<T>) {
+		let (project_id, block_number) = match key {
+			SumTreeName::ProjectTips { project_id, block_number } => (project_id, block_number),
+			_ => return,
+		};
+		if <ValidationProjectBlock<T>>::get(project_id) != Some(block_number) {
+			return
+		}
+		<ValidationProjectBlock<T>>::remove(project_id);
+		Self::deposit_event(Event::ProjectReviewVoided { project_id, block_number });
+	}
+}
+
+impl<T: Config> OnGameResolved<SumTreeNameType<T>, SchellingGameType, GameOutcome> for Pallet<T> {
+	/// Keep the decision of the schelling game of the project
+	fn on_game_resolved(
+		key: SumTreeNameType<T>,
+		_game_type: SchellingGameType,
+		outcome: GameOutcome,
+	) {
+		let (project_id, block_number) = match key {
+			SumTreeName::ProjectTips { project_id, block_number } => (project_id, block_number),
+			_ => return,
+		};
+		let validation_block = <ValidationProjectBlock<T>>::get(project_id);
+		if let GameOutcome::TwoChoice(decision) = outcome {
+			if validation_block == Some(block_number) {
+				<ProjectReviewDecision<T>>::insert(project_id, decision.clone());
+				Self::deposit_event(Event::ProjectReviewed { project_id, decision });
+			}
+		}
+	}
+}
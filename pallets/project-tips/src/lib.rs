@@ -35,8 +35,10 @@ use pallet_support::{
 	ensure_content_is_valid, new_who_and_when, remove_from_vec, Content, PositiveExternalityPostId,
 	WhoAndWhen, WhoAndWhenOf,
 };
-use schelling_game_shared::types::{Period, PhaseData, RangePoint, SchellingGameType};
-use schelling_game_shared_link::SchellingGameSharedLink;
+use schelling_game_shared::types::{
+	GameOutcome, Period, PhaseData, RangePoint, SchellingGameType, WinningDecision,
+};
+use schelling_game_shared_link::{OnGameResolved, OnGameVoided, SchellingGameSharedLink};
 use shared_storage_link::SharedStorageLink;
 use sortition_sum_game::types::SumTreeName;
 pub use types::PROJECT_ID;
@@ -120,6 +122,12 @@ pub mod pallet {
 	pub type ValidationProjectBlock<T: Config> =
 		StorageMap<_, Blake2_128Concat, ProjectId, BlockNumberOf<T>>;
 
+	/// Decision of the schelling game of the project, set when the game is resolved
+	#[pallet::storage]
+	#[pallet::getter(fn project_review_decision)]
+	pub type ProjectReviewDecision<T: Config> =
+		StorageMap<_, Blake2_128Concat, ProjectId, WinningDecision>;
+
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
 	#[pallet::event]
@@ -144,6 +152,16 @@ pub mod pallet {
 			block_number: BlockNumberOf<T>,
 			account: T::AccountId,
 		},
+		/// Schelling game of the project was resolved
+		ProjectReviewed {
+			project_id: ProjectId,
+			decision: WinningDecision,
+		},
+		/// Schelling game of the project was voided, the staking period can be applied again
+		ProjectReviewVoided {
+			project_id: ProjectId,
+			block_number: BlockNumberOf<T>,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -218,7 +236,11 @@ pub mod pallet {
 
 			<ValidationProjectBlock<T>>::insert(project_id, now.clone());
 			// check what if called again, its done with `ensure_staking_period_set_once_project_id`
-			T::SchellingGameSharedSource::set_to_staking_period_pe_link(key.clone(), now.clone())?;
+			T::SchellingGameSharedSource::set_to_staking_period_pe_link(
+				key.clone(),
+				SchellingGameType::ProjectReview,
+				now.clone(),
+			)?;
 			T::SchellingGameSharedSource::create_tree_helper_link(key, 3)?;
 
 			Self::deposit_event(Event::StakinPeriodStarted { project_id, block_number: now });
@@ -239,7 +261,7 @@ pub mod pallet {
 
 			let key = SumTreeName::ProjectTips { project_id, block_number: block_number.clone() };
 
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 
 			T::SchellingGameSharedSource::apply_jurors_helper_link(key, phase_data, who.clone(), stake)?;
 			Self::deposit_event(Event::ApplyJurors { project_id, block_number, account: who });
@@ -260,7 +282,7 @@ pub mod pallet {
 			let key = SumTreeName::ProjectTips { project_id, block_number: block_number.clone() };
 
 			let now = <frame_system::Pallet<T>>::block_number();
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 			T::SchellingGameSharedSource::change_period_link(key, phase_data, now)?;
 			Ok(())
 		}
@@ -278,7 +300,7 @@ pub mod pallet {
 
 			let key = SumTreeName::ProjectTips { project_id, block_number: block_number.clone() };
 
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 
 			T::SchellingGameSharedSource::draw_jurors_helper_link(key, phase_data, iterations)?;
 
@@ -342,7 +364,7 @@ pub mod pallet {
 			let block_number = Self::get_block_number_of_schelling_game(project_id)?;
 			let key = SumTreeName::ProjectTips { project_id, block_number: block_number.clone() };
 
-			let phase_data = Self::get_phase_data();
+			let phase_data = Self::get_phase_data(key.clone());
 			T::SchellingGameSharedSource::get_incentives_two_choice_helper_link(
 				key, phase_data, who,
 			)?;
@@ -1,6 +1,7 @@
 use crate::types::TippingName;
 use crate::{mock::*, Error, Event};
 use frame_support::{assert_noop, assert_ok};
+use pallet_support::Content;
 use sortition_sum_game::types::SumTreeName;
 use schelling_game_shared::types::Period;
 
@@ -105,7 +106,10 @@ fn schelling_game_test() {
 
 			assert_ok!(ProjectTips::apply_staking_period(RuntimeOrigin::signed(1), 1));
 
-			let phase_data = ProjectTips::get_phase_data();
+			let phase_data = ProjectTips::get_phase_data(SumTreeName::ProjectTips {
+				project_id: 1,
+				block_number: 1,
+			});
 	
 	
 			let balance = Balances::free_balance(29);
@@ -235,16 +239,120 @@ fn schelling_game_test() {
 					+ phase_data.vote_length,
 			);
 			assert_ok!(ProjectTips::pass_period(RuntimeOrigin::signed(5), 1));
+			assert_noop!(
+				ProjectTips::get_incentives(RuntimeOrigin::signed(14), 1),
+				<schelling_game_shared::Error<Test>>::PeriodDontMatch
+			);
+			System::set_block_number(
+				phase_data.evidence_length
+					+ 1 + phase_data.staking_length
+					+ phase_data.commit_length
+					+ phase_data.vote_length
+					+ phase_data.appeal_length,
+			);
+			assert_ok!(ProjectTips::pass_period(RuntimeOrigin::signed(5), 1));
 	
+			// Juror 15 did not reveal the vote, half of the stake is slashed
+			let balance: u64 = Balances::free_balance(15);
+			assert_eq!(300000 - 15 * 100, balance);
+			assert_ok!(ProjectTips::get_incentives(RuntimeOrigin::signed(15), 1));
+			let balance: u64 = Balances::free_balance(15);
+			assert_eq!(300000 - 15 * 50, balance);
 			assert_noop!(
 				ProjectTips::get_incentives(RuntimeOrigin::signed(15), 1),
-				<schelling_game_shared::Error<Test>>::VoteNotRevealed
+				<schelling_game_shared::Error<Test>>::AlreadyGotIncentives
 			);
 			let balance: u64 = Balances::free_balance(14);
 			assert_eq!(300000 - 14 * 100, balance);
 			assert_ok!(ProjectTips::get_incentives(RuntimeOrigin::signed(14), 1));
 			let balance: u64 = Balances::free_balance(14);
-			assert_eq!(300025, balance);
+			// 750 / 4 from the slash of juror 15
+			assert_eq!(300187, balance);
 	})
 
 }
+
+#[test]
+fn voided_game_lets_project_apply_staking_period_again_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let tipping_name = TippingName::SmallTipper;
+		let funding_needed = ProjectTips::value_of_tipping_name(tipping_name).max_tipping_value;
+		assert_ok!(ProjectTips::create_project(
+			RuntimeOrigin::signed(1),
+			2,
+			tipping_name,
+			funding_needed
+		));
+		assert_ok!(ProjectTips::apply_staking_period(RuntimeOrigin::signed(1), 1));
+		let key = SumTreeName::ProjectTips { project_id: 1, block_number: 1 };
+		let phase_data = ProjectTips::get_phase_data(key.clone());
+
+		// No juror applied, the game is voided after the drawing deadline
+		System::set_block_number(1 + phase_data.staking_length);
+		assert_ok!(ProjectTips::pass_period(RuntimeOrigin::signed(5), 1));
+		let now = 1 + phase_data.staking_length + phase_data.drawing_length;
+		System::set_block_number(now);
+		assert_ok!(ProjectTips::pass_period(RuntimeOrigin::signed(5), 1));
+		System::assert_has_event(
+			<schelling_game_shared::Event<Test>>::GameVoided { key: key.clone() }.into(),
+		);
+		System::assert_has_event(
+			Event::ProjectReviewVoided { project_id: 1, block_number: 1 }.into(),
+		);
+		assert_eq!(None, ProjectTips::validation_project_block_number(1));
+		assert_eq!(None, ProjectTips::project_review_decision(1));
+
+		assert_ok!(ProjectTips::apply_staking_period(RuntimeOrigin::signed(1), 1));
+		assert_eq!(Some(now), ProjectTips::validation_project_block_number(1));
+	});
+}
+
+#[test]
+fn submit_evidence_of_project_through_shared_game_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let tipping_name = TippingName::SmallTipper;
+		let funding_needed = ProjectTips::value_of_tipping_name(tipping_name).max_tipping_value;
+		assert_ok!(ProjectTips::create_project(
+			RuntimeOrigin::signed(1),
+			2,
+			tipping_name,
+			funding_needed
+		));
+		assert_ok!(ProjectTips::apply_staking_period(RuntimeOrigin::signed(1), 1));
+		let key = SumTreeName::ProjectTips { project_id: 1, block_number: 1 };
+		let phase_data = ProjectTips::get_phase_data(key.clone());
+
+		// `EvidenceDeposit` is 100
+		assert_ok!(SchellingGameShared::submit_evidence(
+			RuntimeOrigin::signed(5),
+			key.clone(),
+			content.clone(),
+			None
+		));
+		assert_eq!(100, Balances::reserved_balance(5));
+		let evidence = SchellingGameShared::get_game_evidence(key.clone(), 0, 10);
+		assert_eq!(vec![1], evidence.iter().map(|(id, _)| *id).collect::<Vec<_>>());
+		assert_eq!(5, evidence[0].1.submitter);
+		assert_eq!(content, evidence[0].1.content);
+
+		// Evidence is closed after the staking period
+		System::set_block_number(1 + phase_data.staking_length);
+		assert_ok!(ProjectTips::pass_period(RuntimeOrigin::signed(5), 1));
+		assert_noop!(
+			SchellingGameShared::submit_evidence(
+				RuntimeOrigin::signed(5),
+				key.clone(),
+				content.clone(),
+				None
+			),
+			<schelling_game_shared::Error<Test>>::PeriodDontMatch
+		);
+	});
+}
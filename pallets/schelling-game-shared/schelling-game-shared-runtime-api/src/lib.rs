@@ -0,0 +1,14 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::sp_std::prelude::*;
+use schelling_game_shared::types::{Evidence, EvidenceId, JurorRecord};
+use sortition_sum_game::types::SumTreeName;
+use sp_api::codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	pub trait SchellingGameSharedApi<AccountId, BlockNumber, Balance> where AccountId: Codec, BlockNumber: Codec, Balance: Codec {
+		fn get_juror_record(who: AccountId) -> JurorRecord;
+		fn get_game_evidence(key: SumTreeName<AccountId, BlockNumber>, offset: u64, limit: u16) -> Vec<(EvidenceId, Evidence<AccountId, Balance, BlockNumber>)>;
+		fn get_evidence_replies(key: SumTreeName<AccountId, BlockNumber>, evidence_id: EvidenceId, offset: u64, limit: u16) -> Vec<(EvidenceId, Evidence<AccountId, Balance, BlockNumber>)>;
+	}
+}
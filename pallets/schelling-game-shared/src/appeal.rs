@@ -0,0 +1,214 @@
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+	/// Number of jurors to be drawn in the current round
+	/// Each appeal doubles the jury of the previous round and adds one more juror
+	pub(super) fn max_draws_of_round(key: SumTreeNameType<T>, phase_data: PhaseDataOf<T>) -> u64 {
+		let appeal_round = <AppealRound<T>>::get(&key);
+		let mut max_draws = phase_data.max_draws;
+		for _ in 0..appeal_round {
+			max_draws = max_draws.saturating_mul(2).saturating_add(1);
+		}
+		max_draws
+	}
+
+	/// Fee to appeal the current round
+	/// It is `juror_incentives.1` scaled by the size of the next jury
+	pub(super) fn get_appeal_fee(
+		key: SumTreeNameType<T>,
+		phase_data: PhaseDataOf<T>,
+	) -> BalanceOf<T> {
+		let next_max_draws = Self::max_draws_of_round(key, phase_data.clone())
+			.saturating_mul(2)
+			.saturating_add(1);
+		let max_draws = phase_data.max_draws.max(1);
+		let fee = phase_data.juror_incentives.1.saturating_mul(next_max_draws) / max_draws;
+		Self::u64_to_balance_saturated(fee)
+	}
+
+	/// Appeal the decision of the current round
+	/// Ensure `Period` is `Appeal` and appeal time is not over
+	/// The appeal fee is moved from `who` to the juror stake account, the winners of the round share it with the penalties
+	/// Jurors of the current round are moved to `PastRoundJurors`, they get their incentives when the final round ends
+	/// The game goes back to `Period::Staking` for the new round with a larger jury
+	pub(super) fn appeal_helper(
+		key: SumTreeNameType<T>,
+		phase_data: PhaseDataOf<T>,
+		who: AccountIdOf<T>,
+		now: BlockNumberOf<T>,
+	) -> DispatchResult {
+		match <PeriodName<T>>::get(&key) {
+			Some(period) => {
+				ensure!(period == Period::Appeal, Error::<T>::PeriodDontMatch);
+			},
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+		let appeal_start_time = <AppealStartTime<T>>::get(&key);
+		let appeal_end_time = appeal_start_time
+			.checked_add(&phase_data.appeal_length)
+			.ok_or(Error::<T>::BlockNumberOverflow)?;
+		ensure!(now < appeal_end_time, Error::<T>::TimeForAppealOver);
+		let staking_end_time = now
+			.checked_add(&phase_data.staking_length)
+			.ok_or(Error::<T>::BlockNumberOverflow)?;
+		let appeal_round = <AppealRound<T>>::get(&key);
+		ensure!(appeal_round < MAX_APPEAL_ROUNDS, Error::<T>::MaxAppealsReached);
+		// Only two choice schelling game can be appealed
+		ensure!(Self::get_game_kind(&key) == GameKind::TwoChoice, Error::<T>::AppealNotAllowed);
+
+		let fee = Self::get_appeal_fee(key.clone(), phase_data.clone());
+		let imbalance = T::Currency::withdraw(
+			&who,
+			fee,
+			WithdrawReasons::FEE,
+			ExistenceRequirement::KeepAlive,
+		)?;
+		T::Currency::resolve_creating(&Self::juror_stake_account(), imbalance);
+		<Appellants<T>>::mutate(&key, |appellants| appellants.push((who.clone(), fee)));
+
+		let round_jurors = Self::get_round_jurors(key.clone());
+		<PastRoundJurors<T>>::insert(&key, appeal_round, round_jurors);
+
+		// Unstaked jurors got their stake back, remove them from the tree so that they are not drawn in the new round
+		let unstaked_jurors = <UnstakedJurors<T>>::get(&key);
+		for juror in unstaked_jurors {
+			T::SortitionSumGameSource::set_link(key.clone(), 0, juror)?;
+		}
+		<UnstakedJurors<T>>::remove(&key);
+		// Only the drawn jurors of the round can commit their votes
+		let drawn_jurors = <DrawnJurors<T>>::take(&key).len() as u32;
+		let _ = <VoteCommits<T>>::clear_prefix(&key, drawn_jurors, None);
+		<DecisionCount<T>>::remove(&key);
+		<DrawsInRound<T>>::remove(&key);
+
+		<AppealRound<T>>::insert(&key, appeal_round + 1);
+		Self::deposit_event(Event::Appealed {
+			key: key.clone(),
+			appellant: who,
+			fee,
+			round: appeal_round + 1,
+		});
+		Self::set_period(key.clone(), Period::Staking);
+		<StakingStartTime<T>>::insert(&key, now);
+		Self::schedule_period_change(key, staking_end_time);
+
+		Ok(())
+	}
+
+	/// Blocks left for ending appeal period, `None` when the end block overflows
+	pub(super) fn get_appeal_period_end_block_helper(
+		key: SumTreeNameType<T>,
+		phase_data: PhaseDataOf<T>,
+		now: BlockNumberOf<T>,
+	) -> Option<u32> {
+		let appeal_start_time = <AppealStartTime<T>>::get(&key);
+		let appeal_length = phase_data.appeal_length;
+		let end_block = appeal_start_time.checked_add(&appeal_length)?;
+		let left_block = end_block.checked_sub(&now);
+		match left_block {
+			Some(val) => {
+				let left_block_u32 = Self::block_number_to_u32_saturated(val);
+				Some(left_block_u32)
+			},
+			None => Some(0),
+		}
+	}
+
+	/// Appeal fee paid to appeal the decision of the round, it is held in the juror stake account
+	pub(super) fn get_appeal_fee_of_round(key: &SumTreeNameType<T>, round: u64) -> u64 {
+		<Appellants<T>>::get(key)
+			.get(round as usize)
+			.map(|(_, fee)| Self::balance_to_u64_saturated(*fee))
+			.unwrap_or(0)
+	}
+
+	/// Winners of the round get their share of the incentives of the round
+	pub(super) fn round_juror_getting_incentives(
+		key: &SumTreeNameType<T>,
+		round: u64,
+		who: AccountIdOf<T>,
+		stake: u64,
+		commit_vote: Option<CommitVote>,
+		winning_decision: WinningDecision,
+	) -> DispatchResult {
+		let vote = match commit_vote {
+			Some(CommitVote { revealed_vote: Some(vote), .. }) => vote,
+			Some(_) => return Self::non_participant_getting_incentives2(key, who, stake, true),
+			None => return Self::non_participant_getting_incentives2(key, who, stake, false),
+		};
+		match (winning_decision, vote) {
+			(WinningDecision::Draw, _) => {
+				let draw_incentives = Self::take_round_incentives(key, round);
+				Self::getting_incentives_draw2(key, who, draw_incentives, stake)
+			},
+			(WinningDecision::WinnerYes, RevealedVote::Yes)
+			| (WinningDecision::WinnerNo, RevealedVote::No) => {
+				let winning_incentives = Self::take_round_incentives(key, round);
+				Self::winner_getting_incentives2(key, who, winning_incentives, stake)
+			},
+			_ => Self::looser_getting_incentives2(key, who, stake),
+		}
+	}
+
+	/// Distribute incentives of `who` for all the past rounds
+	/// Returns true if `who` was a juror in any of the past rounds
+	pub(super) fn get_past_rounds_incentives_helper(
+		key: SumTreeNameType<T>,
+		who: AccountIdOf<T>,
+	) -> Result<bool, DispatchError> {
+		let appeal_round = <AppealRound<T>>::get(&key);
+		let winning_decision = Self::get_winning_decision_value(key.clone());
+		let mut juror_got_incentives = <PastRoundsIncentiveDistributedAccounts<T>>::get(&key);
+		let index = match juror_got_incentives.binary_search(&who) {
+			Ok(_) => return Ok(false),
+			Err(index) => index,
+		};
+		let mut past_round_juror = false;
+		for round in 0..appeal_round {
+			let round_jurors = <PastRoundJurors<T>>::get(&key, round);
+			if let Some((_, stake, commit_vote)) =
+				round_jurors.iter().find(|(c, _, _)| *c == who).cloned()
+			{
+				past_round_juror = true;
+				Self::round_juror_getting_incentives(
+					&key,
+					round,
+					who.clone(),
+					stake,
+					commit_vote,
+					winning_decision.clone(),
+				)?;
+			}
+		}
+		if past_round_juror {
+			juror_got_incentives.insert(index, who);
+			<PastRoundsIncentiveDistributedAccounts<T>>::insert(&key, juror_got_incentives);
+		}
+		Ok(past_round_juror)
+	}
+
+	/// Distribute incentives of all the jurors of past rounds
+	pub(super) fn get_all_past_rounds_incentives_helper(key: SumTreeNameType<T>) -> DispatchResult {
+		let appeal_round = <AppealRound<T>>::get(&key);
+		let winning_decision = Self::get_winning_decision_value(key.clone());
+		let juror_got_incentives = <PastRoundsIncentiveDistributedAccounts<T>>::get(&key);
+		for round in 0..appeal_round {
+			let round_jurors = <PastRoundJurors<T>>::take(&key, round);
+			for (account_id, stake, commit_vote) in round_jurors {
+				if juror_got_incentives.binary_search(&account_id).is_ok() {
+					continue;
+				}
+				Self::round_juror_getting_incentives(
+					&key,
+					round,
+					account_id,
+					stake,
+					commit_vote,
+					winning_decision.clone(),
+				)?;
+			}
+		}
+		<PastRoundsIncentiveDistributedAccounts<T>>::remove(&key);
+		Ok(())
+	}
+}
@@ -0,0 +1,254 @@
+use crate::*;
+
+/// Removes the storage of at most `limit` items of the game, returns the number of loops and whether all are removed
+type ClearStorageFn<T> = fn(&SumTreeNameType<T>, u32) -> (u32, bool);
+
+impl<T: Config> Pallet<T> {
+	/// Keep the result of the settled game and queue its storage for removal
+	/// Games already archived are not changed
+	pub(super) fn archive_game(key: SumTreeNameType<T>, outcome: GameOutcome) {
+		if <GameResults<T>>::contains_key(&key) {
+			return
+		}
+		let result = GameResult {
+			outcome,
+			appeal_rounds: <AppealRound<T>>::get(&key),
+			settled_at: <frame_system::Pallet<T>>::block_number(),
+		};
+		<GameResults<T>>::insert(&key, result.clone());
+		<GamesToCleanUp<T>>::append(key.clone());
+		Self::deposit_event(Event::GameArchived { key, result });
+	}
+
+	/// Jurors of the current round and of the past rounds all got their incentives
+	pub(super) fn all_jurors_got_incentives(key: SumTreeNameType<T>) -> bool {
+		let juror_got_incentives = <JurorsIncentiveDistributedAccounts<T>>::get(&key);
+		let past_juror_got_incentives = <PastRoundsIncentiveDistributedAccounts<T>>::get(&key);
+		let appeal_round = <AppealRound<T>>::get(&key);
+		<DrawnJurors<T>>::get(&key)
+			.iter()
+			.all(|(juror, _)| juror_got_incentives.binary_search(juror).is_ok()) &&
+			(0..appeal_round).all(|round| {
+				<PastRoundJurors<T>>::get(&key, round)
+					.iter()
+					.all(|(juror, _, _)| past_juror_got_incentives.binary_search(juror).is_ok())
+			})
+	}
+
+	/// `Period` of the game, archived games are in `Period::Execution` after their storage is removed
+	pub(super) fn get_period_of_game(key: SumTreeNameType<T>) -> Option<Period> {
+		match <PeriodName<T>>::get(&key) {
+			Some(period) => Some(period),
+			None if <GameResults<T>>::contains_key(&key) => Some(Period::Execution),
+			None => None,
+		}
+	}
+
+	/// Remove the storage of the archived games in the order they were archived
+	/// Votes of a game are removed over several blocks when there are more of them than the block allows
+	pub(super) fn clean_up_archived_games(remaining_weight: Weight) -> Weight {
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+		if remaining_weight.any_lt(weight) {
+			return Weight::zero()
+		}
+		let mut keys = <GamesToCleanUp<T>>::get();
+		let mut vote_removals = T::MaxVoteRemovalsPerBlock::get();
+		let mut cleaned = 0;
+		for key in keys.iter() {
+			let (used_weight, removed) = Self::clean_up_game(
+				key.clone(),
+				remaining_weight.saturating_sub(weight),
+				&mut vote_removals,
+			);
+			weight = weight.saturating_add(used_weight);
+			if !removed {
+				break
+			}
+			cleaned += 1;
+		}
+		if cleaned > 0 {
+			keys.drain(..cleaned);
+			<GamesToCleanUp<T>>::put(keys);
+		}
+		weight
+	}
+
+	/// Remove the storage of the game within `weight_limit` and `vote_removals`
+	/// Returns the used weight and whether all the storage of the game is removed
+	fn clean_up_game(
+		key: SumTreeNameType<T>,
+		weight_limit: Weight,
+		vote_removals: &mut u32,
+	) -> (Weight, bool) {
+		let storage_weight = T::WeightInfo::remove_game_storage();
+		if weight_limit.any_lt(storage_weight) {
+			return (Weight::zero(), false)
+		}
+		let vote_weight = T::DbWeight::get().reads_writes(1, 1);
+		// Account of the juror and its leaf with the parents of the leaf in a tree of `k = 3` of up to 3^16 jurors
+		let tree_stake_weight = T::DbWeight::get().reads_writes(20, 20);
+		// Account of the tree with the stake of its leaf kept for the last snapshot
+		let tree_account_weight = T::DbWeight::get().reads_writes(2, 2);
+		let weight_limit = weight_limit.saturating_sub(storage_weight);
+		let mut weight = Weight::zero();
+
+		// Stakes of the jurors and the appeal fees of a voided game are returned before their storage is removed
+		// Jurors left in the tree of any archived game get their stake back
+		// Votes, evidence and the tree are removed next, they can be more than the limit of a block
+		let clears: [(ClearStorageFn<T>, Weight); 15] = [
+			(Self::refund_voided_drawn_jurors, vote_weight),
+			(Self::refund_voided_past_round_jurors, vote_weight),
+			(Self::refund_voided_appellants, vote_weight),
+			(Self::refund_tree_stakes, tree_stake_weight),
+			(Self::clear_vote_commits, vote_weight),
+			(Self::clear_score_vote_commits, vote_weight),
+			(Self::clear_multi_choice_vote_commits, vote_weight),
+			(Self::clear_past_round_jurors, vote_weight),
+			(Self::clear_draw_entropy_commits, vote_weight),
+			(Self::clear_juror_stakes, vote_weight),
+			(Self::clear_evidence, vote_weight),
+			(Self::clear_evidence_reply_ids, vote_weight),
+			(Self::clear_game_delegations, vote_weight),
+			(Self::clear_tree, tree_account_weight),
+			(Self::clear_tree_snapshots, vote_weight),
+		];
+		for (clear, item_weight) in clears {
+			let limit = (weight_limit.saturating_sub(weight).ref_time() /
+				item_weight.ref_time().max(1))
+			.min(*vote_removals as u64) as u32;
+			if limit == 0 {
+				return (weight, false)
+			}
+			let (loops, done) = clear(&key, limit);
+			*vote_removals = vote_removals.saturating_sub(loops);
+			weight = weight.saturating_add(item_weight.saturating_mul(loops as u64));
+			if !done {
+				return (weight, false)
+			}
+		}
+
+		<PeriodName<T>>::remove(&key);
+		<DrawsInRound<T>>::remove(&key);
+		<EvidenceStartTime<T>>::remove(&key);
+		<StakingStartTime<T>>::remove(&key);
+		<DrawingStartTime<T>>::remove(&key);
+		<CommitStartTime<T>>::remove(&key);
+		<VoteStartTime<T>>::remove(&key);
+		<AppealStartTime<T>>::remove(&key);
+		<DrawnJurors<T>>::remove(&key);
+		<UnstakedJurors<T>>::remove(&key);
+		<DecisionCount<T>>::remove(&key);
+		<RevealScoreValues<T>>::remove(&key);
+		<IncentiveMeanRevealScore<T>>::remove(&key);
+		<MultiChoiceNumberOfChoices<T>>::remove(&key);
+		<MultiChoiceDecisionCount<T>>::remove(&key);
+		<JurorsIncentiveDistributedAccounts<T>>::remove(&key);
+		<JurorPenaltiesCollected<T>>::remove(&key);
+		let _ = <RoundIncentivePools<T>>::clear_prefix(&key, MAX_APPEAL_ROUNDS as u32 + 1, None);
+		<AppealRound<T>>::remove(&key);
+		<Appellants<T>>::remove(&key);
+		<PastRoundsIncentiveDistributedAccounts<T>>::remove(&key);
+		<GameType<T>>::remove(&key);
+		<GameKinds<T>>::remove(&key);
+		<GameCommitVersion<T>>::remove(&key);
+		<GamePhaseData<T>>::remove(&key);
+		<GameScoreAggregation<T>>::remove(&key);
+		<GameIncentiveCurve<T>>::remove(&key);
+		<GameRangePoint<T>>::remove(&key);
+		<DrawEntropy<T>>::remove(&key);
+		<GameCourt<T>>::remove(&key);
+		<GameEvidenceIds<T>>::remove(&key);
+		Self::deposit_event(Event::GameStorageRemoved { key });
+
+		(weight.saturating_add(storage_weight), true)
+	}
+
+	/// Return the stakes of at most `limit` jurors left in the tree of the archived game who did not unstake
+	/// Drawn jurors are removed from the tree, they get their stake with their incentives or when the voided game is refunded
+	/// Jurors of the games of a court who were not drawn are still staked in the court
+	fn refund_tree_stakes(key: &SumTreeNameType<T>, limit: u32) -> (u32, bool) {
+		if <GameCourt<T>>::contains_key(key) {
+			return (0, true)
+		}
+		let (stakes, done) = match T::SortitionSumGameSource::drain_stakes_link(key.clone(), limit)
+		{
+			Ok(result) => result,
+			Err(_) => return (0, true),
+		};
+		let unstaked_jurors = <UnstakedJurors<T>>::get(key);
+		for (juror, weight) in stakes.iter() {
+			if unstaked_jurors.binary_search(juror).is_ok() {
+				continue
+			}
+			let stake = <JurorStakes<T>>::get(key, juror).unwrap_or(*weight);
+			T::Currency::unreserve(juror, Self::u64_to_balance_saturated(stake));
+		}
+		(stakes.len() as u32, done)
+	}
+
+	/// Remove the accounts and the nodes of at most `limit` items of the sortition tree of the game
+	fn clear_tree(key: &SumTreeNameType<T>, limit: u32) -> (u32, bool) {
+		T::SortitionSumGameSource::remove_tree_link(key.clone(), limit)
+	}
+
+	/// Remove at most `limit` votes, returns the number of loops and whether all are removed
+	fn clear_vote_commits(key: &SumTreeNameType<T>, limit: u32) -> (u32, bool) {
+		let result = <VoteCommits<T>>::clear_prefix(key, limit, None);
+		(result.loops, result.maybe_cursor.is_none())
+	}
+
+	fn clear_score_vote_commits(key: &SumTreeNameType<T>, limit: u32) -> (u32, bool) {
+		let result = <ScoreVoteCommits<T>>::clear_prefix(key, limit, None);
+		(result.loops, result.maybe_cursor.is_none())
+	}
+
+	fn clear_multi_choice_vote_commits(key: &SumTreeNameType<T>, limit: u32) -> (u32, bool) {
+		let result = <MultiChoiceVoteCommits<T>>::clear_prefix(key, limit, None);
+		(result.loops, result.maybe_cursor.is_none())
+	}
+
+	fn clear_past_round_jurors(key: &SumTreeNameType<T>, limit: u32) -> (u32, bool) {
+		let result = <PastRoundJurors<T>>::clear_prefix(key, limit, None);
+		(result.loops, result.maybe_cursor.is_none())
+	}
+
+	fn clear_draw_entropy_commits(key: &SumTreeNameType<T>, limit: u32) -> (u32, bool) {
+		let result = <DrawEntropyCommits<T>>::clear_prefix(key, limit, None);
+		(result.loops, result.maybe_cursor.is_none())
+	}
+
+	fn clear_juror_stakes(key: &SumTreeNameType<T>, limit: u32) -> (u32, bool) {
+		let result = <JurorStakes<T>>::clear_prefix(key, limit, None);
+		(result.loops, result.maybe_cursor.is_none())
+	}
+
+	/// Return the deposits of at most `limit` evidence and remove them
+	fn clear_evidence(key: &SumTreeNameType<T>, limit: u32) -> (u32, bool) {
+		let mut loops = 0;
+		for (_, evidence) in <Evidences<T>>::drain_prefix(key).take(limit as usize) {
+			T::Currency::unreserve(&evidence.submitter, evidence.deposit);
+			loops += 1;
+		}
+		(loops, <Evidences<T>>::iter_prefix(key).next().is_none())
+	}
+
+	fn clear_evidence_reply_ids(key: &SumTreeNameType<T>, limit: u32) -> (u32, bool) {
+		let result = <EvidenceReplyIds<T>>::clear_prefix(key, limit, None);
+		(result.loops, result.maybe_cursor.is_none())
+	}
+
+	/// Remove the delegations of at most `limit` jurors of the game, their delegations can be withdrawn again
+	fn clear_game_delegations(key: &SumTreeNameType<T>, limit: u32) -> (u32, bool) {
+		let mut loops = 0;
+		for (juror, _) in <GameDelegations<T>>::drain_prefix(key).take(limit as usize) {
+			<JurorActiveGames<T>>::mutate(&juror, |games| *games = games.saturating_sub(1));
+			loops += 1;
+		}
+		(loops, <GameDelegations<T>>::iter_prefix(key).next().is_none())
+	}
+
+	/// Remove the stakes of at most `limit` jurors kept in the snapshots of the tree of the game
+	fn clear_tree_snapshots(key: &SumTreeNameType<T>, limit: u32) -> (u32, bool) {
+		T::SortitionSumGameSource::remove_snapshots_link(key.clone(), limit)
+	}
+}
@@ -31,5 +31,305 @@ mod benchmarks {
 		assert_eq!(Something::<T>::get(), Some(101u32));
 	}
 
+	#[benchmark]
+	fn change_scheduled_period() {
+		let key = SumTreeName::ProfileValidation {
+			citizen_address: whitelisted_caller(),
+			block_number: 0u32.into(),
+		};
+		let phase_data = PhaseData::<T>::default();
+		let now = phase_data.commit_length;
+		<PeriodName<T>>::insert(&key, Period::Commit);
+		<GamePhaseData<T>>::insert(&key, phase_data);
+		Template::<T>::schedule_period_change(key.clone(), now);
+
+		#[block]
+		{
+			Template::<T>::change_scheduled_periods(now);
+		}
+
+		assert_eq!(<PeriodName<T>>::get(&key), Some(Period::Vote));
+	}
+
+	#[benchmark]
+	fn set_phase_data() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PhaseDataOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let phase_data = PhaseData::<T>::default();
+
+		#[extrinsic_call]
+		set_phase_data(
+			origin as T::RuntimeOrigin,
+			SchellingGameType::ProfileApproval,
+			phase_data.clone(),
+		);
+
+		assert_eq!(
+			<GameTypePhaseData<T>>::get(SchellingGameType::ProfileApproval),
+			Some(phase_data)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_score_aggregation() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PhaseDataOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		set_score_aggregation(
+			origin as T::RuntimeOrigin,
+			SchellingGameType::ProfileScore,
+			ScoreAggregation::MedianAbsoluteDeviation,
+		);
+
+		assert_eq!(
+			<GameTypeScoreAggregation<T>>::get(SchellingGameType::ProfileScore),
+			ScoreAggregation::MedianAbsoluteDeviation
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_incentive_curve() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PhaseDataOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let curve = IncentiveCurve::Linear { cap: Perbill::from_percent(50) };
+
+		#[extrinsic_call]
+		set_incentive_curve(
+			origin as T::RuntimeOrigin,
+			SchellingGameType::ProfileScore,
+			curve.clone(),
+		);
+
+		assert_eq!(<GameTypeIncentiveCurve<T>>::get(SchellingGameType::ProfileScore), curve);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_game_storage() {
+		let key = SumTreeName::ProfileValidation {
+			citizen_address: whitelisted_caller(),
+			block_number: 0u32.into(),
+		};
+		<PeriodName<T>>::insert(&key, Period::Execution);
+		<GamePhaseData<T>>::insert(&key, PhaseData::<T>::default());
+		<DecisionCount<T>>::insert(&key, (1, 2));
+		Template::<T>::archive_game(
+			key.clone(),
+			GameOutcome::TwoChoice(WinningDecision::WinnerYes),
+		);
+
+		#[block]
+		{
+			Template::<T>::clean_up_archived_games(Weight::MAX);
+		}
+
+		assert_eq!(<PeriodName<T>>::get(&key), None);
+		assert!(<GameResults<T>>::contains_key(&key));
+	}
+
+	#[benchmark]
+	fn commit_draw_entropy() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let key = SumTreeName::ProfileValidation {
+			citizen_address: caller.clone(),
+			block_number: 0u32.into(),
+		};
+		<PeriodName<T>>::insert(&key, Period::Staking);
+		T::SortitionSumGameSource::create_tree_link(key.clone(), 3)?;
+		T::SortitionSumGameSource::set_link(key.clone(), 100, caller.clone())?;
+		let entropy_commit = sp_io::hashing::keccak_256(&[1u8; 32]);
+
+		#[extrinsic_call]
+		commit_draw_entropy(RawOrigin::Signed(caller.clone()), key.clone(), entropy_commit);
+
+		assert_eq!(<DrawEntropyCommits<T>>::get(&key, &caller), Some(entropy_commit));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reveal_draw_entropy() {
+		let caller: T::AccountId = whitelisted_caller();
+		let key = SumTreeName::ProfileValidation {
+			citizen_address: caller.clone(),
+			block_number: 0u32.into(),
+		};
+		let entropy = [1u8; 32];
+		<PeriodName<T>>::insert(&key, Period::Drawing);
+		<DrawEntropyCommits<T>>::insert(&key, &caller, sp_io::hashing::keccak_256(&entropy));
+		<DrawEntropy<T>>::insert(&key, [2u8; 32]);
+
+		#[extrinsic_call]
+		reveal_draw_entropy(RawOrigin::Signed(caller.clone()), key.clone(), entropy);
+
+		assert!(!<DrawEntropyCommits<T>>::contains_key(&key, &caller));
+	}
+
+	#[benchmark]
+	fn create_court() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PhaseDataOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let min_stake = Template::<T>::u64_to_balance_saturated(100);
+		Template::<T>::create_court_helper(0, None, min_stake, PhaseData::<T>::default())?;
+
+		#[extrinsic_call]
+		create_court(origin as T::RuntimeOrigin, 1, Some(0), min_stake, PhaseData::<T>::default());
+
+		assert_eq!(<Courts<T>>::get(1).map(|court| court.parent), Some(Some(0)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn stake_in_court() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let stake = Template::<T>::u64_to_balance_saturated(100);
+		T::Currency::make_free_balance_be(&caller, stake + stake);
+		Template::<T>::create_court_helper(0, None, stake, PhaseData::<T>::default())?;
+
+		#[extrinsic_call]
+		stake_in_court(RawOrigin::Signed(caller.clone()), 0, stake);
+
+		assert_eq!(<JurorCourts<T>>::get(&caller), Some((0, 100)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unstake_from_court() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let stake = Template::<T>::u64_to_balance_saturated(100);
+		T::Currency::make_free_balance_be(&caller, stake + stake);
+		Template::<T>::create_court_helper(0, None, stake, PhaseData::<T>::default())?;
+		Template::<T>::stake_in_court_helper(caller.clone(), 0, stake)?;
+
+		#[extrinsic_call]
+		unstake_from_court(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(<JurorCourts<T>>::get(&caller), None);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn submit_evidence() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let key = SumTreeName::ProfileValidation {
+			citizen_address: caller.clone(),
+			block_number: 0u32.into(),
+		};
+		let content =
+			Content::IPFS(b"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy".to_vec());
+		T::Currency::make_free_balance_be(
+			&caller,
+			T::EvidenceDeposit::get() + T::EvidenceDeposit::get() + T::Currency::minimum_balance(),
+		);
+		<PeriodName<T>>::insert(&key, Period::Evidence);
+		Template::<T>::submit_evidence_helper(key.clone(), caller.clone(), content.clone(), None)?;
+		let evidence_id = <NextEvidenceId<T>>::get() - 1;
+
+		#[extrinsic_call]
+		submit_evidence(RawOrigin::Signed(caller.clone()), key.clone(), content, Some(evidence_id));
+
+		assert_eq!(<EvidenceReplyIds<T>>::get(&key, evidence_id).len(), 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_range_point() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PhaseDataOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		set_range_point(
+			origin as T::RuntimeOrigin,
+			SchellingGameType::PositiveExternality,
+			RangePoint::ZeroToFive,
+		);
+
+		assert_eq!(
+			<GameTypeRangePoint<T>>::get(SchellingGameType::PositiveExternality),
+			RangePoint::ZeroToFive
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn distribute_incentives(n: Linear<1, 100>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let key = SumTreeName::ProfileValidation {
+			citizen_address: caller.clone(),
+			block_number: 0u32.into(),
+		};
+		let stake = 100u64;
+		let mut drawn_jurors = Vec::new();
+		for i in 0..n {
+			let juror: T::AccountId = account("juror", i, 0);
+			T::Currency::make_free_balance_be(
+				&juror,
+				Template::<T>::u64_to_balance_saturated(stake) + T::Currency::minimum_balance(),
+			);
+			T::Currency::reserve(&juror, Template::<T>::u64_to_balance_saturated(stake))?;
+			// Every other juror voted against the winning decision
+			let vote = if i % 2 == 0 { RevealedVote::Yes } else { RevealedVote::No };
+			let commit_vote = CommitVote {
+				commit: [0u8; 32],
+				votestatus: VoteStatus::Revealed,
+				revealed_vote: Some(vote),
+			};
+			<VoteCommits<T>>::insert(&key, &juror, commit_vote);
+			drawn_jurors.push((juror, stake));
+		}
+		drawn_jurors.sort();
+		<DrawnJurors<T>>::insert(&key, drawn_jurors);
+		<DecisionCount<T>>::insert(&key, (n as u64 / 2, (n as u64 + 1) / 2));
+		<PeriodName<T>>::insert(&key, Period::Execution);
+
+		#[extrinsic_call]
+		distribute_incentives(RawOrigin::Signed(caller), key.clone(), n);
+
+		assert_eq!(
+			<IncentiveProgresses<T>>::get(&key).map(|progress| progress.stage),
+			Some(IncentiveStage::Rewards)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn delegate_stake() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let juror: T::AccountId = account("juror", 0, 0);
+		let stake = Template::<T>::u64_to_balance_saturated(100);
+		T::Currency::make_free_balance_be(&caller, stake + stake);
+		T::Currency::make_free_balance_be(&juror, stake + stake);
+		Template::<T>::create_court_helper(0, None, stake, PhaseData::<T>::default())?;
+		Template::<T>::stake_in_court_helper(juror.clone(), 0, stake)?;
+		let target = DelegationTarget::Court(0);
+
+		#[extrinsic_call]
+		delegate_stake(RawOrigin::Signed(caller.clone()), juror.clone(), target.clone(), stake);
+
+		assert_eq!(<Delegations<T>>::get(&juror, &target), [(caller, 100)].to_vec());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_delegation() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let juror: T::AccountId = account("juror", 0, 0);
+		let stake = Template::<T>::u64_to_balance_saturated(100);
+		T::Currency::make_free_balance_be(&caller, stake + stake);
+		T::Currency::make_free_balance_be(&juror, stake + stake);
+		Template::<T>::create_court_helper(0, None, stake, PhaseData::<T>::default())?;
+		Template::<T>::stake_in_court_helper(juror.clone(), 0, stake)?;
+		let target = DelegationTarget::Court(0);
+		Template::<T>::delegate_stake_helper(caller.clone(), juror.clone(), target.clone(), stake)?;
+
+		#[extrinsic_call]
+		withdraw_delegation(RawOrigin::Signed(caller.clone()), juror.clone(), target.clone());
+
+		assert!(<Delegations<T>>::get(&juror, &target).is_empty());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
 }
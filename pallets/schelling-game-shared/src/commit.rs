@@ -0,0 +1,43 @@
+use crate::*;
+use frame_support::sp_runtime::traits::Zero;
+use frame_support::sp_std::fmt::Display;
+
+/// Commit of the vote of `juror` in the game `key`, see `CommitVersion` for the formats
+/// Choice of two choice game is `u128`, of score game `i64` and of multi choice game `u32`
+pub fn vote_commit<Hash, Key, AccountId, Choice>(
+	version: CommitVersion,
+	genesis_hash: &Hash,
+	key: &Key,
+	juror: &AccountId,
+	choice: Choice,
+	salt: &[u8],
+) -> [u8; 32]
+where
+	Hash: Encode,
+	Key: Encode,
+	AccountId: Encode,
+	Choice: Encode + Display,
+{
+	match version {
+		CommitVersion::V0 => {
+			let mut vote = format!("{}", choice).as_bytes().to_vec();
+			vote.extend_from_slice(salt);
+			sp_io::hashing::keccak_256(&vote)
+		},
+		CommitVersion::V1 =>
+			sp_io::hashing::keccak_256(&(genesis_hash, key, juror, choice, salt).encode()),
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Commit of the vote of `who` in the format of the game
+	pub(super) fn get_vote_commit<Choice: Encode + Display>(
+		key: &SumTreeNameType<T>,
+		who: &AccountIdOf<T>,
+		choice: Choice,
+		salt: &[u8],
+	) -> [u8; 32] {
+		let genesis_hash = <frame_system::Pallet<T>>::block_hash(T::BlockNumber::zero());
+		vote_commit(<GameCommitVersion<T>>::get(key), &genesis_hash, key, who, choice, salt)
+	}
+}
@@ -0,0 +1,178 @@
+use crate::*;
+use scale_info::prelude::vec;
+
+impl<T: Config> Pallet<T> {
+	/// Key of the sortition tree of the jurors staked in the court
+	pub(super) fn court_tree_key(court_id: CourtId) -> SumTreeNameType<T> {
+		SumTreeName::Court { court_id }
+	}
+
+	/// Court and its ancestors, starting with the court
+	pub(super) fn get_court_and_ancestors(court_id: CourtId) -> Vec<CourtId> {
+		let mut courts = vec![court_id];
+		let mut parent = <Courts<T>>::get(court_id).and_then(|court| court.parent);
+		while let Some(court_id) = parent {
+			courts.push(court_id);
+			parent = <Courts<T>>::get(court_id).and_then(|court| court.parent);
+		}
+		courts
+	}
+
+	/// Create a court with its sortition tree, the parent court must exist
+	/// Courts are at most `MAX_COURT_DEPTH` deep
+	pub(super) fn create_court_helper(
+		court_id: CourtId,
+		parent: Option<CourtId>,
+		min_stake: BalanceOf<T>,
+		phase_data: PhaseDataOf<T>,
+	) -> DispatchResult {
+		ensure!(!<Courts<T>>::contains_key(court_id), Error::<T>::CourtExists);
+		ensure!(phase_data.max_draws > 0, Error::<T>::InvalidPhaseData);
+		if let Some(parent) = parent {
+			ensure!(<Courts<T>>::contains_key(parent), Error::<T>::CourtDoesNotExist);
+			ensure!(
+				Self::get_court_and_ancestors(parent).len() < MAX_COURT_DEPTH as usize,
+				Error::<T>::MaxCourtDepthReached
+			);
+		}
+		T::SortitionSumGameSource::create_tree_link(Self::court_tree_key(court_id), COURT_TREE_K)?;
+		<Courts<T>>::insert(court_id, Court { parent, min_stake, phase_data });
+		Self::deposit_event(Event::CourtCreated { court_id, parent });
+		Ok(())
+	}
+
+	/// Stake in the court, the juror can be drawn for the disputes of the court and of its children
+	/// A juror stakes in one court at a time
+	pub(super) fn stake_in_court_helper(
+		who: AccountIdOf<T>,
+		court_id: CourtId,
+		stake: BalanceOf<T>,
+	) -> DispatchResult {
+		let court = <Courts<T>>::get(court_id).ok_or(Error::<T>::CourtDoesNotExist)?;
+		ensure!(stake >= court.min_stake, Error::<T>::JurorStakeLessThanMin);
+		ensure!(!<JurorCourts<T>>::contains_key(&who), Error::<T>::AlreadyStaked);
+		let stake_u64 = Self::balance_to_u64_saturated(stake);
+		// Stake delegated to the juror for the court adds to the weight
+		let draw_weight = Self::get_draw_weight(&who, stake_u64)?
+			.saturating_add(Self::get_delegated_stake(&who, &DelegationTarget::Court(court_id)));
+		T::Currency::reserve(&who, stake)?;
+		<JurorCourts<T>>::insert(&who, (court_id, stake_u64));
+		T::SortitionSumGameSource::set_link(
+			Self::court_tree_key(court_id),
+			draw_weight,
+			who.clone(),
+		)?;
+		Self::deposit_event(Event::JurorStakedInCourt { court_id, juror: who, stake });
+		Ok(())
+	}
+
+	/// Leave the court and get back the stake, jurors drawn for a dispute leave after the game returns the stake
+	pub(super) fn unstake_from_court_helper(who: AccountIdOf<T>) -> DispatchResult {
+		ensure!(!<CourtDraws<T>>::contains_key(&who), Error::<T>::SelectedAsJuror);
+		let (court_id, stake) =
+			<JurorCourts<T>>::take(&who).ok_or(Error::<T>::StakeDoesNotExists)?;
+		T::SortitionSumGameSource::set_link(Self::court_tree_key(court_id), 0, who.clone())?;
+		let stake = Self::u64_to_balance_saturated(stake);
+		T::Currency::unreserve(&who, stake);
+		Self::deposit_event(Event::JurorUnstakedFromCourt { court_id, juror: who, stake });
+		Ok(())
+	}
+
+	/// Jurors of the game are drawn from the court and its ancestors
+	/// It is called when the game is created, the phase data of the court is used for the game
+	pub(super) fn set_court_of_game_helper(
+		key: SumTreeNameType<T>,
+		court_id: CourtId,
+	) -> DispatchResult {
+		match <PeriodName<T>>::get(&key) {
+			Some(period) => {
+				ensure!(
+					period == Period::Evidence || period == Period::Staking,
+					Error::<T>::PeriodDontMatch
+				);
+			},
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+		let court = <Courts<T>>::get(court_id).ok_or(Error::<T>::CourtDoesNotExist)?;
+		<GamePhaseData<T>>::insert(&key, court.phase_data);
+		<GameCourt<T>>::insert(&key, court_id);
+		Self::deposit_event(Event::GameCourtSet { key, court_id });
+		Ok(())
+	}
+
+	/// Draw a juror from the court and its ancestors, every staked juror has odds by the weight in the tree
+	/// Drawn juror stays in the court, the weight is zero till the game returns the stake with `return_juror_stake`
+	/// Returns the juror, the stake and the court the juror staked in
+	pub(super) fn draw_court_juror(
+		key: &SumTreeNameType<T>,
+		court_id: CourtId,
+		random_number: u64,
+	) -> Result<(AccountIdOf<T>, u64, CourtId), DispatchError> {
+		let mut court_stakes = vec![];
+		let mut total_stake = 0u64;
+		for court_id in Self::get_court_and_ancestors(court_id) {
+			let court_key = Self::court_tree_key(court_id);
+			let court_stake = T::SortitionSumGameSource::total_stake_link(court_key.clone())?;
+			total_stake = total_stake.saturating_add(court_stake);
+			court_stakes.push((court_key, court_stake));
+		}
+		ensure!(total_stake > 0, Error::<T>::NoJurorsInCourt);
+		let mut draw_number = random_number % total_stake;
+		for (court_key, court_stake) in court_stakes {
+			if draw_number < court_stake {
+				let juror = T::SortitionSumGameSource::draw_link(court_key.clone(), draw_number)?;
+				let (staked_court, stake) =
+					<JurorCourts<T>>::get(&juror).ok_or(Error::<T>::StakeDoesNotExists)?;
+				T::SortitionSumGameSource::set_link(court_key, 0, juror.clone())?;
+				<CourtDraws<T>>::insert(&juror, key);
+				return Ok((juror, stake, staked_court))
+			}
+			draw_number -= court_stake;
+		}
+		Err(Error::<T>::NoJurorsInCourt)?
+	}
+
+	/// Return the stake of the drawn juror after the game, the penalty is already taken from `stake`
+	/// Jurors drawn from a court stay in the court with the returned stake and can be drawn again
+	/// They leave the court when the returned stake is less than the minimum stake of the court
+	pub(super) fn return_juror_stake(key: &SumTreeNameType<T>, who: &AccountIdOf<T>, stake: u64) {
+		let court_stake = match <CourtDraws<T>>::get(who) {
+			Some(game) if game == *key => <JurorCourts<T>>::get(who),
+			_ => None,
+		};
+		let balance = Self::u64_to_balance_saturated(stake);
+		let (court_id, _) = match court_stake {
+			Some(court_stake) => court_stake,
+			None => {
+				T::Currency::unreserve(who, balance);
+				return
+			},
+		};
+		<CourtDraws<T>>::remove(who);
+		let min_stake = <Courts<T>>::get(court_id).map(|court| court.min_stake);
+		if min_stake.map_or(false, |min_stake| balance >= min_stake) {
+			<JurorCourts<T>>::insert(who, (court_id, stake));
+			// Jurors whose reputation fell below the threshold stay in the court but are not drawn
+			let weight = match Self::get_draw_weight(who, stake) {
+				Ok(weight) => weight.saturating_add(Self::get_delegated_stake(
+					who,
+					&DelegationTarget::Court(court_id),
+				)),
+				Err(_) => 0,
+			};
+			let _ = T::SortitionSumGameSource::set_link(
+				Self::court_tree_key(court_id),
+				weight,
+				who.clone(),
+			);
+		} else {
+			<JurorCourts<T>>::remove(who);
+			T::Currency::unreserve(who, balance);
+			Self::deposit_event(Event::JurorUnstakedFromCourt {
+				court_id,
+				juror: who.clone(),
+				stake: balance,
+			});
+		}
+	}
+}
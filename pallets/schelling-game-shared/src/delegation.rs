@@ -0,0 +1,200 @@
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+	/// Delegate stake to the juror for the game type or the court
+	/// Stake delegated again by the same delegator adds to the delegated stake
+	pub(super) fn delegate_stake_helper(
+		who: AccountIdOf<T>,
+		juror: AccountIdOf<T>,
+		target: DelegationTarget,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(who != juror, Error::<T>::InvalidDelegation);
+		let amount_u64 = Self::balance_to_u64_saturated(amount);
+		ensure!(amount_u64 > 0, Error::<T>::InvalidDelegation);
+		if let DelegationTarget::Court(court_id) = target {
+			ensure!(<Courts<T>>::contains_key(court_id), Error::<T>::CourtDoesNotExist);
+		}
+		let mut delegators = <Delegations<T>>::get(&juror, &target);
+		match delegators.binary_search_by(|(c, _)| c.cmp(&who)) {
+			Ok(index) => delegators[index].1 = delegators[index].1.saturating_add(amount_u64),
+			Err(index) => {
+				ensure!(
+					delegators.len() < T::MaxDelegatorsPerJuror::get() as usize,
+					Error::<T>::MaxDelegatorsReached
+				);
+				delegators.insert(index, (who.clone(), amount_u64));
+			},
+		}
+		T::Currency::reserve(&who, amount)?;
+		<Delegations<T>>::insert(&juror, &target, delegators);
+		if let DelegationTarget::Court(court_id) = target {
+			Self::update_court_weight(&juror, court_id)?;
+		}
+		Self::deposit_event(Event::StakeDelegated { delegator: who, juror, target, amount });
+		Ok(())
+	}
+
+	/// Withdraw the stake delegated to the juror, the delegations must not be in a game
+	pub(super) fn withdraw_delegation_helper(
+		who: AccountIdOf<T>,
+		juror: AccountIdOf<T>,
+		target: DelegationTarget,
+	) -> DispatchResult {
+		ensure!(<JurorActiveGames<T>>::get(&juror) == 0, Error::<T>::JurorInActiveGame);
+		let mut delegators = <Delegations<T>>::get(&juror, &target);
+		let index = delegators
+			.binary_search_by(|(c, _)| c.cmp(&who))
+			.map_err(|_| Error::<T>::DelegationDoesNotExist)?;
+		let (_, amount) = delegators.remove(index);
+		if delegators.is_empty() {
+			<Delegations<T>>::remove(&juror, &target);
+		} else {
+			<Delegations<T>>::insert(&juror, &target, delegators);
+		}
+		let amount = Self::u64_to_balance_saturated(amount);
+		T::Currency::unreserve(&who, amount);
+		if let DelegationTarget::Court(court_id) = target {
+			Self::update_court_weight(&juror, court_id)?;
+		}
+		Self::deposit_event(Event::DelegationWithdrawn { delegator: who, juror, target, amount });
+		Ok(())
+	}
+
+	/// Total stake delegated to the juror for the game type or the court
+	pub(super) fn get_delegated_stake(juror: &AccountIdOf<T>, target: &DelegationTarget) -> u64 {
+		<Delegations<T>>::get(juror, target)
+			.iter()
+			.fold(0u64, |total, (_, amount)| total.saturating_add(*amount))
+	}
+
+	/// Weight of the juror staked in the court is changed with the delegated stake
+	/// Jurors who are not staked in the court get the delegated stake when they stake
+	/// Jurors drawn for a game get the delegated stake when the game returns their stake
+	fn update_court_weight(juror: &AccountIdOf<T>, court_id: CourtId) -> DispatchResult {
+		if <CourtDraws<T>>::contains_key(juror) {
+			return Ok(())
+		}
+		if let Some((staked_court, stake)) = <JurorCourts<T>>::get(juror) {
+			if staked_court == court_id {
+				// Delegations are withdrawn even if the reputation of the juror fell below the threshold
+				let weight = Self::get_draw_weight(juror, stake).unwrap_or(stake).saturating_add(
+					Self::get_delegated_stake(juror, &DelegationTarget::Court(court_id)),
+				);
+				T::SortitionSumGameSource::set_link(
+					Self::court_tree_key(court_id),
+					weight,
+					juror.clone(),
+				)?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Keep the delegations of the juror for the game, delegations can not be withdrawn till the game storage is removed
+	pub(super) fn snapshot_game_delegations(
+		key: &SumTreeNameType<T>,
+		juror: &AccountIdOf<T>,
+		target: DelegationTarget,
+	) {
+		let delegators = <Delegations<T>>::get(juror, &target);
+		if delegators.is_empty() || <GameDelegations<T>>::contains_key(key, juror) {
+			return
+		}
+		<GameDelegations<T>>::insert(key, juror, (target, delegators));
+		<JurorActiveGames<T>>::mutate(juror, |games| *games = games.saturating_add(1));
+	}
+
+	/// Juror left the game, the delegations can be withdrawn if the juror is not in other games
+	pub(super) fn remove_game_delegations(key: &SumTreeNameType<T>, juror: &AccountIdOf<T>) {
+		if <GameDelegations<T>>::take(key, juror).is_some() {
+			<JurorActiveGames<T>>::mutate(juror, |games| *games = games.saturating_sub(1));
+		}
+	}
+
+	/// Pay `incentives` of the juror from the juror stake account, returns the amount paid to the juror
+	/// Delegators are paid their share of the incentives by their stake before the juror
+	pub(super) fn pay_incentives_with_delegators(
+		key: &SumTreeNameType<T>,
+		juror: &AccountIdOf<T>,
+		stake: u64,
+		incentives: u64,
+	) -> Result<u64, DispatchError> {
+		let delegators = match <GameDelegations<T>>::get(key, juror) {
+			Some((_, delegators)) => delegators,
+			None => return Self::pay_incentives(juror, incentives),
+		};
+		let total_stake = delegators
+			.iter()
+			.fold(stake as u128, |total, (_, amount)| total.saturating_add(*amount as u128));
+		let mut delegators_share = 0u64;
+		let mut delegators_rewarded = 0u64;
+		for (delegator, amount) in delegators.iter() {
+			let reward_share = (incentives as u128 * *amount as u128 / total_stake) as u64;
+			if reward_share > 0 {
+				delegators_share = delegators_share.saturating_add(reward_share);
+				let rewarded = Self::pay_incentives(delegator, reward_share)?;
+				delegators_rewarded = delegators_rewarded.saturating_add(rewarded);
+			}
+		}
+		let rewarded = Self::pay_incentives(juror, incentives.saturating_sub(delegators_share))?;
+		Self::deposit_event(Event::DelegatorsIncentivesShared {
+			key: key.clone(),
+			juror: juror.clone(),
+			rewarded: Self::u64_to_balance_saturated(delegators_rewarded),
+			slashed: Zero::zero(),
+		});
+		Ok(rewarded)
+	}
+
+	/// Stake delegated to the juror for the game loses the same part as the stake of the juror
+	/// Slashed delegated stake is handed to `T::Slash`
+	pub(super) fn slash_delegators(
+		key: &SumTreeNameType<T>,
+		juror: &AccountIdOf<T>,
+		stake: u64,
+		slashed: u64,
+	) {
+		if slashed == 0 || stake == 0 {
+			return
+		}
+		let (target, mut delegators) = match <GameDelegations<T>>::get(key, juror) {
+			Some(game_delegations) => game_delegations,
+			None => return,
+		};
+		let mut current_delegators = <Delegations<T>>::get(juror, &target);
+		let mut total_slashed = 0u64;
+		for (delegator, amount) in delegators.iter_mut() {
+			let slash_share = (*amount as u128 * slashed.min(stake) as u128 / stake as u128) as u64;
+			if slash_share == 0 {
+				continue
+			}
+			let (imbalance, not_slashed) =
+				T::Currency::slash_reserved(delegator, Self::u64_to_balance_saturated(slash_share));
+			T::Slash::on_unbalanced(imbalance);
+			let slash_share =
+				slash_share.saturating_sub(Self::balance_to_u64_saturated(not_slashed));
+			*amount = amount.saturating_sub(slash_share);
+			if let Ok(index) = current_delegators.binary_search_by(|(c, _)| c.cmp(delegator)) {
+				let current_amount = &mut current_delegators[index].1;
+				*current_amount = current_amount.saturating_sub(slash_share);
+			}
+			total_slashed = total_slashed.saturating_add(slash_share);
+		}
+		if total_slashed > 0 {
+			current_delegators.retain(|(_, amount)| *amount > 0);
+			if current_delegators.is_empty() {
+				<Delegations<T>>::remove(juror, &target);
+			} else {
+				<Delegations<T>>::insert(juror, &target, current_delegators);
+			}
+			<GameDelegations<T>>::insert(key, juror, (target, delegators));
+		}
+		Self::deposit_event(Event::DelegatorsIncentivesShared {
+			key: key.clone(),
+			juror: juror.clone(),
+			rewarded: Zero::zero(),
+			slashed: Self::u64_to_balance_saturated(total_slashed),
+		});
+	}
+}
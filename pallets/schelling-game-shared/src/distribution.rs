@@ -0,0 +1,317 @@
+use crate::*;
+
+/// Incentives of a juror of the round
+enum JurorIncentive {
+	/// Juror shares the penalties of the round by the weight
+	Winner(u64),
+	/// Penalty is slashed from the stake of the juror
+	Loser(u64),
+	/// There is no winning decision, the stake is returned and the penalties are shared equally
+	Draw,
+	/// Juror did not commit or did not reveal the vote
+	NotParticipated { committed: bool },
+}
+
+/// Outcome of the game that the jurors get their incentives against
+enum IncentiveOutcome {
+	TwoChoice(WinningDecision),
+	Score { score: i64, window: i64, curve: IncentiveCurve },
+	MultiChoice(Option<u32>),
+}
+
+impl<T: Config> Pallet<T> {
+	/// Distribute the incentives of at most `max_jurors` jurors of the game
+	/// Penalties of a round are collected from all its jurors before the winners of the round get their share
+	/// Penalties and the appeal fee of a round without winners are given to the winners of the next round
+	/// Game is archived when the jurors of all the rounds got their incentives
+	pub(super) fn distribute_incentives_helper(
+		key: SumTreeNameType<T>,
+		max_jurors: u32,
+	) -> DispatchResult {
+		match <PeriodName<T>>::get(&key) {
+			Some(period) => {
+				ensure!(period == Period::Execution, Error::<T>::PeriodDontMatch);
+			},
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+		Self::ensure_game_not_voided(key.clone())?;
+		ensure!(!<GameResults<T>>::contains_key(&key), Error::<T>::AlreadyGotIncentives);
+		let mut progress = match <IncentiveProgresses<T>>::get(&key) {
+			Some(progress) => progress,
+			None => Self::start_incentive_distribution(key.clone())?,
+		};
+		let outcome = Self::get_incentive_outcome(key.clone(), &progress);
+		let appeal_round = <AppealRound<T>>::get(&key);
+		let mut round_jurors = Self::get_jurors_of_round(&key, progress.round, appeal_round);
+		let mut jurors = 0;
+		loop {
+			if progress.next_juror as usize >= round_jurors.len() {
+				match progress.stage {
+					IncentiveStage::Penalties => {
+						progress.stage = IncentiveStage::Rewards;
+						progress.next_juror = 0;
+					},
+					IncentiveStage::Rewards if progress.round < appeal_round => {
+						<PastRoundJurors<T>>::remove(&key, progress.round);
+						progress = IncentiveProgress {
+							round: progress.round + 1,
+							penalties: Self::get_unpaid_penalties(&progress).saturating_add(
+								Self::get_appeal_fee_of_round(&key, progress.round + 1),
+							),
+							score_window: progress.score_window,
+							..Default::default()
+						};
+						round_jurors =
+							Self::get_jurors_of_round(&key, progress.round, appeal_round);
+					},
+					IncentiveStage::Rewards => {
+						Self::slash_unclaimed_incentives(Self::get_unpaid_penalties(&progress));
+						Self::end_incentive_distribution(key, outcome);
+						return Ok(())
+					},
+				}
+				continue
+			}
+			if jurors >= max_jurors {
+				break
+			}
+			let (juror, stake, commit_vote) = round_jurors[progress.next_juror as usize].clone();
+			// Votes of the past rounds are kept with their jurors
+			let past_commit_vote = (progress.round < appeal_round).then_some(commit_vote);
+			let incentive =
+				Self::get_juror_incentive(&key, &outcome, &juror, stake, past_commit_vote);
+			match progress.stage {
+				IncentiveStage::Penalties => {
+					Self::collect_juror_penalty(&key, juror, stake, incentive, &mut progress)?
+				},
+				IncentiveStage::Rewards => match incentive {
+					JurorIncentive::Winner(weight) => {
+						let winning_incentives = Self::take_incentive_share(
+							progress.penalties,
+							progress.winners,
+							progress.winner_weight,
+							weight,
+							&mut progress.paid,
+							&mut progress.paid_winners,
+						);
+						Self::winner_getting_incentives2(&key, juror, winning_incentives, stake)?;
+					},
+					JurorIncentive::Draw => {
+						let draw_incentives = Self::take_incentive_share(
+							progress.penalties,
+							progress.winners,
+							progress.winner_weight,
+							1,
+							&mut progress.paid,
+							&mut progress.paid_winners,
+						);
+						Self::getting_incentives_draw2(&key, juror, draw_incentives, stake)?;
+					},
+					_ => {},
+				},
+			}
+			progress.next_juror += 1;
+			jurors += 1;
+		}
+		<IncentiveProgresses<T>>::insert(&key, progress.clone());
+		Self::deposit_event(Event::IncentivePageDistributed { key, jurors, progress });
+		Ok(())
+	}
+
+	/// Incentives of the game are not being distributed in pages by `distribute_incentives`
+	pub(super) fn ensure_incentives_not_in_pages(key: SumTreeNameType<T>) -> DispatchResult {
+		ensure!(
+			!<IncentiveProgresses<T>>::contains_key(&key),
+			Error::<T>::IncentivesBeingDistributed
+		);
+		Ok(())
+	}
+
+	/// Outcome of the game is decided when the distribution starts
+	/// Jurors must not have got their incentives with the other helpers
+	fn start_incentive_distribution(
+		key: SumTreeNameType<T>,
+	) -> Result<IncentiveProgress, DispatchError> {
+		ensure!(
+			!<JurorPenaltiesCollected<T>>::get(&key) &&
+				<JurorsIncentiveDistributedAccounts<T>>::get(&key).is_empty() &&
+				<PastRoundsIncentiveDistributedAccounts<T>>::get(&key).is_empty(),
+			Error::<T>::IncentivesBeingDistributed
+		);
+		// Appeal fee of the first round is shared by its winners along with the penalties
+		let mut progress = IncentiveProgress {
+			penalties: Self::get_appeal_fee_of_round(&key, 0),
+			..Default::default()
+		};
+		match Self::get_game_kind(&key) {
+			GameKind::MultiChoice => {
+				let choice = Self::get_winning_choice(key.clone());
+				Self::deposit_event(Event::WinningChoiceDecided { key, choice });
+			},
+			GameKind::Score => {
+				let reveal_values = <RevealScoreValues<T>>::get(&key);
+				let aggregation = <GameScoreAggregation<T>>::get(&key);
+				let range_point = <GameRangePoint<T>>::get(&key);
+				let (score, window) =
+					Self::get_score_and_window(&reveal_values, aggregation, range_point)
+						.ok_or(Error::<T>::NoScoreRevealed)?;
+				<IncentiveMeanRevealScore<T>>::insert(&key, score);
+				Self::deposit_event(Event::ScoreMeanDecided { key, mean: score });
+				progress.score_window = Some((score, window));
+			},
+			GameKind::TwoChoice => {
+				// Penalties are collected in pages, the other helpers must not collect them again
+				<JurorPenaltiesCollected<T>>::insert(&key, true);
+				let decision = Self::get_winning_decision_value(key.clone());
+				Self::deposit_event(Event::WinningDecisionDecided { key, decision });
+			},
+		}
+		Ok(progress)
+	}
+
+	fn get_incentive_outcome(
+		key: SumTreeNameType<T>,
+		progress: &IncentiveProgress,
+	) -> IncentiveOutcome {
+		if let Some((score, window)) = progress.score_window {
+			let curve = <GameIncentiveCurve<T>>::get(&key);
+			IncentiveOutcome::Score { score, window, curve }
+		} else if Self::get_game_kind(&key) == GameKind::MultiChoice {
+			IncentiveOutcome::MultiChoice(Self::get_winning_choice(key))
+		} else {
+			IncentiveOutcome::TwoChoice(Self::get_winning_decision_value(key))
+		}
+	}
+
+	/// Jurors of the round with their stake, votes of the current round are read for each juror
+	fn get_jurors_of_round(
+		key: &SumTreeNameType<T>,
+		round: u64,
+		appeal_round: u64,
+	) -> Vec<(AccountIdOf<T>, u64, Option<CommitVote>)> {
+		if round < appeal_round {
+			<PastRoundJurors<T>>::get(key, round)
+		} else {
+			<DrawnJurors<T>>::get(key)
+				.into_iter()
+				.map(|(juror, stake)| (juror, stake, None))
+				.collect()
+		}
+	}
+
+	fn get_juror_incentive(
+		key: &SumTreeNameType<T>,
+		outcome: &IncentiveOutcome,
+		juror: &AccountIdOf<T>,
+		stake: u64,
+		past_commit_vote: Option<Option<CommitVote>>,
+	) -> JurorIncentive {
+		match outcome {
+			IncentiveOutcome::TwoChoice(winning_decision) => {
+				let commit_vote =
+					past_commit_vote.unwrap_or_else(|| <VoteCommits<T>>::get(key, juror));
+				match commit_vote {
+					None => JurorIncentive::NotParticipated { committed: false },
+					Some(CommitVote { revealed_vote: None, .. }) => {
+						JurorIncentive::NotParticipated { committed: true }
+					},
+					Some(CommitVote { revealed_vote: Some(vote), .. }) => {
+						match (winning_decision, vote) {
+							(WinningDecision::Draw, _) => JurorIncentive::Draw,
+							(WinningDecision::WinnerYes, RevealedVote::Yes)
+							| (WinningDecision::WinnerNo, RevealedVote::No) => JurorIncentive::Winner(1),
+							_ => JurorIncentive::Loser(Self::get_incoherent_penalty(stake)),
+						}
+					},
+				}
+			},
+			IncentiveOutcome::Score { score, window, curve } => {
+				match <ScoreVoteCommits<T>>::get(key, juror) {
+					None => JurorIncentive::NotParticipated { committed: false },
+					Some(ScoreCommitVote { revealed_vote: None, .. }) => {
+						JurorIncentive::NotParticipated { committed: true }
+					},
+					Some(ScoreCommitVote { revealed_vote: Some(vote), .. }) => {
+						let distance = (vote * 1000 - score).abs();
+						if distance <= *window {
+							JurorIncentive::Winner(Self::get_score_winner_weight(
+								distance, *window, curve,
+							))
+						} else {
+							JurorIncentive::Loser(Self::get_score_looser_penalty(
+								stake, distance, *window, curve,
+							))
+						}
+					},
+				}
+			},
+			IncentiveOutcome::MultiChoice(winning_choice) => {
+				match <MultiChoiceVoteCommits<T>>::get(key, juror) {
+					None => JurorIncentive::NotParticipated { committed: false },
+					Some(MultiChoiceCommitVote { revealed_vote: None, .. }) => {
+						JurorIncentive::NotParticipated { committed: true }
+					},
+					Some(MultiChoiceCommitVote { revealed_vote: Some(choice), .. }) => {
+						match winning_choice {
+							Some(winning_choice) if *winning_choice == choice => {
+								JurorIncentive::Winner(1)
+							},
+							Some(_) => JurorIncentive::Loser(Self::get_incoherent_penalty(stake)),
+							None => JurorIncentive::Draw,
+						}
+					},
+				}
+			},
+		}
+	}
+
+	/// Losing and non participating jurors get back the rest of their stake, winners and draw jurors are counted
+	fn collect_juror_penalty(
+		key: &SumTreeNameType<T>,
+		juror: AccountIdOf<T>,
+		stake: u64,
+		incentive: JurorIncentive,
+		progress: &mut IncentiveProgress,
+	) -> DispatchResult {
+		match incentive {
+			JurorIncentive::Winner(weight) => {
+				progress.winners += 1;
+				progress.winner_weight = progress.winner_weight.saturating_add(weight);
+			},
+			JurorIncentive::Draw => {
+				progress.winners += 1;
+				progress.winner_weight = progress.winner_weight.saturating_add(1);
+			},
+			JurorIncentive::Loser(penalty) => {
+				Self::slash_reserved_stake(&juror, penalty);
+				progress.penalties = progress.penalties.saturating_add(penalty);
+				Self::unreserve_stake_after_penalty(key, juror, stake, penalty)?;
+			},
+			JurorIncentive::NotParticipated { committed } => {
+				let slash =
+					Self::slash_non_participant(key.clone(), juror.clone(), stake, committed);
+				progress.penalties = progress.penalties.saturating_add(slash);
+				Self::non_participant_getting_incentives2(key, juror, stake, committed)?;
+			},
+		}
+		Ok(())
+	}
+
+	/// Penalties of the round that are not paid, there were no winners in the round
+	fn get_unpaid_penalties(progress: &IncentiveProgress) -> u64 {
+		progress.penalties.saturating_sub(progress.paid)
+	}
+
+	/// Remove the data of the jurors, rest of the storage is removed after the game is archived
+	fn end_incentive_distribution(key: SumTreeNameType<T>, outcome: IncentiveOutcome) {
+		<IncentiveProgresses<T>>::remove(&key);
+		<DrawnJurors<T>>::remove(&key);
+		Self::deposit_event(Event::IncentivesDistributed { key: key.clone() });
+		let outcome = match outcome {
+			IncentiveOutcome::TwoChoice(winning_decision) => GameOutcome::TwoChoice(winning_decision),
+			IncentiveOutcome::Score { score, .. } => GameOutcome::Score(score),
+			IncentiveOutcome::MultiChoice(winning_choice) => GameOutcome::MultiChoice(winning_choice),
+		};
+		Self::archive_game(key, outcome);
+	}
+}
@@ -0,0 +1,120 @@
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+	/// Evidence of the game is submitted in `Period::Evidence`, replies till the jurors are drawn
+	/// Games that start in `Period::Staking` have no evidence period, their evidence is submitted in `Period::Staking`
+	pub(super) fn ensure_evidence_period(
+		key: &SumTreeNameType<T>,
+		is_reply: bool,
+	) -> DispatchResult {
+		match <PeriodName<T>>::get(key) {
+			Some(Period::Evidence) => Ok(()),
+			Some(Period::Staking) if is_reply || !<EvidenceStartTime<T>>::contains_key(key) => {
+				Ok(())
+			},
+			Some(_) => Err(Error::<T>::PeriodDontMatch)?,
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+	}
+
+	/// Submit evidence for the game, or a reply to an evidence of the game
+	/// Parties of the game and observers can submit evidence, `EvidenceDeposit` is reserved from the submitter
+	pub(super) fn submit_evidence_helper(
+		key: SumTreeNameType<T>,
+		who: AccountIdOf<T>,
+		content: Content,
+		reply_to: Option<EvidenceId>,
+	) -> DispatchResult {
+		Self::ensure_evidence_period(&key, reply_to.is_some())?;
+		ensure_content_is_some(&content)?;
+		ensure_content_is_valid(content.clone())?;
+		let max_evidence = T::MaxEvidencePerGame::get() as usize;
+		let evidence_count = match reply_to {
+			Some(evidence_id) => {
+				let evidence = <Evidences<T>>::get(&key, evidence_id)
+					.ok_or(Error::<T>::EvidenceDoesNotExist)?;
+				ensure!(
+					Self::get_reply_depth(&key, &evidence) < MAX_EVIDENCE_REPLY_DEPTH,
+					Error::<T>::MaxReplyDepthReached
+				);
+				<EvidenceReplyIds<T>>::decode_len(&key, evidence_id)
+			},
+			None => <GameEvidenceIds<T>>::decode_len(&key),
+		};
+		ensure!(evidence_count.unwrap_or(0) < max_evidence, Error::<T>::MaxEvidenceReached);
+
+		let deposit = T::EvidenceDeposit::get();
+		T::Currency::reserve(&who, deposit)?;
+		let evidence_id = <NextEvidenceId<T>>::get();
+		let next_evidence_id = evidence_id.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+		<NextEvidenceId<T>>::put(next_evidence_id);
+		let evidence = Evidence {
+			submitter: who.clone(),
+			content,
+			reply_to,
+			deposit,
+			submitted_at: <frame_system::Pallet<T>>::block_number(),
+		};
+		<Evidences<T>>::insert(&key, evidence_id, evidence);
+		match reply_to {
+			Some(parent) => <EvidenceReplyIds<T>>::append(&key, parent, evidence_id),
+			None => <GameEvidenceIds<T>>::append(&key, evidence_id),
+		}
+		Self::deposit_event(Event::EvidenceSubmitted {
+			key,
+			evidence_id,
+			submitter: who,
+			reply_to,
+		});
+		Ok(())
+	}
+
+	/// Number of evidence from the evidence to the evidence of the game, zero for the evidence of the game
+	/// It is at most `MAX_EVIDENCE_REPLY_DEPTH`
+	fn get_reply_depth(key: &SumTreeNameType<T>, evidence: &EvidenceOf<T>) -> u32 {
+		let mut depth = 0;
+		let mut reply_to = evidence.reply_to;
+		while let Some(evidence_id) = reply_to {
+			depth += 1;
+			reply_to = <Evidences<T>>::get(key, evidence_id).and_then(|evidence| evidence.reply_to);
+		}
+		depth
+	}
+
+	/// Evidence of the game from `offset`, at most `limit` of them, in the order they were submitted
+	pub fn get_game_evidence(
+		key: SumTreeNameType<T>,
+		offset: u64,
+		limit: u16,
+	) -> Vec<(EvidenceId, EvidenceOf<T>)> {
+		let evidence_ids = <GameEvidenceIds<T>>::get(&key);
+		Self::get_evidence_page(&key, evidence_ids, offset, limit)
+	}
+
+	/// Replies to the evidence from `offset`, at most `limit` of them, in the order they were submitted
+	pub fn get_evidence_replies(
+		key: SumTreeNameType<T>,
+		evidence_id: EvidenceId,
+		offset: u64,
+		limit: u16,
+	) -> Vec<(EvidenceId, EvidenceOf<T>)> {
+		let reply_ids = <EvidenceReplyIds<T>>::get(&key, evidence_id);
+		Self::get_evidence_page(&key, reply_ids, offset, limit)
+	}
+
+	fn get_evidence_page(
+		key: &SumTreeNameType<T>,
+		evidence_ids: Vec<EvidenceId>,
+		offset: u64,
+		limit: u16,
+	) -> Vec<(EvidenceId, EvidenceOf<T>)> {
+		evidence_ids
+			.into_iter()
+			.skip(offset as usize)
+			.take(limit as usize)
+			.filter_map(|evidence_id| {
+				<Evidences<T>>::get(key, evidence_id).map(|evidence| (evidence_id, evidence))
+			})
+			.collect()
+	}
+}
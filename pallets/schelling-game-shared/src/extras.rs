@@ -43,16 +43,68 @@ impl<T: Config> Pallet<T> {
 			juror_incentives,
 		)
 	}
+	/// Phase data of the game type, set with `set_phase_data` or the default one
+	pub(super) fn get_phase_data_of_game_type(game_type: SchellingGameType) -> PhaseDataOf<T> {
+		<GameTypePhaseData<T>>::get(&game_type)
+			.unwrap_or_else(|| PhaseData::default_of_game_type(&game_type))
+	}
+
+	/// Phase data copied for the game when it was created
+	/// Games created before phase data was copied use `PhaseData::default`
+	pub(super) fn get_phase_data_of_game(key: SumTreeNameType<T>) -> PhaseDataOf<T> {
+		<GamePhaseData<T>>::get(&key).unwrap_or_else(PhaseData::default)
+	}
+
+	/// Kind of votes of the game
+	/// Games created before the kind was kept are score games if a score vote is committed
+	pub(super) fn get_game_kind(key: &SumTreeNameType<T>) -> GameKind {
+		match <GameKinds<T>>::get(key) {
+			Some(kind) => kind,
+			None if <MultiChoiceNumberOfChoices<T>>::contains_key(key) => GameKind::MultiChoice,
+			None if <ScoreVoteCommits<T>>::iter_prefix(key).next().is_some() => GameKind::Score,
+			None => GameKind::TwoChoice,
+		}
+	}
+
+	/// Votes of the `kind` can be committed in the game
+	/// Games created before the kind was kept accept any kind
+	pub(super) fn ensure_game_kind(key: &SumTreeNameType<T>, kind: GameKind) -> DispatchResult {
+		if let Some(game_kind) = <GameKinds<T>>::get(key) {
+			ensure!(game_kind == kind, Error::<T>::GameKindDontMatch);
+		}
+		Ok(())
+	}
+
+	/// Keep the game type, the kind of votes and the commit format of the game and copy its phase data, score aggregation and incentive curve
+	pub(super) fn copy_game_type_settings(
+		key: SumTreeNameType<T>,
+		game_type: SchellingGameType,
+	) -> PhaseDataOf<T> {
+		let phase_data = Self::get_phase_data_of_game_type(game_type.clone());
+		<GameType<T>>::insert(&key, game_type.clone());
+		<GameKinds<T>>::insert(&key, GameKind::TwoChoice);
+		<GameCommitVersion<T>>::insert(&key, T::VoteCommitVersion::get());
+		<GamePhaseData<T>>::insert(&key, phase_data.clone());
+		<GameScoreAggregation<T>>::insert(&key, <GameTypeScoreAggregation<T>>::get(&game_type));
+		<GameIncentiveCurve<T>>::insert(&key, <GameTypeIncentiveCurve<T>>::get(&game_type));
+		<GameRangePoint<T>>::insert(&key, <GameTypeRangePoint<T>>::get(&game_type));
+		phase_data
+	}
+
 	/// Set to evidence period, when some one stakes for validation
+	/// Settings of the game type are copied for the game, see `copy_game_type_settings`
 	pub(super) fn set_to_evidence_period(
 		key: SumTreeNameType<T>,
+		game_type: SchellingGameType,
 		now: BlockNumberOf<T>,
 	) -> DispatchResult {
+		// Key of an archived game is not used again, even after its storage is removed
+		ensure!(!<GameResults<T>>::contains_key(&key), Error::<T>::PeriodExists);
 		match <PeriodName<T>>::get(&key) {
 			Some(_period) => Err(Error::<T>::PeriodExists)?,
 			None => {
-				let period = Period::Evidence;
-				<PeriodName<T>>::insert(&key, period);
+				Self::copy_game_type_settings(key.clone(), game_type);
+				Self::set_period(key.clone(), Period::Evidence);
 				<EvidenceStartTime<T>>::insert(&key, now);
 			},
 		}
@@ -75,15 +127,21 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		if let Some(Period::Evidence) = <PeriodName<T>>::get(&key) {
 			let evidence_stake_block_number = <EvidenceStartTime<T>>::get(&key);
-			let time = now.checked_sub(&evidence_stake_block_number).expect("Overflow");
+			let time = now
+				.checked_sub(&evidence_stake_block_number)
+				.ok_or(Error::<T>::BlockNumberOverflow)?;
 			let evidence_length = phase_data.evidence_length;
 			let end_length_for_staking = phase_data.end_of_staking_time;
-			let total_length =
-				evidence_length.checked_add(&end_length_for_staking).expect("overflow");
+			let total_length = evidence_length
+				.checked_add(&end_length_for_staking)
+				.ok_or(Error::<T>::BlockNumberOverflow)?;
 			if time >= evidence_length && time < total_length {
-				let new_period = Period::Staking;
-				<PeriodName<T>>::insert(&key, new_period);
+				let staking_end_time = now
+					.checked_add(&phase_data.staking_length)
+					.ok_or(Error::<T>::BlockNumberOverflow)?;
+				Self::set_period(key.clone(), Period::Staking);
 				<StakingStartTime<T>>::insert(&key, now);
+				Self::schedule_period_change(key, staking_end_time);
 			} else if time >= total_length {
 				Err(Error::<T>::TimeForStakingOver)?
 			} else {
@@ -103,23 +161,35 @@ impl<T: Config> Pallet<T> {
 		now: BlockNumberOf<T>,
 	) -> DispatchResult {
 		let evidence_stake_block_number = <EvidenceStartTime<T>>::get(&key);
-		let time = now.checked_sub(&evidence_stake_block_number).expect("Overflow");
+		let time = now
+			.checked_sub(&evidence_stake_block_number)
+			.ok_or(Error::<T>::BlockNumberOverflow)?;
 		let evidence_length = phase_data.evidence_length;
 		let end_length_for_staking = phase_data.end_of_staking_time;
-		let total_length = evidence_length.checked_add(&end_length_for_staking).expect("overflow");
+		let total_length = evidence_length
+			.checked_add(&end_length_for_staking)
+			.ok_or(Error::<T>::BlockNumberOverflow)?;
 		ensure!(time >= total_length, Error::<T>::TimeForStakingNotOver);
 		Ok(())
 	}
 
 	/// Set staking period when evidence period is not required
+	/// Settings of the game type are copied for the game, see `copy_game_type_settings`
 	pub(super) fn set_to_staking_period_pe(
 		key: SumTreeNameType<T>,
+		game_type: SchellingGameType,
 		now: BlockNumberOf<T>,
 	) -> DispatchResult {
+		ensure!(!<GameResults<T>>::contains_key(&key), Error::<T>::PeriodIsNotNone);
 		if let None = <PeriodName<T>>::get(&key) {
-			let new_period = Period::Staking;
-			<PeriodName<T>>::insert(&key, new_period);
+			let phase_data = Self::get_phase_data_of_game_type(game_type.clone());
+			let staking_end_time = now
+				.checked_add(&phase_data.staking_length)
+				.ok_or(Error::<T>::BlockNumberOverflow)?;
+			Self::copy_game_type_settings(key.clone(), game_type);
+			Self::set_period(key.clone(), Period::Staking);
 			<StakingStartTime<T>>::insert(&key, now);
+			Self::schedule_period_change(key, staking_end_time);
 		} else {
 			Err(Error::<T>::PeriodIsNotNone)?
 		}
@@ -144,6 +214,7 @@ impl<T: Config> Pallet<T> {
 	///
 	///  `Period::Drawing` to `Period::Commit`   
 	/// When maximum juror are drawn   
+	/// After `drawing_length` when at least `min_number_juror_staked` jurors are drawn, otherwise the game is voided   
 	///  
 	/// `Period::Commit` to `Period::Vote`       
 	/// ```ignore
@@ -152,12 +223,20 @@ impl<T: Config> Pallet<T> {
 	/// }
 	/// ```
 	///
-	/// `Period::Vote` to `Period::Execution`   
+	/// `Period::Vote` to `Period::Appeal`   
 	/// ```ignore
 	/// if now >= min_long_block_length + vote_start_time {
-	///   // Change `Period::Vote` to `Period::Execution`   
+	///   // Change `Period::Vote` to `Period::Appeal`   
+	/// }
+	/// ```
+	///
+	/// `Period::Appeal` to `Period::Execution`   
+	/// ```ignore
+	/// if now >= appeal_length + appeal_start_time {
+	///   // Change `Period::Appeal` to `Period::Execution`   
 	/// }
 	/// ```
+	/// Outcome of the game is given to `OnGameResolved` when it reaches `Period::Execution`
 	pub(super) fn change_period(
 		key: SumTreeNameType<T>,
 		phase_data: PhaseDataOf<T>,
@@ -166,26 +245,40 @@ impl<T: Config> Pallet<T> {
 		match <PeriodName<T>>::get(&key) {
 			Some(period) => {
 				match period {
-					Period::Evidence => todo!(),
+					Period::Evidence => Err(Error::<T>::PeriodDontMatch)?,
 					Period::Staking => {
 						// Also check has min number of jurors has staked
 						let staking_start_time = <StakingStartTime<T>>::get(&key);
 						let staking_length = phase_data.staking_length;
 						if now >= staking_length + staking_start_time {
-							let new_period = Period::Drawing;
-							<PeriodName<T>>::insert(&key, new_period);
+							let drawing_end_time = now
+								.checked_add(&phase_data.drawing_length)
+								.ok_or(Error::<T>::BlockNumberOverflow)?;
+							// Stakes at the draw of the round are kept for audits and appeals
+							// Jurors of the games of a court are drawn from the court trees
+							if !<GameCourt<T>>::contains_key(&key) {
+								T::SortitionSumGameSource::snapshot_tree_link(key.clone())?;
+							}
+							<DrawingStartTime<T>>::insert(&key, now);
+							Self::set_period(key.clone(), Period::Drawing);
+							Self::schedule_period_change(key, drawing_end_time);
 						} else {
 							Err(Error::<T>::StakingPeriodNotOver)?
 						}
 					},
 					Period::Drawing => {
-						// Also give time
-						let max_draws = phase_data.max_draws;
+						let max_draws = Self::max_draws_of_round(key.clone(), phase_data.clone());
 						let draws_in_round = <DrawsInRound<T>>::get(&key);
+						let drawing_start_time = <DrawingStartTime<T>>::get(&key);
 						if draws_in_round >= max_draws {
+							let commit_end_time = now
+								.checked_add(&phase_data.commit_length)
+								.ok_or(Error::<T>::BlockNumberOverflow)?;
 							<CommitStartTime<T>>::insert(&key, now);
-							let new_period = Period::Commit;
-							<PeriodName<T>>::insert(&key, new_period);
+							Self::set_period(key.clone(), Period::Commit);
+							Self::schedule_period_change(key, commit_end_time);
+						} else if now >= phase_data.drawing_length + drawing_start_time {
+							Self::end_drawing_after_deadline(key, phase_data, now)?;
 						} else {
 							Err(Error::<T>::MaxJurorNotDrawn)?
 						}
@@ -194,9 +287,12 @@ impl<T: Config> Pallet<T> {
 						let commit_start_time = <CommitStartTime<T>>::get(&key);
 						let commit_length = phase_data.commit_length;
 						if now >= commit_length + commit_start_time {
+							let vote_end_time = now
+								.checked_add(&phase_data.vote_length)
+								.ok_or(Error::<T>::BlockNumberOverflow)?;
 							<VoteStartTime<T>>::insert(&key, now);
-							let new_period = Period::Vote;
-							<PeriodName<T>>::insert(&key, new_period);
+							Self::set_period(key.clone(), Period::Vote);
+							Self::schedule_period_change(key, vote_end_time);
 						} else {
 							Err(Error::<T>::CommitPeriodNotOver)?
 						}
@@ -205,14 +301,27 @@ impl<T: Config> Pallet<T> {
 						let vote_start_time = <VoteStartTime<T>>::get(&key);
 						let vote_length = phase_data.vote_length;
 						if now >= vote_length + vote_start_time {
-							let new_period = Period::Execution;
-							<PeriodName<T>>::insert(&key, new_period);
+							let appeal_end_time = now
+								.checked_add(&phase_data.appeal_length)
+								.ok_or(Error::<T>::BlockNumberOverflow)?;
+							<AppealStartTime<T>>::insert(&key, now);
+							Self::set_period(key.clone(), Period::Appeal);
+							Self::schedule_period_change(key, appeal_end_time);
 						} else {
 							Err(Error::<T>::VotePeriodNotOver)?
 						}
 					},
-					Period::Appeal => todo!(),
-					Period::Execution => todo!(),
+					Period::Appeal => {
+						let appeal_start_time = <AppealStartTime<T>>::get(&key);
+						let appeal_length = phase_data.appeal_length;
+						if now >= appeal_length + appeal_start_time {
+							Self::set_period(key.clone(), Period::Execution);
+							Self::resolve_game(key);
+						} else {
+							Err(Error::<T>::AppealPeriodNotOver)?
+						}
+					},
+					Period::Execution => Err(Error::<T>::PeriodDontMatch)?,
 				}
 			},
 			None => Err(Error::<T>::PeriodDoesNotExists)?,
@@ -232,22 +341,12 @@ impl<T: Config> Pallet<T> {
 			},
 			None => Err(Error::<T>::PeriodDoesNotExists)?,
 		}
+		// Jurors of the games of a court are drawn from the jurors staked in the court
+		ensure!(!<GameCourt<T>>::contains_key(&key), Error::<T>::JurorsStakeInCourt);
 		let min_stake = phase_data.min_juror_stake;
 
 		ensure!(stake >= min_stake, Error::<T>::JurorStakeLessThanMin);
 
-		// let imb = T::Currency::withdraw(
-		// 	&who,
-		// 	stake,
-		// 	WithdrawReasons::TRANSFER,
-		// 	ExistenceRequirement::AllowDeath,
-		// )?;
-
-		// T::Currency::resolve_creating(&Self::juror_stake_account(), imb);
-
-		let imbalance = T::Currency::slash(&who, stake).0;
-		T::Slash::on_unbalanced(imbalance);
-
 		// let stake_of = Self::stake_of(key.clone(), profile_citizenid)?;
 
 		let stake_u64 = Self::balance_to_u64_saturated(stake);
@@ -257,8 +356,25 @@ impl<T: Config> Pallet<T> {
 		match stake_of {
 			Some(_stake) => Err(Error::<T>::AlreadyStaked)?,
 			None => {
-				let result = T::SortitionSumGameSource::set_link(key, stake_u64, who);
-				result
+				// Jurors with a low coherence rate are excluded, proven jurors get better draw odds
+				let mut draw_weight = Self::get_draw_weight(&who, stake_u64)?;
+				// Stake delegated to the juror for the game type adds to the weight
+				let target = <GameType<T>>::get(&key).map(DelegationTarget::GameType);
+				if let Some(target) = target.clone() {
+					draw_weight =
+						draw_weight.saturating_add(Self::get_delegated_stake(&who, &target));
+				}
+				// Stake is locked till the game ends
+				T::Currency::reserve(&who, stake)?;
+				if draw_weight != stake_u64 {
+					<JurorStakes<T>>::insert(&key, &who, stake_u64);
+				}
+				if let Some(target) = target {
+					Self::snapshot_game_delegations(&key, &who, target);
+				}
+				T::SortitionSumGameSource::set_link(key.clone(), draw_weight, who.clone())?;
+				Self::deposit_event(Event::JurorApplied { key, juror: who, stake });
+				Ok(())
 			},
 		}
 	}
@@ -275,7 +391,7 @@ impl<T: Config> Pallet<T> {
 			},
 			None => Err(Error::<T>::PeriodDoesNotExists)?,
 		}
-		let max_draws = phase_data.max_draws;
+		let max_draws = Self::max_draws_of_round(key.clone(), phase_data.clone());
 		let draws_in_round = <DrawsInRound<T>>::get(&key);
 		ensure!(draws_in_round < max_draws.into(), Error::<T>::MaxDrawExceeded);
 		let mut end_index = draws_in_round + iterations;
@@ -283,30 +399,67 @@ impl<T: Config> Pallet<T> {
 			end_index = max_draws;
 		}
 		let mut draw_increment = draws_in_round.clone();
+		let court_id = <GameCourt<T>>::get(&key);
 
-		for _ in draws_in_round..end_index {
-			let nonce = Self::get_and_increment_nonce();
-			let random_seed = T::RandomnessSource::random(&nonce).encode();
-			let random_number = u64::decode(&mut random_seed.as_ref())
-				.expect("secure hashes should always be bigger than u64; qed");
-			// let mut rng = rand::thread_rng();
-			// let random_number: u64 = rng.gen();
-			// log::info!("Random number: {:?}", random_number);
-			let accountid = T::SortitionSumGameSource::draw_link(key.clone(), random_number)?;
-			let stake = T::SortitionSumGameSource::stake_of_link(key.clone(), accountid.clone())?;
-
-			let mut drawn_juror = <DrawnJurors<T>>::get(&key);
+		let drawn = match court_id {
+			Some(court_id) => {
+				let mut drawn = Vec::new();
+				for _ in draws_in_round..end_index {
+					let nonce = Self::get_and_increment_nonce();
+					let random_number = T::DrawRandomness::random_number(&key, &nonce);
+					// Jurors drawn from a court are not drawn again till the game returns their stake
+					let (accountid, stake, staked_court) =
+						Self::draw_court_juror(&key, court_id, random_number)?;
+					Self::snapshot_game_delegations(
+						&key,
+						&accountid,
+						DelegationTarget::Court(staked_court),
+					);
+					drawn.push((accountid, stake));
+				}
+				drawn
+			},
+			None => {
+				let seeds = (draws_in_round..end_index)
+					.map(|_| {
+						let nonce = Self::get_and_increment_nonce();
+						T::DrawRandomness::random_number(&key, &nonce)
+					})
+					.collect::<Vec<_>>();
+				let draws = seeds.len() as u32;
+				// Drawn jurors are removed from the tree, the weight is the stake of jurors without reputation weight
+				T::SortitionSumGameSource::draw_many_link(key.clone(), seeds, draws)?
+					.into_iter()
+					.map(|(accountid, weight)| {
+						let stake = <JurorStakes<T>>::get(&key, &accountid).unwrap_or(weight);
+						(accountid, stake)
+					})
+					.collect()
+			},
+		};
+
+		let mut drawn_juror = <DrawnJurors<T>>::get(&key);
+		for (accountid, stake) in drawn {
 			match drawn_juror.binary_search_by(|(c, _)| c.cmp(&accountid)) {
 				Ok(_) => {},
 				Err(index) => {
-					drawn_juror.insert(index, (accountid.clone(), stake.unwrap()));
-					<DrawnJurors<T>>::insert(&key, drawn_juror);
+					drawn_juror.insert(index, (accountid.clone(), stake));
 					draw_increment = draw_increment + 1;
-					// println!("draw_increment, {:?}", draw_increment);
-					T::SortitionSumGameSource::set_link(key.clone(), 0, accountid)?;
+					Self::deposit_event(Event::JurorDrawn {
+						key: key.clone(),
+						juror: accountid,
+						stake: Self::u64_to_balance_saturated(stake),
+					});
 				},
 			}
-			<DrawsInRound<T>>::insert(&key, draw_increment);
+		}
+		<DrawnJurors<T>>::insert(&key, drawn_juror);
+		<DrawsInRound<T>>::insert(&key, draw_increment);
+		if draw_increment >= max_draws {
+			// All jurors are drawn, commit period starts in the next block
+			let now = <frame_system::Pallet<T>>::block_number();
+			let next_block = now.checked_add(&One::one()).ok_or(Error::<T>::BlockNumberOverflow)?;
+			Self::schedule_period_change(key, next_block);
 		}
 		Ok(())
 	}
@@ -331,7 +484,7 @@ impl<T: Config> Pallet<T> {
 			Err(_) => {},
 		}
 
-		let stake_of = T::SortitionSumGameSource::stake_of_link(key.clone(), who.clone())?;
+		let stake_of = Self::get_applied_stake(key.clone(), who.clone())?;
 
 		match stake_of {
 			Some(stake) => {
@@ -342,25 +495,19 @@ impl<T: Config> Pallet<T> {
 					Err(index) => {
 						unstaked_jurors.insert(index, who.clone());
 						<UnstakedJurors<T>>::insert(&key, unstaked_jurors);
-						// let _ = T::Currency::resolve_into_existing(
-						// 	&who,
-						// 	T::Currency::withdraw(
-						// 		&Self::juror_stake_account(),
-						// 		balance,
-						// 		WithdrawReasons::TRANSFER,
-						// 		ExistenceRequirement::AllowDeath,
-						// 	)?,
-						// );
-						let r = T::Currency::deposit_into_existing(&who, balance).ok().unwrap();
-						T::Reward::on_unbalanced(r);
+						T::Currency::unreserve(&who, balance);
+						Self::remove_game_delegations(&key, &who);
+						Self::deposit_event(Event::JurorUnstaked {
+							key: key.clone(),
+							juror: who.clone(),
+							stake: balance,
+						});
 					},
 				}
 			},
 			None => Err(Error::<T>::StakeDoesNotExists)?,
 		}
 
-		// println!("stakeof {:?}", stake_of);
-
 		Ok(())
 	}
 
@@ -375,6 +522,7 @@ impl<T: Config> Pallet<T> {
 			},
 			None => Err(Error::<T>::PeriodDoesNotExists)?,
 		}
+		Self::ensure_game_kind(&key, GameKind::TwoChoice)?;
 		let drawn_jurors = <DrawnJurors<T>>::get(&key);
 		match drawn_jurors.binary_search_by(|(c, _)| c.cmp(&who.clone())) {
 			Ok(_) => {
@@ -384,6 +532,7 @@ impl<T: Config> Pallet<T> {
 					revealed_vote: None,
 				};
 				<VoteCommits<T>>::insert(&key, &who, vote_commit_struct);
+				Self::deposit_event(Event::VoteCommitted { key: key.clone(), juror: who });
 			},
 			Err(_) => Err(Error::<T>::JurorDoesNotExists)?,
 		}
@@ -409,14 +558,8 @@ impl<T: Config> Pallet<T> {
 					commit_struct.votestatus == VoteStatus::Commited,
 					Error::<T>::VoteStatusNotCommited
 				);
-				let mut vote = format!("{}", choice).as_bytes().to_vec();
-				// let mut vote = choice.clone();
-				let mut salt_a = salt.clone();
-				vote.append(&mut salt_a);
-				let vote_bytes: &[u8] = &vote;
-				let hash = sp_io::hashing::keccak_256(vote_bytes);
-				let commit: &[u8] = &commit_struct.commit;
-				if hash == commit {
+				let hash = Self::get_vote_commit(&key, &who, choice, &salt);
+				if hash == commit_struct.commit {
 					let mut decision_tuple = <DecisionCount<T>>::get(&key);
 					if choice == 1 {
 						decision_tuple.1 = decision_tuple.1 + 1;
@@ -430,7 +573,15 @@ impl<T: Config> Pallet<T> {
 						Err(Error::<T>::NotValidChoice)?
 					}
 					commit_struct.votestatus = VoteStatus::Revealed;
+					let vote = commit_struct.revealed_vote.clone();
 					<VoteCommits<T>>::insert(&key, &who, commit_struct);
+					if let Some(vote) = vote {
+						Self::deposit_event(Event::VoteRevealed {
+							key: key.clone(),
+							juror: who,
+							vote,
+						});
+					}
 				} else {
 					Err(Error::<T>::CommitDoesNotMatch)?
 				}
@@ -444,7 +595,7 @@ impl<T: Config> Pallet<T> {
 	/// Distribute incentives in a single go.
 	pub(super) fn get_all_incentives_two_choice_helper(
 		key: SumTreeNameType<T>,
-		phase_data: PhaseDataOf<T>,
+		_phase_data: PhaseDataOf<T>,
 	) -> DispatchResult {
 		match <PeriodName<T>>::get(&key) {
 			Some(period) => {
@@ -452,77 +603,46 @@ impl<T: Config> Pallet<T> {
 			},
 			None => Err(Error::<T>::PeriodDoesNotExists)?,
 		}
+		Self::ensure_game_not_voided(key.clone())?;
+		Self::ensure_incentives_not_in_pages(key.clone())?;
 
-		let drawn_jurors = <DrawnJurors<T>>::get(&key);
-		let reveal_votes_iterator = <VoteCommits<T>>::iter_prefix(&key);
-
-		let mut reveal_votes = reveal_votes_iterator
-			.map(|(account_id, commit_vote)| (account_id, commit_vote.revealed_vote))
-			.collect::<Vec<(_, _)>>();
-		reveal_votes.sort_by(|a, b| a.0.cmp(&b.0));
-		let decision_count = <DecisionCount<T>>::get(&key);
-		let incentives = phase_data.juror_incentives;
-		let (winning_decision, winning_incentives) =
-			Self::get_winning_incentives(decision_count, incentives);
-		for juror in drawn_jurors {
-			match reveal_votes.binary_search_by(|(c, _)| c.cmp(&juror.0)) {
-				Ok(index) => {
-					let account_n_vote = reveal_votes[index].clone();
-					if let Some(vote) = account_n_vote.1 {
-						match winning_decision {
-							WinningDecision::WinnerYes => match vote {
-								RevealedVote::Yes => {
-									let result = Self::winner_getting_incentives2(
-										juror.0.clone(),
-										winning_incentives,
-										juror.1,
-									)?;
-									result
-								},
-								RevealedVote::No => {
-									let result =
-										Self::looser_getting_incentives2(juror.0.clone(), juror.1)?;
-									result
-								},
-							},
-							WinningDecision::WinnerNo => match vote {
-								RevealedVote::Yes => {
-									let result =
-										Self::looser_getting_incentives2(juror.0.clone(), juror.1)?;
-									result
-								},
-								RevealedVote::No => {
-									let result = Self::winner_getting_incentives2(
-										juror.0.clone(),
-										winning_incentives,
-										juror.1,
-									)?;
-									result
-								},
-							},
-							WinningDecision::Draw => {
-								let result =
-									Self::getting_incentives_draw2(juror.0.clone(), juror.1)?;
-								result
-							},
-						}
-					}
-				},
-				Err(_) => {},
-			}
+		// Penalties of the losing jurors are collected first, they pay the winning jurors
+		Self::collect_penalties_helper(key.clone())?;
+
+		let winning_decision = Self::get_winning_decision_value(key.clone());
+		let appeal_round = <AppealRound<T>>::get(&key);
+		Self::get_all_past_rounds_incentives_helper(key.clone())?;
+		// Jurors who got their incentives with `get_incentives_two_choice_helper` are not paid again
+		let mut juror_got_incentives = <JurorsIncentiveDistributedAccounts<T>>::get(&key);
+		for (juror, stake, commit_vote) in Self::get_round_jurors(key.clone()) {
+			let index = match juror_got_incentives.binary_search(&juror) {
+				Ok(_) => continue,
+				Err(index) => index,
+			};
+			juror_got_incentives.insert(index, juror.clone());
+			Self::round_juror_getting_incentives(
+				&key,
+				appeal_round,
+				juror,
+				stake,
+				commit_vote,
+				winning_decision.clone(),
+			)?;
 		}
-		// Remove SorititionSumTrees in `sortition-sum-game` pallet
-		let _result = T::SortitionSumGameSource::remove_tree_link(key.clone());
+		<JurorsIncentiveDistributedAccounts<T>>::insert(&key, juror_got_incentives);
+
+		// Jurors left in the sortition tree get their stake back in `on_idle` once the game is archived
 
 		// Remove DrawnJurors
 		<DrawnJurors<T>>::remove(&key);
 
+		<JurorPenaltiesCollected<T>>::remove(&key);
+
 		// Remove VoteCommits
-		<VoteCommits<T>>::remove_prefix(key.clone(), None); // Deprecated: Use clear_prefix instead
-													// let reveal_votes_iterator2 = <VoteCommits<T>>::iter_prefix(&key);
-													// reveal_votes_iterator2.for_each(|(account_id, _)|{
-													// 	<VoteCommits<T>>::remove(key.clone(), account_id);
-													// });
+		// The rest is removed in `on_idle` once the game is archived
+		let _ = <VoteCommits<T>>::clear_prefix(&key, T::MaxVoteRemovalsPerBlock::get(), None);
+
+		Self::archive_game(key, GameOutcome::TwoChoice(winning_decision));
 
 		Ok(())
 	}
@@ -530,7 +650,7 @@ impl<T: Config> Pallet<T> {
 	// Improvements: Will it be better to distribute all jurors incentives in single call
 	pub(super) fn get_incentives_two_choice_helper(
 		key: SumTreeNameType<T>,
-		phase_data: PhaseDataOf<T>,
+		_phase_data: PhaseDataOf<T>,
 		who: AccountIdOf<T>,
 	) -> DispatchResult {
 		match <PeriodName<T>>::get(&key) {
@@ -539,19 +659,25 @@ impl<T: Config> Pallet<T> {
 			},
 			None => Err(Error::<T>::PeriodDoesNotExists)?,
 		}
+		Self::ensure_game_not_voided(key.clone())?;
+		Self::ensure_incentives_not_in_pages(key.clone())?;
+
+		// Penalties of the losing jurors are collected first, they pay the winning jurors
+		Self::collect_penalties_helper(key.clone())?;
 
 		let drawn_juror = <DrawnJurors<T>>::get(&key);
 
+		// Jurors of the appealed rounds get their incentives along with the final round
+		let past_round_juror = Self::get_past_rounds_incentives_helper(key.clone(), who.clone())?;
+
 		let who_commit_vote = <VoteCommits<T>>::get(&key, &who);
 		match who_commit_vote {
 			Some(commit_struct) => {
 				let vote_option = commit_struct.revealed_vote;
 				match vote_option {
 					Some(vote) => {
-						let decision_count: (u64, u64) = <DecisionCount<T>>::get(&key);
-						let incentives = phase_data.juror_incentives;
-						let (winning_decision, winning_incentives) =
-							Self::get_winning_incentives(decision_count, incentives);
+						let winning_decision = Self::get_winning_decision_value(key.clone());
+						let appeal_round = <AppealRound<T>>::get(&key);
 						if let Ok(i) = drawn_juror.binary_search_by(|(c, _)| c.cmp(&who.clone())) {
 							let stake = drawn_juror[i].1;
 							match winning_decision {
@@ -560,7 +686,7 @@ impl<T: Config> Pallet<T> {
 										let result = Self::winner_getting_incentives(
 											key.clone(),
 											who.clone(),
-											winning_incentives,
+											Self::take_round_incentives(&key, appeal_round),
 											stake,
 										)?;
 										result
@@ -587,7 +713,7 @@ impl<T: Config> Pallet<T> {
 										let result = Self::winner_getting_incentives(
 											key.clone(),
 											who.clone(),
-											winning_incentives,
+											Self::take_round_incentives(&key, appeal_round),
 											stake,
 										)?;
 										result
@@ -597,6 +723,7 @@ impl<T: Config> Pallet<T> {
 									let result = Self::getting_incentives_draw(
 										key.clone(),
 										who.clone(),
+										Self::take_round_incentives(&key, appeal_round),
 										stake.clone(),
 									)?;
 									result
@@ -606,10 +733,37 @@ impl<T: Config> Pallet<T> {
 							Err(Error::<T>::StakeDoesNotExists)?
 						}
 					},
-					None => Err(Error::<T>::VoteNotRevealed)?,
+					None => {
+						if let Ok(i) = drawn_juror.binary_search_by(|(c, _)| c.cmp(&who.clone())) {
+							Self::non_participant_getting_incentives(
+								key.clone(),
+								who.clone(),
+								drawn_juror[i].1,
+								true,
+							)?;
+						} else if !past_round_juror {
+							Err(Error::<T>::VoteNotRevealed)?
+						}
+					},
 				}
 			},
-			None => Err(Error::<T>::CommitDoesNotExists)?,
+			None => {
+				if let Ok(i) = drawn_juror.binary_search_by(|(c, _)| c.cmp(&who.clone())) {
+					Self::non_participant_getting_incentives(
+						key.clone(),
+						who.clone(),
+						drawn_juror[i].1,
+						false,
+					)?;
+				} else if !past_round_juror {
+					Err(Error::<T>::CommitDoesNotExists)?
+				}
+			},
+		}
+
+		if Self::all_jurors_got_incentives(key.clone()) {
+			let winning_decision = Self::get_winning_decision_value(key.clone());
+			Self::archive_game(key, GameOutcome::TwoChoice(winning_decision));
 		}
 		Ok(())
 	}
@@ -617,28 +771,32 @@ impl<T: Config> Pallet<T> {
 	pub(super) fn getting_incentives_draw(
 		key: SumTreeNameType<T>,
 		who: AccountIdOf<T>,
+		draw_incentives: u64,
 		stake: u64,
 	) -> DispatchResult {
-		let balance = Self::u64_to_balance_saturated(stake);
 		let mut juror_got_incentives = <JurorsIncentiveDistributedAccounts<T>>::get(&key);
 		match juror_got_incentives.binary_search(&who) {
 			Ok(_) => Err(Error::<T>::AlreadyGotIncentives)?,
 			Err(index) => {
 				juror_got_incentives.insert(index, who.clone());
 				<JurorsIncentiveDistributedAccounts<T>>::insert(&key, juror_got_incentives);
-				let r = T::Currency::deposit_into_existing(&who, balance).ok().unwrap();
-				T::Reward::on_unbalanced(r);
+				Self::getting_incentives_draw2(&key, who, draw_incentives, stake)?;
 			},
 		}
 
 		Ok(())
 	}
 
-	pub(super) fn getting_incentives_draw2(who: AccountIdOf<T>, stake: u64) -> DispatchResult {
-		let balance = Self::u64_to_balance_saturated(stake);
-
-		let r = T::Currency::deposit_into_existing(&who, balance).ok().unwrap();
-		T::Reward::on_unbalanced(r);
+	/// Stake is returned, jurors who revealed the vote share the slashes of the non participating jurors
+	pub(super) fn getting_incentives_draw2(
+		key: &SumTreeNameType<T>,
+		who: AccountIdOf<T>,
+		draw_incentives: u64,
+		stake: u64,
+	) -> DispatchResult {
+		Self::return_juror_stake(key, &who, stake);
+		let rewarded = Self::pay_incentives_with_delegators(key, &who, stake, draw_incentives)?;
+		Self::record_juror_vote(&who, JurorVote::Draw, rewarded, 0);
 
 		Ok(())
 	}
@@ -648,25 +806,28 @@ impl<T: Config> Pallet<T> {
 		who: AccountIdOf<T>,
 		stake: u64,
 	) -> DispatchResult {
-		let balance = Self::u64_to_balance_saturated(stake * 3 / 4);
 		let mut juror_got_incentives = <JurorsIncentiveDistributedAccounts<T>>::get(&key);
 		match juror_got_incentives.binary_search(&who) {
 			Ok(_) => Err(Error::<T>::AlreadyGotIncentives)?,
 			Err(index) => {
 				juror_got_incentives.insert(index, who.clone());
 				<JurorsIncentiveDistributedAccounts<T>>::insert(&key, juror_got_incentives);
-				let r = T::Currency::deposit_into_existing(&who, balance).ok().unwrap();
-				T::Reward::on_unbalanced(r);
+				Self::looser_getting_incentives2(&key, who, stake)?;
 			},
 		}
 		Ok(())
 	}
 
-	pub(super) fn looser_getting_incentives2(who: AccountIdOf<T>, stake: u64) -> DispatchResult {
-		let balance = Self::u64_to_balance_saturated(stake * 3 / 4);
-
-		let r = T::Currency::deposit_into_existing(&who, balance).ok().unwrap();
-		T::Reward::on_unbalanced(r);
+	/// Rest of the stake is returned, the penalty is already moved to the juror stake account
+	pub(super) fn looser_getting_incentives2(
+		key: &SumTreeNameType<T>,
+		who: AccountIdOf<T>,
+		stake: u64,
+	) -> DispatchResult {
+		let penalty = Self::get_incoherent_penalty(stake);
+		Self::return_juror_stake(key, &who, stake.saturating_sub(penalty));
+		Self::record_juror_vote(&who, JurorVote::Incoherent, 0, penalty);
+		Self::slash_delegators(key, &who, stake, penalty);
 
 		Ok(())
 	}
@@ -683,29 +844,292 @@ impl<T: Config> Pallet<T> {
 			Err(index) => {
 				juror_got_incentives.insert(index, who.clone());
 				<JurorsIncentiveDistributedAccounts<T>>::insert(&key, juror_got_incentives);
-				let total_incentives = stake.checked_add(winning_incentives).expect("overflow");
-				let incentives = Self::u64_to_balance_saturated(total_incentives);
-				let r = T::Currency::deposit_into_existing(&who, incentives).ok().unwrap();
-				T::Reward::on_unbalanced(r);
+				Self::winner_getting_incentives2(&key, who, winning_incentives, stake)?;
 			},
 		};
 
 		Ok(())
 	}
 
+	/// Stake is returned and the incentives are paid from the juror stake account
 	pub(super) fn winner_getting_incentives2(
+		key: &SumTreeNameType<T>,
 		who: AccountIdOf<T>,
 		winning_incentives: u64,
 		stake: u64,
 	) -> DispatchResult {
-		let total_incentives = stake.checked_add(winning_incentives).expect("overflow");
-		let incentives = Self::u64_to_balance_saturated(total_incentives);
-		let r = T::Currency::deposit_into_existing(&who, incentives).ok().unwrap();
-		T::Reward::on_unbalanced(r);
+		Self::return_juror_stake(key, &who, stake);
+		let rewarded = Self::pay_incentives_with_delegators(key, &who, stake, winning_incentives)?;
+		Self::record_juror_vote(&who, JurorVote::Coherent, rewarded, 0);
 
 		Ok(())
 	}
 
+	/// Pay `incentives` to `who` from the juror stake account, returns the amount paid
+	pub(super) fn pay_incentives(
+		who: &AccountIdOf<T>,
+		incentives: u64,
+	) -> Result<u64, DispatchError> {
+		let juror_stake_account = Self::juror_stake_account();
+		let incentives = Self::u64_to_balance_saturated(incentives)
+			.min(T::Currency::free_balance(&juror_stake_account));
+		T::Currency::transfer(
+			&juror_stake_account,
+			who,
+			incentives,
+			ExistenceRequirement::AllowDeath,
+		)?;
+		Ok(Self::balance_to_u64_saturated(incentives))
+	}
+
+	/// Drawn juror who did not commit or did not reveal the vote gets back the stake after the slash
+	pub(super) fn non_participant_getting_incentives(
+		key: SumTreeNameType<T>,
+		who: AccountIdOf<T>,
+		stake: u64,
+		committed: bool,
+	) -> DispatchResult {
+		let mut juror_got_incentives = <JurorsIncentiveDistributedAccounts<T>>::get(&key);
+		match juror_got_incentives.binary_search(&who) {
+			Ok(_) => Err(Error::<T>::AlreadyGotIncentives)?,
+			Err(index) => {
+				juror_got_incentives.insert(index, who.clone());
+				<JurorsIncentiveDistributedAccounts<T>>::insert(&key, juror_got_incentives);
+				Self::non_participant_getting_incentives2(&key, who, stake, committed)?;
+			},
+		}
+		Ok(())
+	}
+
+	/// Rest of the stake of the incoherent juror is returned, the penalty is already moved to the juror stake account
+	pub(super) fn unreserve_stake_after_penalty(
+		key: &SumTreeNameType<T>,
+		who: AccountIdOf<T>,
+		stake: u64,
+		penalty: u64,
+	) -> DispatchResult {
+		Self::return_juror_stake(key, &who, stake.saturating_sub(penalty));
+		Self::record_juror_vote(&who, JurorVote::Incoherent, 0, penalty);
+		Self::slash_delegators(key, &who, stake, penalty);
+		Ok(())
+	}
+
+	pub(super) fn non_participant_getting_incentives2(
+		key: &SumTreeNameType<T>,
+		who: AccountIdOf<T>,
+		stake: u64,
+		committed: bool,
+	) -> DispatchResult {
+		let slash = Self::get_non_participation_slash(stake, committed);
+		Self::return_juror_stake(key, &who, stake.saturating_sub(slash));
+		let vote = if committed { JurorVote::NotRevealed } else { JurorVote::NotCommitted };
+		Self::record_juror_vote(&who, vote, 0, slash);
+		Self::slash_delegators(key, &who, stake, slash);
+
+		Ok(())
+	}
+
+	/// Slash on the stake of a drawn juror who did not commit (`committed` is false) or did not reveal the vote
+	pub(super) fn get_non_participation_slash(stake: u64, committed: bool) -> u64 {
+		let slash_fraction = if committed {
+			T::NonRevealSlashFraction::get()
+		} else {
+			T::NonCommitSlashFraction::get()
+		};
+		slash_fraction * stake
+	}
+
+	/// Account that holds the slashed stakes till they are given to the winning jurors
+	pub(super) fn juror_stake_account() -> T::AccountId {
+		PALLET_ID.into_account_truncating()
+	}
+
+	/// Move `amount` from the reserved stake of `who` to the juror stake account
+	pub(super) fn slash_reserved_stake(who: &AccountIdOf<T>, amount: u64) {
+		let balance = Self::u64_to_balance_saturated(amount);
+		let (imbalance, _) = T::Currency::slash_reserved(who, balance);
+		T::Currency::resolve_creating(&Self::juror_stake_account(), imbalance);
+	}
+
+	/// Slash the stake of a drawn juror who did not commit or did not reveal the vote
+	/// Returns the slashed amount
+	pub(super) fn slash_non_participant(
+		key: SumTreeNameType<T>,
+		who: AccountIdOf<T>,
+		stake: u64,
+		committed: bool,
+	) -> u64 {
+		let slash = Self::get_non_participation_slash(stake, committed);
+		Self::slash_reserved_stake(&who, slash);
+		let slashed = Self::u64_to_balance_saturated(slash);
+		if committed {
+			Self::deposit_event(Event::JurorNotRevealed { key, juror: who, slashed });
+		} else {
+			Self::deposit_event(Event::JurorNotCommitted { key, juror: who, slashed });
+		}
+		slash
+	}
+
+	/// Jurors of the current round with their stake and vote
+	pub(super) fn get_round_jurors(
+		key: SumTreeNameType<T>,
+	) -> Vec<(AccountIdOf<T>, u64, Option<CommitVote>)> {
+		<DrawnJurors<T>>::get(&key)
+			.into_iter()
+			.map(|(account_id, stake)| {
+				let commit_vote = <VoteCommits<T>>::get(&key, &account_id);
+				(account_id, stake, commit_vote)
+			})
+			.collect()
+	}
+
+	/// Part of the stake that a juror who voted against the winning decision loses
+	pub(super) fn get_incoherent_penalty(stake: u64) -> u64 {
+		stake - stake * 3 / 4
+	}
+
+	/// Part of the stake that a juror of two choice schelling game loses
+	pub(super) fn get_juror_penalty(
+		stake: u64,
+		commit_vote: &Option<CommitVote>,
+		winning_decision: &WinningDecision,
+	) -> u64 {
+		match commit_vote {
+			None => Self::get_non_participation_slash(stake, false),
+			Some(CommitVote { revealed_vote: None, .. }) => {
+				Self::get_non_participation_slash(stake, true)
+			},
+			Some(CommitVote { revealed_vote: Some(vote), .. }) => match (winning_decision, vote) {
+				(WinningDecision::WinnerYes, RevealedVote::No)
+				| (WinningDecision::WinnerNo, RevealedVote::Yes) => Self::get_incoherent_penalty(stake),
+				_ => 0,
+			},
+		}
+	}
+
+	/// Jurors of the round who voted for the winning decision, or who revealed the vote in a draw, share the incentives of the round
+	pub(super) fn is_round_winner(
+		commit_vote: &Option<CommitVote>,
+		winning_decision: &WinningDecision,
+	) -> bool {
+		match (winning_decision, commit_vote) {
+			(WinningDecision::Draw, Some(CommitVote { revealed_vote: Some(_), .. })) => true,
+			(
+				WinningDecision::WinnerYes,
+				Some(CommitVote { revealed_vote: Some(RevealedVote::Yes), .. }),
+			)
+			| (
+				WinningDecision::WinnerNo,
+				Some(CommitVote { revealed_vote: Some(RevealedVote::No), .. }),
+			) => true,
+			_ => false,
+		}
+	}
+
+	/// Share of a winner with `weight` in the `incentives` shared by `winners` with `winner_weight`
+	/// Winners share the incentives by their weight, equally when all the weights are zero
+	/// Last winner gets the remainder of the division, `paid` and `paid_winners` keep what is paid so far
+	pub(super) fn take_incentive_share(
+		incentives: u64,
+		winners: u64,
+		winner_weight: u64,
+		weight: u64,
+		paid: &mut u64,
+		paid_winners: &mut u64,
+	) -> u64 {
+		let left = incentives.saturating_sub(*paid);
+		let share = if paid_winners.saturating_add(1) >= winners {
+			left
+		} else if winner_weight == 0 {
+			incentives / winners
+		} else {
+			(incentives as u128 * weight as u128 / winner_weight as u128) as u64
+		};
+		let share = share.min(left);
+		*paid = paid.saturating_add(share);
+		*paid_winners = paid_winners.saturating_add(1);
+		share
+	}
+
+	/// Share of a winner of the round in the incentives of the round
+	pub(super) fn take_round_incentives(key: &SumTreeNameType<T>, round: u64) -> u64 {
+		<RoundIncentivePools<T>>::mutate(key, round, |pool| {
+			Self::take_incentive_share(
+				pool.incentives,
+				pool.winners,
+				pool.winners,
+				1,
+				&mut pool.paid,
+				&mut pool.paid_winners,
+			)
+		})
+	}
+
+	/// Incentives that no juror of the game can get are handed to `T::Slash`
+	pub(super) fn slash_unclaimed_incentives(amount: u64) {
+		if amount == 0 {
+			return
+		}
+		if let Ok(imbalance) = T::Currency::withdraw(
+			&Self::juror_stake_account(),
+			Self::u64_to_balance_saturated(amount),
+			WithdrawReasons::TRANSFER,
+			ExistenceRequirement::AllowDeath,
+		) {
+			T::Slash::on_unbalanced(imbalance);
+		}
+	}
+
+	/// Move the penalties of the losing and non participating jurors of all the rounds to the juror stake account
+	/// Penalties and the appeal fee of a round are kept in `RoundIncentivePools` for the winners of the round
+	/// Incentives of a round without winners are given to the winners of the next round
+	/// It is done once, before any juror gets the incentives
+	pub(super) fn collect_penalties_helper(key: SumTreeNameType<T>) -> DispatchResult {
+		if <JurorPenaltiesCollected<T>>::get(&key) {
+			return Ok(())
+		}
+		let winning_decision = Self::get_winning_decision_value(key.clone());
+		let appeal_round = <AppealRound<T>>::get(&key);
+		let mut unclaimed = 0u64;
+		for round in 0..=appeal_round {
+			let round_jurors = if round < appeal_round {
+				<PastRoundJurors<T>>::get(&key, round)
+			} else {
+				Self::get_round_jurors(key.clone())
+			};
+			let mut pool = IncentivePool {
+				incentives: unclaimed.saturating_add(Self::get_appeal_fee_of_round(&key, round)),
+				..Default::default()
+			};
+			for (account_id, stake, commit_vote) in round_jurors {
+				let penalty = match commit_vote {
+					None => Self::slash_non_participant(key.clone(), account_id, stake, false),
+					Some(CommitVote { revealed_vote: None, .. }) => {
+						Self::slash_non_participant(key.clone(), account_id, stake, true)
+					},
+					Some(_) => {
+						let penalty =
+							Self::get_juror_penalty(stake, &commit_vote, &winning_decision);
+						if penalty > 0 {
+							Self::slash_reserved_stake(&account_id, penalty);
+						}
+						penalty
+					},
+				};
+				pool.incentives = pool.incentives.saturating_add(penalty);
+				if Self::is_round_winner(&commit_vote, &winning_decision) {
+					pool.winners += 1;
+				}
+			}
+			unclaimed = if pool.winners == 0 { core::mem::take(&mut pool.incentives) } else { 0 };
+			<RoundIncentivePools<T>>::insert(&key, round, pool);
+		}
+		Self::slash_unclaimed_incentives(unclaimed);
+		<JurorPenaltiesCollected<T>>::insert(&key, true);
+		Self::deposit_event(Event::WinningDecisionDecided { key, decision: winning_decision });
+		Ok(())
+	}
+
 	pub(super) fn get_winning_decision(decision_tuple: (u64, u64)) -> WinningDecision {
 		if decision_tuple.1 > decision_tuple.0 {
 			WinningDecision::WinnerYes // Decision 1 won
@@ -716,31 +1140,17 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Winning decision of archived games is kept in `GameResults` after their storage is removed
 	pub(super) fn get_winning_decision_value(key: SumTreeNameType<T>) -> WinningDecision {
+		if let Some(GameResult { outcome: GameOutcome::TwoChoice(winning_decision), .. }) =
+			<GameResults<T>>::get(&key)
+		{
+			return winning_decision
+		}
 		let decision_tuple: (u64, u64) = <DecisionCount<T>>::get(&key);
 		Self::get_winning_decision(decision_tuple)
 	}
 
-	pub(super) fn get_winning_incentives(
-		decision_tuple: (u64, u64),
-		incentive_tuple: (u64, u64),
-	) -> (WinningDecision, u64) {
-		let winning_decision = Self::get_winning_decision(decision_tuple);
-		match winning_decision {
-			WinningDecision::WinnerYes => {
-				let winning_incentives =
-					(incentive_tuple.1).checked_div(decision_tuple.1).expect("Overflow");
-				(WinningDecision::WinnerYes, winning_incentives)
-			},
-			WinningDecision::WinnerNo => {
-				let winning_incentives =
-					(incentive_tuple.1).checked_div(decision_tuple.0).expect("Overflow");
-				(WinningDecision::WinnerNo, winning_incentives)
-			},
-			WinningDecision::Draw => (WinningDecision::Draw, 0),
-		}
-	}
-
 	pub(super) fn balance_to_u64_saturated(input: BalanceOf<T>) -> u64 {
 		input.saturated_into::<u64>()
 	}
@@ -767,7 +1177,7 @@ impl<T: Config> Pallet<T> {
 	) -> Option<u32> {
 		let start_block_number = <EvidenceStartTime<T>>::get(&key);
 		let evidence_length = phase_data.evidence_length;
-		let end_block = start_block_number.checked_add(&evidence_length).expect("Overflow");
+		let end_block = start_block_number.checked_add(&evidence_length)?;
 		let left_block = end_block.checked_sub(&now);
 		match left_block {
 			Some(val) => {
@@ -785,7 +1195,7 @@ impl<T: Config> Pallet<T> {
 	) -> Option<u32> {
 		let staking_start_time = <StakingStartTime<T>>::get(&key);
 		let staking_length = phase_data.staking_length;
-		let end_block = staking_start_time.checked_add(&staking_length).expect("Overflow");
+		let end_block = staking_start_time.checked_add(&staking_length)?;
 		let left_block = end_block.checked_sub(&now);
 		match left_block {
 			Some(val) => {
@@ -800,7 +1210,7 @@ impl<T: Config> Pallet<T> {
 		key: SumTreeNameType<T>,
 		phase_data: PhaseDataOf<T>,
 	) -> (u64, u64, bool) {
-		let max_draws = phase_data.max_draws;
+		let max_draws = Self::max_draws_of_round(key.clone(), phase_data);
 		let draws_in_round = <DrawsInRound<T>>::get(&key);
 		if draws_in_round >= max_draws.into() {
 			(max_draws, draws_in_round, true)
@@ -816,7 +1226,7 @@ impl<T: Config> Pallet<T> {
 	) -> Option<u32> {
 		let commit_start_time = <CommitStartTime<T>>::get(&key);
 		let commit_length = phase_data.commit_length;
-		let end_block = commit_start_time.checked_add(&commit_length).expect("Overflow");
+		let end_block = commit_start_time.checked_add(&commit_length)?;
 		let left_block = end_block.checked_sub(&now);
 		match left_block {
 			Some(val) => {
@@ -834,7 +1244,7 @@ impl<T: Config> Pallet<T> {
 	) -> Option<u32> {
 		let vote_start_time = <VoteStartTime<T>>::get(&key);
 		let vote_length = phase_data.vote_length;
-		let end_block = vote_start_time.checked_add(&vote_length).expect("Overflow");
+		let end_block = vote_start_time.checked_add(&vote_length)?;
 		let left_block = end_block.checked_sub(&now);
 		match left_block {
 			Some(val) => {
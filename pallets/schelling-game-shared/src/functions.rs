@@ -51,6 +51,26 @@ impl<T: Config> PhaseData<T> {
 		}
 	}
 
+	/// Phase data of the game type till it is set with `set_phase_data`
+	pub fn default_of_game_type(game_type: &SchellingGameType) -> Self {
+		match game_type {
+			SchellingGameType::ProfileApproval => PhaseData::create_phase_with_all_data(
+				10,
+				100,
+				100,
+				100,
+				100,
+				100,
+				100,
+				5,
+				5,
+				100,
+				(100, 100),
+			),
+			_ => PhaseData::create_with_data(50, 5, 3, 100, (100, 100)),
+		}
+	}
+
 	pub fn create_with_data(
 		block_length: u64,
 		max_draws: u64,
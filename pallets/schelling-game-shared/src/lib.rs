@@ -11,45 +11,70 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
-
-
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod weights;
 pub use weights::*;
+pub mod commit;
+pub use commit::vote_commit;
+pub mod randomness;
+pub use randomness::{BlockRandomness, DrawRandomness, JurorBeacon};
+pub mod reputation;
+pub use reputation::{CoherenceMultiplier, ReputationMultiplier};
 
+mod appeal;
+mod archive;
+mod court;
+mod delegation;
+mod distribution;
+mod evidence;
 mod extras;
 mod functions;
+mod multi_choice;
+mod resolve;
+mod schedule;
 pub mod types;
 mod score_game;
 mod share_link;
+mod void;
 
 use crate::types::{
-	CommitVote, Period, PhaseData, RangePoint, RevealedVote, SchellingGameType, ScoreCommitVote,
-	VoteStatus, WinningDecision,
+	CommitVersion, CommitVote, Court, CourtId, DelegationTarget, Evidence, EvidenceId, GameKind,
+	GameOutcome, GameResult, IncentiveCurve, IncentivePool, IncentiveProgress, IncentiveStage, JurorRecord,
+	MultiChoiceCommitVote, Period, PhaseData, RangePoint, RevealedVote, SchellingGameType,
+	ScoreAggregation, ScoreCommitVote, VoteStatus, WinningDecision, COURT_TREE_K,
+	FIRST_EVIDENCE_ID, MAX_APPEAL_ROUNDS, MAX_COURT_DEPTH, MAX_EVIDENCE_REPLY_DEPTH,
+	MAX_NUMBER_OF_CHOICES, MAX_PERIOD_CHANGE_DELAY,
 };
+use reputation::JurorVote;
 use frame_support::pallet_prelude::*;
-use frame_support::sp_runtime::traits::{CheckedAdd, CheckedSub};
-use frame_support::sp_runtime::SaturatedConversion;
+use frame_support::sp_runtime::traits::{AccountIdConversion, CheckedAdd, CheckedSub, One, Zero};
+use frame_support::sp_runtime::{Perbill, SaturatedConversion};
 use frame_support::sp_std::prelude::*;
 use frame_support::traits::Randomness;
-use frame_support::traits::{Currency, OnUnbalanced, ReservableCurrency};
+use frame_support::traits::{
+	Currency, ExistenceRequirement, OnUnbalanced, ReservableCurrency, WithdrawReasons,
+};
+use frame_support::PalletId;
 use num_integer::Roots;
+use pallet_support::{ensure_content_is_some, ensure_content_is_valid, Content};
 use scale_info::prelude::format;
 use sortition_sum_game::types::SumTreeName;
+use schelling_game_shared_link::{OnGameResolved, OnGameVoided};
 use sortition_sum_game_link::SortitionSumGameLink;
 
 pub type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
-type PositiveImbalanceOf<T> = <<T as Config>::Currency as Currency<
-	<T as frame_system::Config>::AccountId,
->>::PositiveImbalance;
 type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::NegativeImbalance;
 type SumTreeNameType<T> = SumTreeName<AccountIdOf<T>, BlockNumberOf<T>>;
 type PhaseDataOf<T> = PhaseData<T>;
+type EvidenceOf<T> = Evidence<AccountIdOf<T>, BalanceOf<T>, BlockNumberOf<T>>;
+
+/// Slashed juror stakes are held by the account of this id until they are given to the winning jurors
+const PALLET_ID: PalletId = PalletId(*b"sg/stake");
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -78,11 +103,62 @@ pub mod pallet {
 
 		type RandomnessSource: Randomness<Self::Hash, Self::BlockNumber>;
 
-		/// Handler for the unbalanced increment when rewarding (minting rewards)
-		type Reward: OnUnbalanced<PositiveImbalanceOf<Self>>;
+		/// Random numbers that draw the jurors, `JurorBeacon` mixes `RandomnessSource` with the entropy of the jurors
+		type DrawRandomness: DrawRandomness<SumTreeName<Self::AccountId, Self::BlockNumber>>;
+
+		/// Bonus of the juror in the draw from the record of the juror, it can exclude the juror from the games
+		type ReputationMultiplier: ReputationMultiplier;
+
+		/// Called when a game is voided, the consumer pallet of the game returns its deposits
+		type OnGameVoided: OnGameVoided<SumTreeName<Self::AccountId, Self::BlockNumber>>;
+
+		/// Called when a game reaches `Period::Execution` with the outcome of the game
+		type OnGameResolved: OnGameResolved<
+			SumTreeName<Self::AccountId, Self::BlockNumber>,
+			SchellingGameType,
+			GameOutcome,
+		>;
 
 		/// Handler for the unbalanced decrement when slashing (burning collateral)
 		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Fraction of the stake slashed from a drawn juror who did not commit the vote
+		#[pallet::constant]
+		type NonCommitSlashFraction: Get<Perbill>;
+
+		/// Fraction of the stake slashed from a drawn juror who committed but did not reveal the vote
+		#[pallet::constant]
+		type NonRevealSlashFraction: Get<Perbill>;
+
+		/// Maximum number of games whose period is changed in `on_initialize` of a block
+		/// Rest of the games are scheduled in the next blocks, `pass_period` can still be called for them
+		#[pallet::constant]
+		type MaxPeriodChangesPerBlock: Get<u32>;
+
+		/// Maximum number of votes and past round jurors of archived games removed in `on_idle` of a block
+		#[pallet::constant]
+		type MaxVoteRemovalsPerBlock: Get<u32>;
+
+		/// Origin that can change the phase data, the score aggregation and the incentive curve of a schelling game type
+		type PhaseDataOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Format of the vote commits of the games created from now on
+		/// Games created before keep the format they were created with
+		#[pallet::constant]
+		type VoteCommitVersion: Get<CommitVersion>;
+
+		/// Deposit reserved for every evidence, it is returned when the storage of the game is removed
+		/// Zero for evidence without a deposit
+		#[pallet::constant]
+		type EvidenceDeposit: Get<BalanceOf<Self>>;
+
+		/// Maximum number of evidence of a game, and of replies to an evidence
+		#[pallet::constant]
+		type MaxEvidencePerGame: Get<u32>;
+
+		/// Maximum number of delegators of a juror for a game type or a court
+		#[pallet::constant]
+		type MaxDelegatorsPerJuror: Get<u32>;
 	}
 
 	// The pallet's runtime storage items.
@@ -115,6 +191,12 @@ pub mod pallet {
 	pub type StakingStartTime<T> =
 		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, BlockNumberOf<T>, ValueQuery>;
 
+	/// Jurors are drawn till `drawing_length` after the drawing period starts
+	#[pallet::storage]
+	#[pallet::getter(fn drawing_start_time)]
+	pub type DrawingStartTime<T> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, BlockNumberOf<T>, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn commit_start_time)]
 	pub type CommitStartTime<T> =
@@ -149,6 +231,23 @@ pub mod pallet {
 		CommitVote,
 	>;
 
+	/// Hash of the entropy committed by the juror for drawing the jurors
+	#[pallet::storage]
+	#[pallet::getter(fn draw_entropy_commits)]
+	pub type DrawEntropyCommits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		[u8; 32],
+	>;
+
+	/// Entropy revealed by the jurors of the game mixed together
+	#[pallet::storage]
+	#[pallet::getter(fn draw_entropy)]
+	pub type DrawEntropy<T: Config> = StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, [u8; 32]>;
+
 	/// Vote Commits for Score Schelling  
 	#[pallet::storage]
 	#[pallet::getter(fn vote_commits_score)]
@@ -174,6 +273,30 @@ pub mod pallet {
 	pub type IncentiveMeanRevealScore<T: Config> =
 		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, i64, ValueQuery>;
 
+	/// Vote Commits for multi choice schelling game
+	#[pallet::storage]
+	#[pallet::getter(fn vote_commits_multi_choice)]
+	pub type MultiChoiceVoteCommits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		MultiChoiceCommitVote,
+	>;
+
+	/// Number of choices of multi choice schelling game, choices are `0..number_of_choices`
+	#[pallet::storage]
+	#[pallet::getter(fn number_of_choices)]
+	pub type MultiChoiceNumberOfChoices<T> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, u32>;
+
+	/// Decision count for each choice of multi choice schelling game after reveal vote
+	#[pallet::storage]
+	#[pallet::getter(fn multi_choice_decision_count)]
+	pub type MultiChoiceDecisionCount<T> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, Vec<u64>, ValueQuery>;
+
 	/// Decision count for two choices after reveal vote:  (count for 0, count for 1)
 	#[pallet::storage]
 	#[pallet::getter(fn decision_count)]
@@ -185,6 +308,281 @@ pub mod pallet {
 	pub type JurorsIncentiveDistributedAccounts<T: Config> =
 		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, Vec<T::AccountId>, ValueQuery>;
 
+	/// Slashes of the losing and non participating jurors are moved to the juror stake account
+	#[pallet::storage]
+	#[pallet::getter(fn juror_penalties_collected)]
+	pub type JurorPenaltiesCollected<T> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, bool, ValueQuery>;
+
+	/// Incentives of each round of the game not paid yet, filled when the penalties are collected
+	#[pallet::storage]
+	#[pallet::getter(fn round_incentive_pool)]
+	pub type RoundIncentivePools<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Twox64Concat,
+		u64,
+		IncentivePool,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn appeal_start_time)]
+	pub type AppealStartTime<T> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, BlockNumberOf<T>, ValueQuery>;
+
+	/// Number of appeals made on the game, `0` while the first round is running
+	#[pallet::storage]
+	#[pallet::getter(fn appeal_round)]
+	pub type AppealRound<T> = StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, u64, ValueQuery>;
+
+	/// Accounts that paid the appeal fee along with the fee paid, in the order of appeals
+	#[pallet::storage]
+	#[pallet::getter(fn appellants)]
+	pub type Appellants<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Vec<(T::AccountId, BalanceOf<T>)>,
+		ValueQuery,
+	>;
+
+	/// Jurors of the rounds that were appealed: Vec<(AccountId, Stake, Commit vote)>  
+	/// They are settled against the decision of the final round
+	#[pallet::storage]
+	#[pallet::getter(fn past_round_jurors)]
+	pub type PastRoundJurors<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Twox64Concat,
+		u64,
+		Vec<(T::AccountId, u64, Option<CommitVote>)>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn past_rounds_incentive_distribution)]
+	pub type PastRoundsIncentiveDistributedAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, Vec<T::AccountId>, ValueQuery>;
+
+	/// Games whose current period ends at the block, their period is changed in `on_initialize`
+	/// At most `MaxPeriodChangesPerBlock` games are changed in a block
+	#[pallet::storage]
+	#[pallet::getter(fn period_change_queue)]
+	pub type PeriodChangeQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberOf<T>,
+		BoundedVec<SumTreeNameType<T>, T::MaxPeriodChangesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Phase data of the schelling game types set by `PhaseDataOrigin`
+	/// Game types not in the map use `PhaseData::default_of_game_type`
+	#[pallet::storage]
+	#[pallet::getter(fn game_type_phase_data)]
+	pub type GameTypePhaseData<T: Config> =
+		StorageMap<_, Blake2_128Concat, SchellingGameType, PhaseDataOf<T>>;
+
+	/// Schelling game type of the game, kept when the game is created
+	#[pallet::storage]
+	#[pallet::getter(fn game_type)]
+	pub type GameType<T: Config> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, SchellingGameType>;
+
+	/// Kind of votes of the game, kept when the game is created
+	/// Games created before the kind was kept have no entry, see `get_game_kind`
+	#[pallet::storage]
+	#[pallet::getter(fn game_kind)]
+	pub type GameKinds<T: Config> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, GameKind>;
+
+	/// Format of the vote commits of the game, set when the game is created
+	/// Games created before the format was kept use `CommitVersion::V0`
+	#[pallet::storage]
+	#[pallet::getter(fn game_commit_version)]
+	pub type GameCommitVersion<T: Config> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, CommitVersion, ValueQuery>;
+
+	/// Phase data of the game, copied from its game type when the game is created
+	/// Changing the phase data of the game type does not change the games in progress
+	#[pallet::storage]
+	#[pallet::getter(fn game_phase_data)]
+	pub type GamePhaseData<T: Config> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, PhaseDataOf<T>>;
+
+	/// Score aggregation of the schelling game types set by `PhaseDataOrigin`
+	#[pallet::storage]
+	#[pallet::getter(fn game_type_score_aggregation)]
+	pub type GameTypeScoreAggregation<T: Config> =
+		StorageMap<_, Blake2_128Concat, SchellingGameType, ScoreAggregation, ValueQuery>;
+
+	/// Score aggregation of the game, copied from its game type when the game is created
+	#[pallet::storage]
+	#[pallet::getter(fn game_score_aggregation)]
+	pub type GameScoreAggregation<T: Config> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, ScoreAggregation, ValueQuery>;
+
+	/// Incentive curve of score schelling game of the game types set by `PhaseDataOrigin`
+	#[pallet::storage]
+	#[pallet::getter(fn game_type_incentive_curve)]
+	pub type GameTypeIncentiveCurve<T: Config> =
+		StorageMap<_, Blake2_128Concat, SchellingGameType, IncentiveCurve, ValueQuery>;
+
+	/// Incentive curve of the game, copied from its game type when the game is created
+	#[pallet::storage]
+	#[pallet::getter(fn game_incentive_curve)]
+	pub type GameIncentiveCurve<T: Config> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, IncentiveCurve, ValueQuery>;
+
+	/// Range point of score schelling game of the game types set by `PhaseDataOrigin`
+	#[pallet::storage]
+	#[pallet::getter(fn game_type_range_point)]
+	pub type GameTypeRangePoint<T: Config> =
+		StorageMap<_, Blake2_128Concat, SchellingGameType, RangePoint, ValueQuery>;
+
+	/// Range point of the game, copied from its game type when the game is created
+	/// It is used by `distribute_incentives`, the consumer pallets give their own range point to the other helpers
+	#[pallet::storage]
+	#[pallet::getter(fn game_range_point)]
+	pub type GameRangePoint<T: Config> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, RangePoint, ValueQuery>;
+
+	/// Progress of the incentives of the game distributed in pages by `distribute_incentives`
+	#[pallet::storage]
+	#[pallet::getter(fn incentive_progress)]
+	pub type IncentiveProgresses<T: Config> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, IncentiveProgress>;
+
+	/// Result of the settled games, it is kept after the storage of the game is removed
+	#[pallet::storage]
+	#[pallet::getter(fn game_result)]
+	pub type GameResults<T: Config> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, GameResult<BlockNumberOf<T>>>;
+
+	/// Settled games whose storage is removed in `on_idle`
+	#[pallet::storage]
+	#[pallet::getter(fn games_to_clean_up)]
+	pub type GamesToCleanUp<T: Config> = StorageValue<_, Vec<SumTreeNameType<T>>, ValueQuery>;
+
+	/// Votes of the juror in all the settled games
+	#[pallet::storage]
+	#[pallet::getter(fn juror_record)]
+	pub type JurorRecords<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, JurorRecord, ValueQuery>;
+
+	/// Stake reserved by the jurors who applied for the game with a reputation bonus
+	/// Weight of the juror in the sortition tree is the stake with the bonus, jurors without a bonus are not in the map
+	#[pallet::storage]
+	#[pallet::getter(fn juror_stakes)]
+	pub type JurorStakes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		u64,
+	>;
+
+	/// Courts of specialized jurors, created by `PhaseDataOrigin`
+	#[pallet::storage]
+	#[pallet::getter(fn court)]
+	pub type Courts<T: Config> = StorageMap<_, Twox64Concat, CourtId, Court<T>>;
+
+	/// Court the juror staked in and the stake: (CourtId, Stake)
+	#[pallet::storage]
+	#[pallet::getter(fn juror_court)]
+	pub type JurorCourts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (CourtId, u64)>;
+
+	/// Game the court stake of the juror is drawn for, the juror stays in the court and is not drawn again till the game returns the stake
+	#[pallet::storage]
+	#[pallet::getter(fn court_draw)]
+	pub type CourtDraws<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, SumTreeNameType<T>>;
+
+	/// Court of the game, jurors of the game are drawn from the court and its ancestors
+	/// Games without a court draw the jurors who applied for the game
+	#[pallet::storage]
+	#[pallet::getter(fn game_court)]
+	pub type GameCourt<T: Config> = StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, CourtId>;
+
+	#[pallet::type_value]
+	pub fn DefaultForNextEvidenceId() -> EvidenceId {
+		FIRST_EVIDENCE_ID
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_evidence_id)]
+	pub type NextEvidenceId<T: Config> =
+		StorageValue<_, EvidenceId, ValueQuery, DefaultForNextEvidenceId>;
+
+	/// Evidence of the games and the replies to them
+	#[pallet::storage]
+	#[pallet::getter(fn evidence)]
+	pub type Evidences<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Blake2_128Concat,
+		EvidenceId,
+		EvidenceOf<T>,
+	>;
+
+	/// Evidence of the game in the order they were submitted, replies are not included
+	#[pallet::storage]
+	#[pallet::getter(fn game_evidence_ids)]
+	pub type GameEvidenceIds<T: Config> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, Vec<EvidenceId>, ValueQuery>;
+
+	/// Replies to the evidence in the order they were submitted
+	#[pallet::storage]
+	#[pallet::getter(fn evidence_reply_ids)]
+	pub type EvidenceReplyIds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Blake2_128Concat,
+		EvidenceId,
+		Vec<EvidenceId>,
+		ValueQuery,
+	>;
+
+	/// Stake delegated to the juror for the game type or the court: Vec<(Delegator, Amount)>
+	/// Delegators are sorted, the amount adds to the weight of the juror in the sortition tree
+	#[pallet::storage]
+	#[pallet::getter(fn delegations)]
+	pub type Delegations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		DelegationTarget,
+		Vec<(T::AccountId, u64)>,
+		ValueQuery,
+	>;
+
+	/// Delegations of the juror when the juror applied for the game or was drawn from the court
+	/// Delegators share the incentives of the juror in the game
+	#[pallet::storage]
+	#[pallet::getter(fn game_delegations)]
+	pub type GameDelegations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		(DelegationTarget, Vec<(T::AccountId, u64)>),
+	>;
+
+	/// Number of games with the delegations of the juror, delegations are not withdrawn while it is not zero
+	#[pallet::storage]
+	#[pallet::getter(fn juror_active_games)]
+	pub type JurorActiveGames<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
 	#[pallet::event]
@@ -193,6 +591,145 @@ pub mod pallet {
 		/// Event documentation should end with an array that provides descriptive names for event
 		/// parameters. [something, who]
 		SomethingStored { something: u32, who: T::AccountId },
+		/// Drawn juror did not commit the vote, the slashed stake goes to the coherent jurors
+		JurorNotCommitted {
+			key: SumTreeName<T::AccountId, T::BlockNumber>,
+			juror: T::AccountId,
+			slashed: BalanceOf<T>,
+		},
+		/// Drawn juror did not reveal the vote, the slashed stake goes to the coherent jurors
+		JurorNotRevealed {
+			key: SumTreeName<T::AccountId, T::BlockNumber>,
+			juror: T::AccountId,
+			slashed: BalanceOf<T>,
+		},
+		/// Phase data of the game type changed, new games of the type use it
+		PhaseDataSet { game_type: SchellingGameType },
+		/// Score aggregation of the game type changed, new games of the type use it
+		ScoreAggregationSet { game_type: SchellingGameType, aggregation: ScoreAggregation },
+		/// Incentive curve of the game type changed, new games of the type use it
+		IncentiveCurveSet { game_type: SchellingGameType, curve: IncentiveCurve },
+		/// Range point of the game type changed, new games of the type use it
+		RangePointSet { game_type: SchellingGameType, range_point: RangePoint },
+		/// All the jurors of the game got their incentives, the result of the game is archived
+		GameArchived {
+			key: SumTreeName<T::AccountId, T::BlockNumber>,
+			result: GameResult<T::BlockNumber>,
+		},
+		/// Juror committed the hash of the entropy for drawing the jurors
+		DrawEntropyCommitted { key: SumTreeName<T::AccountId, T::BlockNumber>, juror: T::AccountId },
+		/// Juror revealed the entropy for drawing the jurors
+		DrawEntropyRevealed { key: SumTreeName<T::AccountId, T::BlockNumber>, juror: T::AccountId },
+		/// Court created, `parent` is `None` for top courts
+		CourtCreated { court_id: CourtId, parent: Option<CourtId> },
+		/// Juror staked in the court
+		JurorStakedInCourt { court_id: CourtId, juror: T::AccountId, stake: BalanceOf<T> },
+		/// Juror left the court and got back the stake
+		JurorUnstakedFromCourt { court_id: CourtId, juror: T::AccountId, stake: BalanceOf<T> },
+		/// Jurors of the game are drawn from the court
+		GameCourtSet { key: SumTreeName<T::AccountId, T::BlockNumber>, court_id: CourtId },
+		/// Too few jurors were drawn before the drawing deadline, stakes of the jurors are returned in `on_idle`
+		GameVoided { key: SumTreeName<T::AccountId, T::BlockNumber> },
+		/// Game reached the execution period, `OnGameResolved` got the outcome
+		GameResolved {
+			key: SumTreeName<T::AccountId, T::BlockNumber>,
+			game_type: SchellingGameType,
+			outcome: GameOutcome,
+		},
+		/// Evidence submitted for the game, `reply_to` is the evidence it replies to
+		EvidenceSubmitted {
+			key: SumTreeName<T::AccountId, T::BlockNumber>,
+			evidence_id: EvidenceId,
+			submitter: T::AccountId,
+			reply_to: Option<EvidenceId>,
+		},
+		/// Page of the jurors of the game got their incentives, the progress is kept for the next page
+		IncentivePageDistributed {
+			key: SumTreeName<T::AccountId, T::BlockNumber>,
+			jurors: u32,
+			progress: IncentiveProgress,
+		},
+		/// All the jurors of the game got their incentives with `distribute_incentives`
+		IncentivesDistributed { key: SumTreeName<T::AccountId, T::BlockNumber> },
+		/// Stake of the delegator is reserved, it adds to the weight of the juror
+		StakeDelegated {
+			delegator: T::AccountId,
+			juror: T::AccountId,
+			target: DelegationTarget,
+			amount: BalanceOf<T>,
+		},
+		/// Delegator got back the stake delegated to the juror
+		DelegationWithdrawn {
+			delegator: T::AccountId,
+			juror: T::AccountId,
+			target: DelegationTarget,
+			amount: BalanceOf<T>,
+		},
+		/// Delegators of the juror got their share of the reward, or lost the same part of the delegated stake as the juror
+		DelegatorsIncentivesShared {
+			key: SumTreeName<T::AccountId, T::BlockNumber>,
+			juror: T::AccountId,
+			rewarded: BalanceOf<T>,
+			slashed: BalanceOf<T>,
+		},
+		/// Storage of the archived game is removed
+		GameStorageRemoved { key: SumTreeName<T::AccountId, T::BlockNumber> },
+		/// Period of the game changed
+		PeriodChanged { key: SumTreeName<T::AccountId, T::BlockNumber>, period: Period },
+		/// Juror applied for the game with the stake
+		JurorApplied {
+			key: SumTreeName<T::AccountId, T::BlockNumber>,
+			juror: T::AccountId,
+			stake: BalanceOf<T>,
+		},
+		/// Juror was drawn for the current round
+		JurorDrawn {
+			key: SumTreeName<T::AccountId, T::BlockNumber>,
+			juror: T::AccountId,
+			stake: BalanceOf<T>,
+		},
+		/// Juror who was not drawn got back the stake
+		JurorUnstaked {
+			key: SumTreeName<T::AccountId, T::BlockNumber>,
+			juror: T::AccountId,
+			stake: BalanceOf<T>,
+		},
+		/// Drawn juror committed the vote
+		VoteCommitted { key: SumTreeName<T::AccountId, T::BlockNumber>, juror: T::AccountId },
+		/// Juror revealed the vote of two choice schelling game
+		VoteRevealed {
+			key: SumTreeName<T::AccountId, T::BlockNumber>,
+			juror: T::AccountId,
+			vote: RevealedVote,
+		},
+		/// Juror revealed the vote of score schelling game
+		ScoreVoteRevealed {
+			key: SumTreeName<T::AccountId, T::BlockNumber>,
+			juror: T::AccountId,
+			score: i64,
+		},
+		/// Juror revealed the vote of multi choice schelling game
+		MultiChoiceVoteRevealed {
+			key: SumTreeName<T::AccountId, T::BlockNumber>,
+			juror: T::AccountId,
+			choice: u32,
+		},
+		/// Choice of multi choice schelling game with the most votes, `None` on a tie
+		WinningChoiceDecided { key: SumTreeName<T::AccountId, T::BlockNumber>, choice: Option<u32> },
+		/// Decision of two choice schelling game, jurors get their incentives against it
+		WinningDecisionDecided {
+			key: SumTreeName<T::AccountId, T::BlockNumber>,
+			decision: WinningDecision,
+		},
+		/// Mean of the revealed scores (multiplied by 1000), jurors get their incentives against it
+		ScoreMeanDecided { key: SumTreeName<T::AccountId, T::BlockNumber>, mean: i64 },
+		/// Decision of the round was appealed, a new round with a larger jury starts
+		Appealed {
+			key: SumTreeName<T::AccountId, T::BlockNumber>,
+			appellant: T::AccountId,
+			fee: BalanceOf<T>,
+			round: u64,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -227,6 +764,49 @@ pub mod pallet {
 		VoteNotRevealed,
 		TimeForStakingOver,
 		TimeForStakingNotOver,
+		AppealPeriodNotOver,
+		TimeForAppealOver,
+		MaxAppealsReached,
+		AppealNotAllowed,
+		InvalidPhaseData,
+		InvalidNumberOfChoices,
+		NumberOfChoicesNotSet,
+		/// Votes of the kind can not be committed in the game
+		GameKindDontMatch,
+		InvalidScoreAggregation,
+		NoScoreRevealed,
+		EntropyAlreadyCommitted,
+		EntropyRevealClosed,
+		JurorReputationTooLow,
+		CourtExists,
+		CourtDoesNotExist,
+		MaxCourtDepthReached,
+		NoJurorsInCourt,
+		JurorsStakeInCourt,
+		GameVoided,
+		EvidenceDoesNotExist,
+		MaxEvidenceReached,
+		MaxReplyDepthReached,
+		IncentivesBeingDistributed,
+		InvalidDelegation,
+		MaxDelegatorsReached,
+		DelegationDoesNotExist,
+		JurorInActiveGame,
+		/// Block number of the end of the period overflows
+		BlockNumberOverflow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Change the period of the games whose period ends at `now`
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			Self::change_scheduled_periods(now)
+		}
+
+		/// Remove the storage of the archived games with the weight left in the block
+		fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::clean_up_archived_games(remaining_weight)
+		}
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -272,5 +852,190 @@ pub mod pallet {
 				},
 			}
 		}
+
+		/// Set the phase data of the schelling game type
+		/// Games created after it use the new phase data, games in progress keep their own copy
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_phase_data())]
+		pub fn set_phase_data(
+			origin: OriginFor<T>,
+			game_type: SchellingGameType,
+			phase_data: PhaseDataOf<T>,
+		) -> DispatchResult {
+			T::PhaseDataOrigin::ensure_origin(origin)?;
+			ensure!(phase_data.max_draws > 0, Error::<T>::InvalidPhaseData);
+
+			<GameTypePhaseData<T>>::insert(&game_type, phase_data);
+
+			Self::deposit_event(Event::PhaseDataSet { game_type });
+			Ok(())
+		}
+
+		/// Set the statistic that decides the score and the winners of score schelling game of the game type
+		/// Games created after it use the new aggregation, games in progress keep their own copy
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::set_score_aggregation())]
+		pub fn set_score_aggregation(
+			origin: OriginFor<T>,
+			game_type: SchellingGameType,
+			aggregation: ScoreAggregation,
+		) -> DispatchResult {
+			T::PhaseDataOrigin::ensure_origin(origin)?;
+			if let ScoreAggregation::TrimmedMean(trim_percent) = aggregation {
+				ensure!(trim_percent < 50, Error::<T>::InvalidScoreAggregation);
+			}
+
+			<GameTypeScoreAggregation<T>>::insert(&game_type, aggregation.clone());
+
+			Self::deposit_event(Event::ScoreAggregationSet { game_type, aggregation });
+			Ok(())
+		}
+
+		/// Set how the incentives of score schelling game jurors change with the distance from the score of the game
+		/// Games created after it use the new curve, games in progress keep their own copy
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::set_incentive_curve())]
+		pub fn set_incentive_curve(
+			origin: OriginFor<T>,
+			game_type: SchellingGameType,
+			curve: IncentiveCurve,
+		) -> DispatchResult {
+			T::PhaseDataOrigin::ensure_origin(origin)?;
+
+			<GameTypeIncentiveCurve<T>>::insert(&game_type, curve.clone());
+
+			Self::deposit_event(Event::IncentiveCurveSet { game_type, curve });
+			Ok(())
+		}
+
+		/// Commit `keccak_256(entropy)` in `Period::Staking`, the caller must have applied as juror
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::commit_draw_entropy())]
+		pub fn commit_draw_entropy(
+			origin: OriginFor<T>,
+			key: SumTreeNameType<T>,
+			entropy_commit: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::commit_draw_entropy_helper(key, who, entropy_commit)
+		}
+
+		/// Reveal the committed entropy in `Period::Drawing` before the first juror is drawn
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::reveal_draw_entropy())]
+		pub fn reveal_draw_entropy(
+			origin: OriginFor<T>,
+			key: SumTreeNameType<T>,
+			entropy: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::reveal_draw_entropy_helper(key, who, entropy)
+		}
+
+		/// Create a court, jurors staked in it are drawn for the disputes of the court and of its children
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::create_court())]
+		pub fn create_court(
+			origin: OriginFor<T>,
+			court_id: CourtId,
+			parent: Option<CourtId>,
+			min_stake: BalanceOf<T>,
+			phase_data: PhaseDataOf<T>,
+		) -> DispatchResult {
+			T::PhaseDataOrigin::ensure_origin(origin)?;
+			Self::create_court_helper(court_id, parent, min_stake, phase_data)
+		}
+
+		/// Stake in the court, the stake is reserved till the juror leaves the court
+		/// Drawn jurors stay in the court with the stake returned by the game
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::stake_in_court())]
+		pub fn stake_in_court(
+			origin: OriginFor<T>,
+			court_id: CourtId,
+			stake: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::stake_in_court_helper(who, court_id, stake)
+		}
+
+		/// Leave the court and get back the stake, drawn jurors leave after the game returns the stake
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::unstake_from_court())]
+		pub fn unstake_from_court(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::unstake_from_court_helper(who)
+		}
+
+		/// Submit evidence for the game, or a reply to an evidence of the game with `reply_to`
+		/// Evidence is submitted in `Period::Evidence`, replies also in `Period::Staking`
+		/// `EvidenceDeposit` is reserved
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::submit_evidence())]
+		pub fn submit_evidence(
+			origin: OriginFor<T>,
+			key: SumTreeNameType<T>,
+			content: Content,
+			reply_to: Option<EvidenceId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::submit_evidence_helper(key, who, content, reply_to)
+		}
+
+		/// Set the range point of score schelling game of the game type for `distribute_incentives`
+		/// Games created after it use the new range point, games in progress keep their own copy
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_range_point())]
+		pub fn set_range_point(
+			origin: OriginFor<T>,
+			game_type: SchellingGameType,
+			range_point: RangePoint,
+		) -> DispatchResult {
+			T::PhaseDataOrigin::ensure_origin(origin)?;
+
+			<GameTypeRangePoint<T>>::insert(&game_type, range_point.clone());
+
+			Self::deposit_event(Event::RangePointSet { game_type, range_point });
+			Ok(())
+		}
+
+		/// Distribute the incentives of at most `max_jurors` jurors of the game in `Period::Execution`
+		/// It is called again till all the jurors got their incentives, the progress is kept in `IncentiveProgresses`
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::distribute_incentives(*max_jurors))]
+		pub fn distribute_incentives(
+			origin: OriginFor<T>,
+			key: SumTreeNameType<T>,
+			max_jurors: u32,
+		) -> DispatchResult {
+			let _who = ensure_signed(origin)?;
+			Self::distribute_incentives_helper(key, max_jurors)
+		}
+
+		/// Delegate stake to the juror for the game type or the court, the stake is reserved
+		/// Delegators share the rewards and the slashes of the juror by the stake
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::delegate_stake())]
+		pub fn delegate_stake(
+			origin: OriginFor<T>,
+			juror: T::AccountId,
+			target: DelegationTarget,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::delegate_stake_helper(who, juror, target, amount)
+		}
+
+		/// Withdraw the stake delegated to the juror, the juror must not be in a game with the delegations
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::withdraw_delegation())]
+		pub fn withdraw_delegation(
+			origin: OriginFor<T>,
+			juror: T::AccountId,
+			target: DelegationTarget,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::withdraw_delegation_helper(who, juror, target)
+		}
 	}
 }
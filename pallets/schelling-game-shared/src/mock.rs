@@ -1,9 +1,16 @@
 use crate as pallet_template;
-use frame_support::traits::{ConstU16, ConstU64};
+use crate::types::{CommitVersion, GameOutcome, SchellingGameType};
+use frame_support::{
+	parameter_types,
+	traits::{ConstU16, ConstU64},
+};
+use schelling_game_shared_link::{OnGameResolved, OnGameVoided};
+use sortition_sum_game::types::SumTreeName;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	Perbill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -51,14 +58,70 @@ impl frame_system::Config for Test {
 	type AccountData = pallet_balances::AccountData<u64>; // New code
 }
 
+parameter_types! {
+	pub const NonCommitSlashFraction: Perbill = Perbill::from_percent(50);
+	pub const NonRevealSlashFraction: Perbill = Perbill::from_percent(50);
+	pub const MaxPeriodChangesPerBlock: u32 = 10;
+	pub const MaxVoteRemovalsPerBlock: u32 = 3;
+	pub const MinDecidedVotes: u64 = 3;
+	pub const MinCoherenceRate: Perbill = Perbill::from_percent(50);
+	pub const BonusPerCoherentVote: Perbill = Perbill::from_percent(10);
+	pub const MaxReputationBonus: Perbill = Perbill::from_percent(50);
+	pub static VoidedGames: Vec<SumTreeName<u64, u64>> = vec![];
+	pub static ResolvedGames: Vec<(SumTreeName<u64, u64>, SchellingGameType, GameOutcome)> = vec![];
+	pub static VoteCommitVersion: CommitVersion = CommitVersion::V0;
+	pub const EvidenceDeposit: u64 = 100;
+	pub const MaxEvidencePerGame: u32 = 3;
+	pub const MaxDelegatorsPerJuror: u32 = 3;
+}
+
+/// Keeps the voided games for the tests
+pub struct RecordVoidedGames;
+
+impl OnGameVoided<SumTreeName<u64, u64>> for RecordVoidedGames {
+	fn on_game_voided(key: SumTreeName<u64, u64>) {
+		VoidedGames::mutate(|games| games.push(key));
+	}
+}
+
+/// Keeps the resolved games with their outcome for the tests
+pub struct RecordResolvedGames;
+
+impl OnGameResolved<SumTreeName<u64, u64>, SchellingGameType, GameOutcome> for RecordResolvedGames {
+	fn on_game_resolved(
+		key: SumTreeName<u64, u64>,
+		game_type: SchellingGameType,
+		outcome: GameOutcome,
+	) {
+		ResolvedGames::mutate(|games| games.push((key, game_type, outcome)));
+	}
+}
+
 impl pallet_template::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type Currency = Balances; // New code
 	type RandomnessSource = TestRandomness<Self>;
+	type DrawRandomness = pallet_template::JurorBeacon<Self>;
+	type ReputationMultiplier = pallet_template::CoherenceMultiplier<
+		MinDecidedVotes,
+		MinCoherenceRate,
+		BonusPerCoherentVote,
+		MaxReputationBonus,
+	>;
+	type OnGameVoided = RecordVoidedGames;
+	type OnGameResolved = RecordResolvedGames;
 	type Slash = ();
-	type Reward = ();
 	type SortitionSumGameSource = SortitionSumGame;
+	type NonCommitSlashFraction = NonCommitSlashFraction;
+	type NonRevealSlashFraction = NonRevealSlashFraction;
+	type MaxPeriodChangesPerBlock = MaxPeriodChangesPerBlock;
+	type MaxVoteRemovalsPerBlock = MaxVoteRemovalsPerBlock;
+	type PhaseDataOrigin = frame_system::EnsureRoot<u64>;
+	type VoteCommitVersion = VoteCommitVersion;
+	type EvidenceDeposit = EvidenceDeposit;
+	type MaxEvidencePerGame = MaxEvidencePerGame;
+	type MaxDelegatorsPerJuror = MaxDelegatorsPerJuror;
 }
 
 impl sortition_sum_game::Config for Test {
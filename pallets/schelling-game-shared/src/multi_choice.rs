@@ -0,0 +1,228 @@
+use crate::*;
+use scale_info::prelude::vec;
+
+impl<T: Config> Pallet<T> {
+	/// Set the number of choices of multi choice schelling game, jurors vote for a choice in `0..number_of_choices`
+	/// It is called when the game is created, before the commit period
+	pub(super) fn set_number_of_choices_helper(
+		key: SumTreeNameType<T>,
+		number_of_choices: u32,
+	) -> DispatchResult {
+		match <PeriodName<T>>::get(&key) {
+			Some(period) => {
+				ensure!(
+					period == Period::Evidence || period == Period::Staking,
+					Error::<T>::PeriodDontMatch
+				);
+			},
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+		ensure!(
+			(2..=MAX_NUMBER_OF_CHOICES).contains(&number_of_choices),
+			Error::<T>::InvalidNumberOfChoices
+		);
+		<GameKinds<T>>::insert(&key, GameKind::MultiChoice);
+		<MultiChoiceNumberOfChoices<T>>::insert(&key, number_of_choices);
+		<MultiChoiceDecisionCount<T>>::insert(&key, vec![0u64; number_of_choices as usize]);
+		Ok(())
+	}
+
+	/// Commit your vote for multi choice schelling game
+	pub(super) fn commit_vote_for_multi_choice_helper(
+		key: SumTreeNameType<T>,
+		who: AccountIdOf<T>,
+		vote_commit: [u8; 32],
+	) -> DispatchResult {
+		match <PeriodName<T>>::get(&key) {
+			Some(period) => {
+				ensure!(period == Period::Commit, Error::<T>::PeriodDontMatch);
+			},
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+		ensure!(
+			<MultiChoiceNumberOfChoices<T>>::contains_key(&key),
+			Error::<T>::NumberOfChoicesNotSet
+		);
+		Self::ensure_game_kind(&key, GameKind::MultiChoice)?;
+		let drawn_jurors = <DrawnJurors<T>>::get(&key);
+		match drawn_jurors.binary_search_by(|(c, _)| c.cmp(&who.clone())) {
+			Ok(_) => {
+				let vote_commit_struct = MultiChoiceCommitVote {
+					commit: vote_commit,
+					votestatus: VoteStatus::Commited,
+					revealed_vote: None,
+				};
+				<MultiChoiceVoteCommits<T>>::insert(&key, &who, vote_commit_struct);
+				Self::deposit_event(Event::VoteCommitted { key: key.clone(), juror: who });
+			},
+			Err(_) => Err(Error::<T>::JurorDoesNotExists)?,
+		}
+		Ok(())
+	}
+
+	/// Reveal the choice of multi choice schelling game
+	/// Commit is in the format of the game, same as two choice schelling game
+	pub(super) fn reveal_vote_multi_choice_helper(
+		key: SumTreeNameType<T>,
+		who: AccountIdOf<T>,
+		choice: u32,
+		salt: Vec<u8>,
+	) -> DispatchResult {
+		match <PeriodName<T>>::get(&key) {
+			Some(period) => {
+				ensure!(period == Period::Vote, Error::<T>::PeriodDontMatch);
+			},
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+		let number_of_choices =
+			<MultiChoiceNumberOfChoices<T>>::get(&key).ok_or(Error::<T>::NumberOfChoicesNotSet)?;
+		ensure!(choice < number_of_choices, Error::<T>::NotValidChoice);
+		let who_commit_vote = <MultiChoiceVoteCommits<T>>::get(&key, &who);
+		match who_commit_vote {
+			Some(mut commit_struct) => {
+				ensure!(
+					commit_struct.votestatus == VoteStatus::Commited,
+					Error::<T>::VoteStatusNotCommited
+				);
+				let hash = Self::get_vote_commit(&key, &who, choice, &salt);
+				if hash == commit_struct.commit {
+					<MultiChoiceDecisionCount<T>>::mutate(&key, |decision_count| {
+						if let Some(count) = decision_count.get_mut(choice as usize) {
+							*count = count.saturating_add(1);
+						}
+					});
+					commit_struct.revealed_vote = Some(choice);
+					commit_struct.votestatus = VoteStatus::Revealed;
+					<MultiChoiceVoteCommits<T>>::insert(&key, &who, commit_struct);
+					Self::deposit_event(Event::MultiChoiceVoteRevealed {
+						key: key.clone(),
+						juror: who,
+						choice,
+					});
+				} else {
+					Err(Error::<T>::CommitDoesNotMatch)?
+				}
+			},
+			None => Err(Error::<T>::CommitDoesNotExists)?,
+		}
+
+		Ok(())
+	}
+
+	/// Choice with the most revealed votes
+	/// `None` when no vote is revealed or the top choices have the same number of votes
+	/// Winning choice of archived games is kept in `GameResults` after their storage is removed
+	pub(super) fn get_winning_choice(key: SumTreeNameType<T>) -> Option<u32> {
+		if let Some(GameResult { outcome: GameOutcome::MultiChoice(choice), .. }) =
+			<GameResults<T>>::get(&key)
+		{
+			return choice
+		}
+		let decision_count = <MultiChoiceDecisionCount<T>>::get(&key);
+		let max_count = decision_count.iter().max().copied().unwrap_or(0);
+		if max_count == 0 {
+			return None
+		}
+		let mut top_choices = decision_count.iter().enumerate().filter(|(_, c)| **c == max_count);
+		match (top_choices.next(), top_choices.next()) {
+			(Some((choice, _)), None) => Some(choice as u32),
+			_ => None,
+		}
+	}
+
+	/// Distribute incentives to all jurors in execution period in multi choice schelling game
+	/// Jurors who picked the winning choice share the penalties of the other jurors
+	/// When there is no winning choice, jurors who revealed get back their stake and share the penalties
+	pub(super) fn get_incentives_multi_choice_helper(
+		key: SumTreeNameType<T>,
+		_phase_data: PhaseDataOf<T>,
+	) -> DispatchResult {
+		match <PeriodName<T>>::get(&key) {
+			Some(period) => {
+				ensure!(period == Period::Execution, Error::<T>::PeriodDontMatch);
+			},
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+		Self::ensure_game_not_voided(key.clone())?;
+		Self::ensure_incentives_not_in_pages(key.clone())?;
+
+		let winning_choice = Self::get_winning_choice(key.clone());
+		let drawn_jurors = <DrawnJurors<T>>::get(&key);
+		let mut winners = vec![];
+		let mut draw_jurors = vec![];
+		// Penalties of the losing jurors, they are given to the winning jurors
+		let mut penalties = 0u64;
+		for (juror, stake) in drawn_jurors {
+			match <MultiChoiceVoteCommits<T>>::get(&key, &juror) {
+				Some(MultiChoiceCommitVote { revealed_vote: Some(choice), .. }) => {
+					match winning_choice {
+						Some(winning_choice) if winning_choice == choice => {
+							winners.push((juror, stake));
+						},
+						Some(_) => {
+							let penalty = Self::get_incoherent_penalty(stake);
+							Self::slash_reserved_stake(&juror, penalty);
+							penalties = penalties.saturating_add(penalty);
+							Self::looser_getting_incentives2(&key, juror, stake)?;
+						},
+						None => draw_jurors.push((juror, stake)),
+					}
+				},
+				Some(_) => {
+					let slash =
+						Self::slash_non_participant(key.clone(), juror.clone(), stake, true);
+					penalties = penalties.saturating_add(slash);
+					Self::non_participant_getting_incentives2(&key, juror, stake, true)?;
+				},
+				None => {
+					let slash =
+						Self::slash_non_participant(key.clone(), juror.clone(), stake, false);
+					penalties = penalties.saturating_add(slash);
+					Self::non_participant_getting_incentives2(&key, juror, stake, false)?;
+				},
+			}
+		}
+
+		let winners_len = (winners.len() + draw_jurors.len()) as u64;
+		let (mut paid, mut paid_winners) = (0, 0);
+		for (juror, stake) in winners {
+			let winning_incentives = Self::take_incentive_share(
+				penalties,
+				winners_len,
+				winners_len,
+				1,
+				&mut paid,
+				&mut paid_winners,
+			);
+			Self::winner_getting_incentives2(&key, juror, winning_incentives, stake)?;
+		}
+		for (juror, stake) in draw_jurors {
+			let draw_incentives = Self::take_incentive_share(
+				penalties,
+				winners_len,
+				winners_len,
+				1,
+				&mut paid,
+				&mut paid_winners,
+			);
+			Self::getting_incentives_draw2(&key, juror, draw_incentives, stake)?;
+		}
+		Self::slash_unclaimed_incentives(penalties.saturating_sub(paid));
+		Self::deposit_event(Event::WinningChoiceDecided {
+			key: key.clone(),
+			choice: winning_choice,
+		});
+
+		// Remove the data of the jurors, rest of the storage is removed after the game is archived
+		<DrawnJurors<T>>::remove(&key);
+		let _ = <MultiChoiceVoteCommits<T>>::clear_prefix(
+			&key,
+			T::MaxVoteRemovalsPerBlock::get(),
+			None,
+		);
+
+		Self::archive_game(key, GameOutcome::MultiChoice(winning_choice));
+
+		Ok(())
+	}
+}
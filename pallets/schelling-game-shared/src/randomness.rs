@@ -0,0 +1,107 @@
+use crate::*;
+use frame_support::sp_std::marker::PhantomData;
+
+/// Source of the random numbers that draw the jurors of a game
+pub trait DrawRandomness<SumTreeName> {
+	/// Random number for drawing a juror of the game `key`, `subject` is different for every draw
+	fn random_number(key: &SumTreeName, subject: &[u8]) -> u64;
+}
+
+/// Random numbers from `Config::RandomnessSource` only
+/// The block author can bias them when `RandomnessSource` is `pallet_insecure_randomness_collective_flip`
+pub struct BlockRandomness<T>(PhantomData<T>);
+
+impl<T: Config> DrawRandomness<SumTreeNameType<T>> for BlockRandomness<T> {
+	fn random_number(_key: &SumTreeNameType<T>, subject: &[u8]) -> u64 {
+		let random_seed = T::RandomnessSource::random(subject).encode();
+		u64::decode(&mut random_seed.as_ref())
+			.expect("secure hashes should always be bigger than u64; qed")
+	}
+}
+
+/// Random numbers from `Config::RandomnessSource` mixed with the entropy revealed by the jurors of the game
+/// Jurors commit the hash of their entropy in `Period::Staking` and reveal it in `Period::Drawing` before the first draw
+/// A juror who reveals adds entropy that the block authors and the other jurors did not choose
+/// It is not unbiasable, the last juror to reveal or a block author who leaves out reveals can choose between two draws
+/// Games without revealed entropy use `BlockRandomness`
+pub struct JurorBeacon<T>(PhantomData<T>);
+
+impl<T: Config> DrawRandomness<SumTreeNameType<T>> for JurorBeacon<T> {
+	fn random_number(key: &SumTreeNameType<T>, subject: &[u8]) -> u64 {
+		match <DrawEntropy<T>>::get(key) {
+			Some(entropy) => {
+				let block_random = T::RandomnessSource::random(subject);
+				let random_seed = sp_io::hashing::blake2_256(&(block_random, entropy).encode());
+				u64::decode(&mut random_seed.as_ref())
+					.expect("secure hashes should always be bigger than u64; qed")
+			},
+			None => BlockRandomness::<T>::random_number(key, subject),
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Commit the hash of the entropy for drawing the jurors, `who` must have applied as juror
+	/// Jurors of the games of a court must be staked in the court or in one of its ancestors
+	pub(super) fn commit_draw_entropy_helper(
+		key: SumTreeNameType<T>,
+		who: AccountIdOf<T>,
+		entropy_commit: [u8; 32],
+	) -> DispatchResult {
+		match <PeriodName<T>>::get(&key) {
+			Some(period) => {
+				ensure!(period == Period::Staking, Error::<T>::PeriodDontMatch);
+			},
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+		let staked = match <GameCourt<T>>::get(&key) {
+			Some(court_id) => <JurorCourts<T>>::get(&who).map_or(false, |(juror_court, _)| {
+				Self::get_court_and_ancestors(court_id).contains(&juror_court)
+			}),
+			None => {
+				let stake = T::SortitionSumGameSource::stake_of_link(key.clone(), who.clone())?;
+				stake.unwrap_or(0) > 0
+			},
+		};
+		ensure!(staked, Error::<T>::StakeDoesNotExists);
+		ensure!(
+			!<DrawEntropyCommits<T>>::contains_key(&key, &who),
+			Error::<T>::EntropyAlreadyCommitted
+		);
+		<DrawEntropyCommits<T>>::insert(&key, &who, entropy_commit);
+		Self::deposit_event(Event::DrawEntropyCommitted { key, juror: who });
+		Ok(())
+	}
+
+	/// Reveal the entropy, it is mixed with the entropy revealed by the other jurors
+	/// Entropy can be revealed in `Period::Drawing` till the first juror of the round is drawn
+	pub(super) fn reveal_draw_entropy_helper(
+		key: SumTreeNameType<T>,
+		who: AccountIdOf<T>,
+		entropy: [u8; 32],
+	) -> DispatchResult {
+		match <PeriodName<T>>::get(&key) {
+			Some(period) => {
+				ensure!(period == Period::Drawing, Error::<T>::PeriodDontMatch);
+			},
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+		ensure!(<DrawsInRound<T>>::get(&key) == 0, Error::<T>::EntropyRevealClosed);
+		let entropy_commit =
+			<DrawEntropyCommits<T>>::get(&key, &who).ok_or(Error::<T>::CommitDoesNotExists)?;
+		ensure!(
+			sp_io::hashing::keccak_256(&entropy) == entropy_commit,
+			Error::<T>::CommitDoesNotMatch
+		);
+		<DrawEntropyCommits<T>>::remove(&key, &who);
+		<DrawEntropy<T>>::mutate(&key, |draw_entropy| {
+			let mixed = match draw_entropy {
+				Some(previous) => sp_io::hashing::blake2_256(&(*previous, entropy).encode()),
+				None => sp_io::hashing::blake2_256(&entropy),
+			};
+			*draw_entropy = Some(mixed);
+		});
+		Self::deposit_event(Event::DrawEntropyRevealed { key, juror: who });
+		Ok(())
+	}
+}
@@ -0,0 +1,111 @@
+use crate::*;
+use frame_support::sp_std::marker::PhantomData;
+
+/// Weight of a juror in the draw, from the record of the juror
+pub trait ReputationMultiplier {
+	/// Part of the stake added to the weight of the juror in the draw
+	/// `None` excludes the juror from the games
+	fn bonus(record: &JurorRecord) -> Option<Perbill>;
+}
+
+/// Jurors are drawn by their stake only
+impl ReputationMultiplier for () {
+	fn bonus(_record: &JurorRecord) -> Option<Perbill> {
+		Some(Perbill::zero())
+	}
+}
+
+/// `BonusPerCoherentVote` of the stake is added to the weight for every coherent vote, at most `MaxBonus`
+/// Jurors with at least `MinDecidedVotes` decided votes and a coherence rate below `MinCoherenceRate` are excluded
+pub struct CoherenceMultiplier<MinDecidedVotes, MinCoherenceRate, BonusPerCoherentVote, MaxBonus>(
+	PhantomData<(MinDecidedVotes, MinCoherenceRate, BonusPerCoherentVote, MaxBonus)>,
+);
+
+impl<MinDecidedVotes, MinCoherenceRate, BonusPerCoherentVote, MaxBonus> ReputationMultiplier
+	for CoherenceMultiplier<MinDecidedVotes, MinCoherenceRate, BonusPerCoherentVote, MaxBonus>
+where
+	MinDecidedVotes: Get<u64>,
+	MinCoherenceRate: Get<Perbill>,
+	BonusPerCoherentVote: Get<Perbill>,
+	MaxBonus: Get<Perbill>,
+{
+	fn bonus(record: &JurorRecord) -> Option<Perbill> {
+		if record.decided_votes() >= MinDecidedVotes::get() &&
+			record.coherence_rate() < MinCoherenceRate::get()
+		{
+			return None
+		}
+		let bonus = (BonusPerCoherentVote::get().deconstruct() as u64)
+			.saturating_mul(record.coherent)
+			.min(MaxBonus::get().deconstruct() as u64);
+		Some(Perbill::from_parts(bonus as u32))
+	}
+}
+
+impl JurorRecord {
+	/// Games the juror did not vote in or voted in with a winning decision, games ending in a draw are not counted
+	pub fn decided_votes(&self) -> u64 {
+		let draws = self.revealed.saturating_sub(self.coherent).saturating_sub(self.incoherent);
+		self.games_drawn.saturating_sub(draws)
+	}
+
+	/// Part of the decided votes that were coherent, one when there is no decided vote
+	pub fn coherence_rate(&self) -> Perbill {
+		match self.decided_votes() {
+			0 => Perbill::one(),
+			decided_votes => Perbill::from_rational(self.coherent, decided_votes),
+		}
+	}
+}
+
+/// Vote of a drawn juror in a settled game
+pub(super) enum JurorVote {
+	NotCommitted,
+	NotRevealed,
+	Draw,
+	Coherent,
+	Incoherent,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Add the vote of the juror in a settled game to the record of the juror
+	pub(super) fn record_juror_vote(
+		who: &AccountIdOf<T>,
+		vote: JurorVote,
+		rewarded: u64,
+		slashed: u64,
+	) {
+		<JurorRecords<T>>::mutate(who, |record| {
+			record.games_drawn = record.games_drawn.saturating_add(1);
+			let (committed, revealed, coherent, incoherent) = match vote {
+				JurorVote::NotCommitted => (0, 0, 0, 0),
+				JurorVote::NotRevealed => (1, 0, 0, 0),
+				JurorVote::Draw => (1, 1, 0, 0),
+				JurorVote::Coherent => (1, 1, 1, 0),
+				JurorVote::Incoherent => (1, 1, 0, 1),
+			};
+			record.committed = record.committed.saturating_add(committed);
+			record.revealed = record.revealed.saturating_add(revealed);
+			record.coherent = record.coherent.saturating_add(coherent);
+			record.incoherent = record.incoherent.saturating_add(incoherent);
+			record.rewarded = record.rewarded.saturating_add(rewarded);
+			record.slashed = record.slashed.saturating_add(slashed);
+		});
+	}
+
+	/// Weight of the juror in the draw, the stake with the bonus of `ReputationMultiplier`
+	pub(super) fn get_draw_weight(who: &AccountIdOf<T>, stake: u64) -> Result<u64, DispatchError> {
+		let bonus = T::ReputationMultiplier::bonus(&<JurorRecords<T>>::get(who))
+			.ok_or(Error::<T>::JurorReputationTooLow)?;
+		Ok(stake.saturating_add(bonus * stake))
+	}
+
+	/// Stake reserved by the juror, the weight of the juror in the tree includes the bonus
+	pub(super) fn get_applied_stake(
+		key: SumTreeNameType<T>,
+		who: AccountIdOf<T>,
+	) -> Result<Option<u64>, DispatchError> {
+		let weight = T::SortitionSumGameSource::stake_of_link(key.clone(), who.clone())?;
+		Ok(weight.map(|weight| <JurorStakes<T>>::get(&key, &who).unwrap_or(weight)))
+	}
+}
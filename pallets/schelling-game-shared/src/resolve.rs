@@ -0,0 +1,35 @@
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+	/// Outcome of the game from the revealed votes
+	/// `None` for score games without a revealed score
+	pub(super) fn get_game_outcome(key: SumTreeNameType<T>) -> Option<GameOutcome> {
+		match Self::get_game_kind(&key) {
+			GameKind::MultiChoice => Some(GameOutcome::MultiChoice(Self::get_winning_choice(key))),
+			GameKind::Score => {
+				let reveal_values = <RevealScoreValues<T>>::get(&key);
+				let aggregation = <GameScoreAggregation<T>>::get(&key);
+				Self::get_score(&reveal_values, aggregation).map(GameOutcome::Score)
+			},
+			GameKind::TwoChoice =>
+				Some(GameOutcome::TwoChoice(Self::get_winning_decision_value(key))),
+		}
+	}
+
+	/// Game reached `Period::Execution`, the outcome is given to `OnGameResolved`
+	/// Games created before the game type was kept and score games without a revealed score are not resolved
+	pub(super) fn resolve_game(key: SumTreeNameType<T>) {
+		let game_type = match <GameType<T>>::get(&key) {
+			Some(game_type) => game_type,
+			None => return,
+		};
+		if let Some(outcome) = Self::get_game_outcome(key.clone()) {
+			Self::deposit_event(Event::GameResolved {
+				key: key.clone(),
+				game_type: game_type.clone(),
+				outcome: outcome.clone(),
+			});
+			T::OnGameResolved::on_game_resolved(key, game_type, outcome);
+		}
+	}
+}
@@ -0,0 +1,55 @@
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+	/// Set the `Period` of the game and emit `PeriodChanged`
+	pub(super) fn set_period(key: SumTreeNameType<T>, period: Period) {
+		<PeriodName<T>>::insert(&key, period.clone());
+		Self::deposit_event(Event::PeriodChanged { key, period });
+	}
+
+	/// Change the period of the game in `on_initialize` of block `at`
+	/// If `at` is not after the current block, it is changed in the next block
+	/// If the queue of the block is full, it is changed in the first of the next `MAX_PERIOD_CHANGE_DELAY` blocks with room
+	/// Otherwise the period is not changed automatically, `pass_period` changes it
+	pub(super) fn schedule_period_change(key: SumTreeNameType<T>, at: BlockNumberOf<T>) {
+		let next_block = <frame_system::Pallet<T>>::block_number().saturating_add(One::one());
+		let mut at = at.max(next_block);
+		for _ in 0..=MAX_PERIOD_CHANGE_DELAY {
+			if <PeriodChangeQueue<T>>::try_mutate(at, |keys| keys.try_push(key.clone())).is_ok() {
+				return
+			}
+			at = at.saturating_add(One::one());
+		}
+	}
+
+	/// Change the period of the games scheduled at `now`, at most `MaxPeriodChangesPerBlock` of them
+	pub(super) fn change_scheduled_periods(now: BlockNumberOf<T>) -> Weight {
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+		for key in <PeriodChangeQueue<T>>::take(now) {
+			Self::change_scheduled_period(key, now);
+			weight = weight.saturating_add(T::WeightInfo::change_scheduled_period());
+		}
+		weight
+	}
+
+	/// Period may already be changed with `pass_period`, then the new period is not over and nothing changes
+	/// Evidence and execution period are never changed automatically
+	fn change_scheduled_period(key: SumTreeNameType<T>, now: BlockNumberOf<T>) {
+		let phase_data = match <GamePhaseData<T>>::get(&key) {
+			Some(phase_data) => phase_data,
+			None => return,
+		};
+		match <PeriodName<T>>::get(&key) {
+			Some(Period::Staking)
+			| Some(Period::Drawing)
+			| Some(Period::Commit)
+			| Some(Period::Vote)
+			| Some(Period::Appeal) => {
+				let _ = frame_support::storage::with_storage_layer(|| {
+					Self::change_period(key, phase_data, now)
+				});
+			},
+			_ => {},
+		}
+	}
+}
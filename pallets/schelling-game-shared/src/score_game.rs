@@ -2,6 +2,22 @@ use crate::*;
 use scale_info::prelude::vec;
 
 impl<T: Config> Pallet<T> {
+	/// Set the game as score schelling game, jurors commit score votes
+	/// It is called when the game is created, before the commit period
+	pub(super) fn set_score_game_helper(key: SumTreeNameType<T>) -> DispatchResult {
+		match <PeriodName<T>>::get(&key) {
+			Some(period) => {
+				ensure!(
+					period == Period::Evidence || period == Period::Staking,
+					Error::<T>::PeriodDontMatch
+				);
+			},
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+		<GameKinds<T>>::insert(&key, GameKind::Score);
+		Ok(())
+	}
+
 	/// Commit your score vote
 	pub(super) fn commit_vote_for_score_helper(
 		key: SumTreeNameType<T>,
@@ -14,6 +30,7 @@ impl<T: Config> Pallet<T> {
 			},
 			None => Err(Error::<T>::PeriodDoesNotExists)?,
 		}
+		Self::ensure_game_kind(&key, GameKind::Score)?;
 		let drawn_jurors = <DrawnJurors<T>>::get(&key);
 		match drawn_jurors.binary_search_by(|(c, _)| c.cmp(&who.clone())) {
 			Ok(_) => {
@@ -23,6 +40,7 @@ impl<T: Config> Pallet<T> {
 					revealed_vote: None,
 				};
 				<ScoreVoteCommits<T>>::insert(&key, &who, vote_commit_struct);
+				Self::deposit_event(Event::VoteCommitted { key: key.clone(), juror: who });
 			},
 			Err(_) => Err(Error::<T>::JurorDoesNotExists)?,
 		}
@@ -49,20 +67,19 @@ impl<T: Config> Pallet<T> {
 					commit_struct.votestatus == VoteStatus::Commited,
 					Error::<T>::VoteStatusNotCommited
 				);
-				let mut vote = format!("{}", choice).as_bytes().to_vec();
-				// let mut vote = choice.clone();
-				let mut salt_a = salt.clone();
-				vote.append(&mut salt_a);
-				let vote_bytes: &[u8] = &vote;
-				let hash = sp_io::hashing::keccak_256(vote_bytes);
-				let commit: &[u8] = &commit_struct.commit;
-				if hash == commit {
+				let hash = Self::get_vote_commit(&key, &who, choice, &salt);
+				if hash == commit_struct.commit {
 					let mut reveal_score_values = <RevealScoreValues<T>>::get(&key);
 					reveal_score_values.push(choice * 1000);
 					<RevealScoreValues<T>>::insert(&key, reveal_score_values);
 					commit_struct.revealed_vote = Some(choice);
 					commit_struct.votestatus = VoteStatus::Revealed;
 					<ScoreVoteCommits<T>>::insert(&key, &who, commit_struct);
+					Self::deposit_event(Event::ScoreVoteRevealed {
+						key: key.clone(),
+						juror: who,
+						score: choice,
+					});
 				} else {
 					Err(Error::<T>::CommitDoesNotMatch)?
 				}
@@ -76,7 +93,7 @@ impl<T: Config> Pallet<T> {
 	/// Distribute incentives to all jurors in execution period in score schelling game
 	pub(super) fn get_incentives_score_schelling_helper(
 		key: SumTreeNameType<T>,
-		phase_data: PhaseDataOf<T>,
+		_phase_data: PhaseDataOf<T>,
 		range_point: RangePoint,
 	) -> DispatchResult {
 		match <PeriodName<T>>::get(&key) {
@@ -85,66 +102,96 @@ impl<T: Config> Pallet<T> {
 			},
 			None => Err(Error::<T>::PeriodDoesNotExists)?,
 		}
+		Self::ensure_game_not_voided(key.clone())?;
+		Self::ensure_incentives_not_in_pages(key.clone())?;
 
 		let drawn_jurors = <DrawnJurors<T>>::get(&key);
 		let reveal_votes_iterator = <ScoreVoteCommits<T>>::iter_prefix(&key);
 		let reveal_values = <RevealScoreValues<T>>::get(&key);
-		let sd_and_mean = Self::std_deviation_interger(&reveal_values);
-		let new_mean = Self::calculate_new_mean(&reveal_values, sd_and_mean).unwrap();
-		// println!("new mean: {:?}", new_mean);
-		<IncentiveMeanRevealScore<T>>::insert(key.clone(), new_mean);
-		let incentives_range = Self::get_incentives_range(range_point);
+		let aggregation = <GameScoreAggregation<T>>::get(&key);
+		let (score, window) = Self::get_score_and_window(&reveal_values, aggregation, range_point)
+			.ok_or(Error::<T>::NoScoreRevealed)?;
+		let curve = <GameIncentiveCurve<T>>::get(&key);
+		<IncentiveMeanRevealScore<T>>::insert(key.clone(), score);
+		Self::deposit_event(Event::ScoreMeanDecided { key: key.clone(), mean: score });
 		let mut reveal_votes = reveal_votes_iterator
 			.map(|(account_id, score_commit_vote)| (account_id, score_commit_vote.revealed_vote))
 			.collect::<Vec<(_, _)>>();
 		reveal_votes.sort_by(|a, b| a.0.cmp(&b.0));
 
-		
-		// println!("reveal votes, {:?}",reveal_votes);
 		let mut winners = vec![];
+		// Penalties of the losing jurors, they are given to the winning jurors
+		let mut penalties = 0u64;
 		for juror in drawn_jurors {
 			match reveal_votes.binary_search_by(|(c, _)| c.cmp(&juror.0)) {
 				Ok(index) => {
-					// println!("Ok index {:?}", index);
 					let account_n_vote = &reveal_votes[index];
 					if let Some(i) = account_n_vote.1 {
-						// println!("vote {:?}", i);
-						if i*1000 >= new_mean.checked_sub(incentives_range).unwrap()
-							&&  i*1000  <= new_mean.checked_add(incentives_range).unwrap()
-						{
+						let distance = (i * 1000 - score).abs();
+						if distance <= window {
 							// get incentives
-							winners.push((juror.0.clone(), juror.1.clone()));
+							let weight = Self::get_score_winner_weight(distance, window, &curve);
+							winners.push((juror.0.clone(), juror.1.clone(), weight));
 						} else {
 							// deduct incentives
 							let stake = juror.1;
-							let balance = Self::u64_to_balance_saturated(stake * 3 / 4);
-							let r =
-								T::Currency::deposit_into_existing(&juror.0, balance).ok().unwrap();
-							T::Reward::on_unbalanced(r);
+							let penalty =
+								Self::get_score_looser_penalty(stake, distance, window, &curve);
+							Self::slash_reserved_stake(&juror.0, penalty);
+							penalties = penalties.saturating_add(penalty);
+							Self::unreserve_stake_after_penalty(
+								&key,
+								juror.0.clone(),
+								stake,
+								penalty,
+							)?;
 						}
+					} else {
+						let slash =
+							Self::slash_non_participant(key.clone(), juror.0.clone(), juror.1, true);
+						penalties = penalties.saturating_add(slash);
+						Self::non_participant_getting_incentives2(
+							&key,
+							juror.0.clone(),
+							juror.1,
+							true,
+						)?;
 					}
 				},
 				Err(_) => {
-					// println!("Err index {:?}", index);
+					let slash =
+						Self::slash_non_participant(key.clone(), juror.0.clone(), juror.1, false);
+					penalties = penalties.saturating_add(slash);
+					Self::non_participant_getting_incentives2(
+						&key,
+						juror.0.clone(),
+						juror.1,
+						false,
+					)?;
 				},
 			}
 		}
 
 		let winners_len = winners.len() as u64;
-		// println!("winners_len {}", winners_len);
-		let incentives_tuple = phase_data.juror_incentives;
-		let winning_incentives = incentives_tuple.1.checked_div(winners_len).expect("oveflow");
+		let total_weight = winners.iter().map(|winner| winner.2).sum::<u64>();
+		let (mut paid, mut paid_winners) = (0, 0);
 		for winner in winners {
-			let total_incentives = winner.1.checked_add(winning_incentives).expect("overflow");
-			let incentives = Self::u64_to_balance_saturated(total_incentives);
-			let r = T::Currency::deposit_into_existing(&winner.0, incentives).ok().unwrap();
-			T::Reward::on_unbalanced(r);
+			// Winners share the penalties by their weight, equally when all the weights are zero
+			let winning_incentives = Self::take_incentive_share(
+				penalties,
+				winners_len,
+				total_weight,
+				winner.2,
+				&mut paid,
+				&mut paid_winners,
+			);
+			Self::winner_getting_incentives2(&key, winner.0, winning_incentives, winner.1)?;
 		}
+		Self::slash_unclaimed_incentives(penalties.saturating_sub(paid));
 
 		// Remove all data
 
-		// Remove SorititionSumTrees in `sortition-sum-game` pallet
-		let _result = T::SortitionSumGameSource::remove_tree_link(key.clone());
+		// Jurors left in the sortition tree get their stake back in `on_idle` once the game is archived
 
 		// Remove DrawnJurors
 		<DrawnJurors<T>>::remove(&key);
@@ -153,27 +200,31 @@ impl<T: Config> Pallet<T> {
 	
 
 		// Remove ScoreVoteCommits
-		<ScoreVoteCommits<T>>::remove_prefix(key.clone(), None); // Deprecated: Use clear_prefix instead
-		// let reveal_votes_iterator2 = <ScoreVoteCommits<T>>::iter_prefix(&key);
-		// reveal_votes_iterator2.for_each(|(account_id, _)|{
-		// 	<ScoreVoteCommits<T>>::remove(key.clone(), account_id);
-		// });
+		// The rest is removed in `on_idle` once the game is archived
+		let _ = <ScoreVoteCommits<T>>::clear_prefix(&key, T::MaxVoteRemovalsPerBlock::get(), None);
 
 		// Remove RevealScoreValues
 		<RevealScoreValues<T>>::remove(&key);
 
+		Self::archive_game(key, GameOutcome::Score(score));
+
 		Ok(())
 	}
 
    
-   pub(super) fn get_mean_value(key: SumTreeNameType<T>) -> i64 {
-	  let value = 	<IncentiveMeanRevealScore<T>>::get(key.clone());
-	  value
-   }
+	/// Score of archived games is kept in `GameResults` after their storage is removed
+	pub(super) fn get_mean_value(key: SumTreeNameType<T>) -> i64 {
+		if let Some(GameResult { outcome: GameOutcome::Score(score), .. }) =
+			<GameResults<T>>::get(&key)
+		{
+			return score
+		}
+		<IncentiveMeanRevealScore<T>>::get(key)
+	}
 
 
 	/// Calculate the mean of integer
-	pub(super) fn mean_integer(data: &Vec<i64>) -> Option<i64> {
+	pub(super) fn mean_integer(data: &[i64]) -> Option<i64> {
 		let data_mul_sum = data.iter().sum::<i64>();
 		let count = data.len();
 
@@ -183,7 +234,7 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	pub(super) fn std_deviation_interger(data: &Vec<i64>) -> Option<(i64, i64)> {
+	pub(super) fn std_deviation_interger(data: &[i64]) -> Option<(i64, i64)> {
 		let mean = Self::mean_integer(data);
 		match (mean, data.len()) {
 			(Some(data_mean), count) if count > 0 => {
@@ -202,7 +253,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub(super) fn calculate_new_mean(
-		data: &Vec<i64>,
+		data: &[i64],
 		sd_and_mean: Option<(i64, i64)>,
 	) -> Option<i64> {
 		let mut new_items = vec![];
@@ -217,6 +268,106 @@ impl<T: Config> Pallet<T> {
 		new_mean
 	}
 
+	/// Median of integer, mean of the two middle values when the count is even
+	pub(super) fn median_integer(data: &[i64]) -> Option<i64> {
+		let mut sorted = data.to_vec();
+		sorted.sort();
+		let middle = sorted.len() / 2;
+		match sorted.len() {
+			0 => None,
+			count if count % 2 == 0 => Some((sorted[middle - 1] + sorted[middle]) / 2),
+			_ => Some(sorted[middle]),
+		}
+	}
+
+	/// Median of the absolute deviations of the data from its median
+	pub(super) fn median_absolute_deviation(data: &[i64]) -> Option<i64> {
+		let median = Self::median_integer(data)?;
+		let deviations = data.iter().map(|value| (value - median).abs()).collect::<Vec<i64>>();
+		Self::median_integer(&deviations)
+	}
+
+	/// Mean of integer after removing `trim_percent` of the lowest and of the highest values
+	/// At least one value is kept
+	pub(super) fn trimmed_mean_integer(data: &[i64], trim_percent: u8) -> Option<i64> {
+		let mut sorted = data.to_vec();
+		sorted.sort();
+		let count = sorted.len();
+		let trim = (count * trim_percent as usize / 100).min(count.saturating_sub(1) / 2);
+		Self::mean_integer(&sorted[trim..count - trim])
+	}
+
+	/// Score of the game from the revealed scores, it does not depend on the `RangePoint`
+	/// `None` when no score is revealed
+	pub(super) fn get_score(data: &[i64], aggregation: ScoreAggregation) -> Option<i64> {
+		if data.is_empty() {
+			return None
+		}
+		match aggregation {
+			ScoreAggregation::MeanWithinStdDeviation => {
+				let sd_and_mean = Self::std_deviation_interger(data);
+				Self::calculate_new_mean(data, sd_and_mean)
+			},
+			ScoreAggregation::MedianAbsoluteDeviation => Self::median_integer(data),
+			ScoreAggregation::TrimmedMean(trim_percent) => {
+				Self::trimmed_mean_integer(data, trim_percent)
+			},
+		}
+	}
+
+	/// Score of the game and the distance from the score within which the jurors win
+	/// `None` when no score is revealed
+	pub(super) fn get_score_and_window(
+		data: &[i64],
+		aggregation: ScoreAggregation,
+		range_point: RangePoint,
+	) -> Option<(i64, i64)> {
+		let score = Self::get_score(data, aggregation.clone())?;
+		let incentives_range = Self::get_incentives_range(range_point);
+		let window = match aggregation {
+			ScoreAggregation::MedianAbsoluteDeviation => {
+				Self::median_absolute_deviation(data)?.max(incentives_range)
+			},
+			_ => incentives_range,
+		};
+		Some((score, window))
+	}
+
+	/// Share of the penalties of a juror whose score is `distance` from the score of the game, `distance <= window`
+	pub(super) fn get_score_winner_weight(distance: i64, window: i64, curve: &IncentiveCurve) -> u64 {
+		let closeness = window.saturating_sub(distance).max(0) as u64;
+		match curve {
+			IncentiveCurve::Flat => 1,
+			IncentiveCurve::Linear { .. } => closeness,
+			IncentiveCurve::Quadratic { .. } => {
+				closeness.saturating_mul(closeness).checked_div(window as u64).unwrap_or(0)
+			},
+		}
+	}
+
+	/// Penalty of a juror whose score is `distance` from the score of the game, `distance > window`
+	/// Penalty increases with the distance outside the window till it is `window` outside
+	pub(super) fn get_score_looser_penalty(
+		stake: u64,
+		distance: i64,
+		window: i64,
+		curve: &IncentiveCurve,
+	) -> u64 {
+		let window = window.max(1) as u128;
+		let outside = (distance.saturating_sub(window as i64).max(0) as u128).min(window);
+		match curve {
+			IncentiveCurve::Flat => Self::get_incoherent_penalty(stake),
+			IncentiveCurve::Linear { cap } => {
+				let penalty = stake as u128 * outside / window;
+				cap.mul_floor(penalty as u64)
+			},
+			IncentiveCurve::Quadratic { cap } => {
+				let penalty = stake as u128 * outside * outside / (window * window);
+				cap.mul_floor(penalty as u64)
+			},
+		}
+	}
+
 	pub(super) fn get_incentives_range(range_point: RangePoint) -> i64 {
 		match range_point {
 			RangePoint::ZeroToTen => 1500, //3 points,  1.5 ± mean, multiply by 1000 to make it integer
@@ -12,6 +12,7 @@ impl<T: Config> SchellingGameSharedLink for Pallet<T> {
 	type Period = Period;
 	type PhaseData = PhaseDataOf<T>;
 	type WinningDecision = WinningDecision;
+	type Content = Content;
 
 	fn create_phase_data(
 		block_length: u64,
@@ -59,7 +60,7 @@ impl<T: Config> SchellingGameSharedLink for Pallet<T> {
 
 	/// Get the Period
 	fn get_period_link(key: Self::SumTreeName) -> Option<Period> {
-		Self::get_period(key)
+		Self::get_period_of_game(key)
 	}
 
 	/// Set `PeriodName` to `Period::Evidence`
@@ -67,9 +68,15 @@ impl<T: Config> SchellingGameSharedLink for Pallet<T> {
 	/// Also set `EvidenceStartTime`    
 	fn set_to_evidence_period_link(
 		key: Self::SumTreeName,
+		game_type: Self::SchellingGameType,
 		now: Self::BlockNumber,
 	) -> DispatchResult {
-		Self::set_to_evidence_period(key, now)
+		Self::set_to_evidence_period(key, game_type, now)
+	}
+
+	/// Phase data of the game, copied from its game type when the game was created
+	fn get_phase_data_of_game_link(key: Self::SumTreeName) -> Self::PhaseData {
+		Self::get_phase_data_of_game(key)
 	}
 
 	/// Create a sortition sum tree   
@@ -104,9 +111,10 @@ impl<T: Config> SchellingGameSharedLink for Pallet<T> {
 
 	fn set_to_staking_period_pe_link(
 		key: Self::SumTreeName,
+		game_type: Self::SchellingGameType,
 		now: Self::BlockNumber,
 	) -> DispatchResult {
-		Self::set_to_staking_period_pe(key, now)
+		Self::set_to_staking_period_pe(key, game_type, now)
 	}
 
 	/// Change the `Period`
@@ -129,12 +137,14 @@ impl<T: Config> SchellingGameSharedLink for Pallet<T> {
 	/// }
 	/// ```
 	///
-	/// `Period::Vote` to `Period::Execution`   
+	/// `Period::Vote` to `Period::Appeal`   
 	/// ```ignore
 	/// if now >= min_long_block_length + vote_start_time {
-	///   // Change `Period::Vote` to `Period::Execution`   
+	///   // Change `Period::Vote` to `Period::Appeal`   
 	/// }
-	/// ```   
+	/// ```
+	///
+	/// `Period::Appeal` to `Period::Execution`, when no appeal is made within `appeal_length`   
 	fn change_period_link(
 		key: Self::SumTreeName,
 		phase_data: Self::PhaseData,
@@ -285,6 +295,51 @@ impl<T: Config> SchellingGameSharedLink for Pallet<T> {
 		Self::get_incentives_score_schelling_helper(key, phase_data, range_point)
 	}
 
+	/// Set the game as score schelling game
+	fn set_score_game_link(key: Self::SumTreeName) -> DispatchResult {
+		Self::set_score_game_helper(key)
+	}
+
+	/// Set the number of choices of multi choice schelling game
+	fn set_number_of_choices_link(
+		key: Self::SumTreeName,
+		number_of_choices: u32,
+	) -> DispatchResult {
+		Self::set_number_of_choices_helper(key, number_of_choices)
+	}
+
+	/// Commit vote for multi choice schelling game
+	fn commit_vote_for_multi_choice_link(
+		key: Self::SumTreeName,
+		who: Self::AccountId,
+		vote_commit: [u8; 32],
+	) -> DispatchResult {
+		Self::commit_vote_for_multi_choice_helper(key, who, vote_commit)
+	}
+
+	/// Reveal vote for multi choice schelling game
+	fn reveal_vote_multi_choice_link(
+		key: Self::SumTreeName,
+		who: Self::AccountId,
+		choice: u32,
+		salt: Vec<u8>,
+	) -> DispatchResult {
+		Self::reveal_vote_multi_choice_helper(key, who, choice, salt)
+	}
+
+	/// Distribute incentives to all multi choice schelling game jurors
+	fn get_incentives_multi_choice_link(
+		key: Self::SumTreeName,
+		phase_data: Self::PhaseData,
+	) -> DispatchResult {
+		Self::get_incentives_multi_choice_helper(key, phase_data)
+	}
+
+	/// Winning choice of multi choice schelling game, `None` on a tie
+	fn get_winning_choice_link(key: Self::SumTreeName) -> Option<u32> {
+		Self::get_winning_choice(key)
+	}
+
 	/// Get new mean in score schelling game
 	fn get_mean_value_link(key: Self::SumTreeName) -> i64 {
 		Self::get_mean_value(key)
@@ -305,4 +360,58 @@ impl<T: Config> SchellingGameSharedLink for Pallet<T> {
 	fn get_winning_decision_value_link(key: Self::SumTreeName) -> WinningDecision {
 		Self::get_winning_decision_value(key)
 	}
+
+	/// Appeal the decision in `Period::Appeal`   
+	/// `who` pays the appeal fee, and a new round starts with a larger jury on the same `SumTreeName`   
+	/// Jurors of the appealed rounds get their incentives when the final round ends   
+	fn appeal_helper_link(
+		key: Self::SumTreeName,
+		phase_data: Self::PhaseData,
+		who: Self::AccountId,
+		now: Self::BlockNumber,
+	) -> DispatchResult {
+		Self::appeal_helper(key, phase_data, who, now)
+	}
+
+	/// Blocks left for ending appeal period
+	fn get_appeal_period_end_block_helper_link(
+		key: Self::SumTreeName,
+		phase_data: Self::PhaseData,
+		now: Self::BlockNumber,
+	) -> Option<u32> {
+		Self::get_appeal_period_end_block_helper(key, phase_data, now)
+	}
+
+	/// Fee required to appeal the current round
+	fn get_appeal_fee_link(key: Self::SumTreeName, phase_data: Self::PhaseData) -> Self::Balance {
+		Self::get_appeal_fee(key, phase_data)
+	}
+
+	/// Jurors of the game are drawn from the court and its ancestors, the game uses the phase data of the court
+	fn set_court_of_game_link(key: Self::SumTreeName, court_id: CourtId) -> DispatchResult {
+		Self::set_court_of_game_helper(key, court_id)
+	}
+
+	/// Submit evidence for the game, or a reply to an evidence of the game with `reply_to`
+	fn submit_evidence_link(
+		key: Self::SumTreeName,
+		who: Self::AccountId,
+		content: Self::Content,
+		reply_to: Option<EvidenceId>,
+	) -> DispatchResult {
+		Self::submit_evidence_helper(key, who, content, reply_to)
+	}
+
+	/// Ids of the evidence of the game from `offset`, replies are not included
+	fn get_game_evidence_ids_link(
+		key: Self::SumTreeName,
+		offset: u64,
+		limit: u16,
+	) -> Vec<EvidenceId> {
+		Self::game_evidence_ids(key)
+			.into_iter()
+			.skip(offset as usize)
+			.take(limit as usize)
+			.collect()
+	}
 }
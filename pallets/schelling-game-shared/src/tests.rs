@@ -1,9 +1,23 @@
 use crate::{
 	mock::*,
-	types::{Period, PhaseData, RangePoint, SchellingGameType},
-	Error, Event,
+	types::{
+		CommitVersion, DelegationTarget, GameKind, GameOutcome, GameResult, IncentiveCurve,
+		IncentivePool, IncentiveProgress, IncentiveStage, JurorRecord, Period, PhaseData,
+		RangePoint, RevealedVote, SchellingGameType, ScoreAggregation, WinningDecision,
+		MAX_EVIDENCE_REPLY_DEPTH,
+	},
+	vote_commit, BlockRandomness, CoherenceMultiplier, DrawRandomness, Error, Event, JurorBeacon,
+	ReputationMultiplier,
 };
-use frame_support::{assert_noop, assert_ok};
+use codec::{Decode, Encode};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Hooks, Randomness},
+	weights::Weight,
+};
+use frame_support_test::TestRandomness;
+use pallet_support::{Content, ContentError};
+use sp_runtime::Perbill;
 
 use sortition_sum_game::types::SumTreeName;
 
@@ -23,6 +37,14 @@ fn get_the_phase_data() -> PhaseData<Test> {
 	data
 }
 
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let block_number = System::block_number() + 1;
+		System::set_block_number(block_number);
+		TemplateModule::on_initialize(block_number);
+	}
+}
+
 #[test]
 fn it_works_for_default_value() {
 	new_test_ext().execute_with(|| {
@@ -53,7 +75,11 @@ fn evidence_period_not_over_test() {
 	new_test_ext().execute_with(|| {
 		let key = return_key_profile(0);
 		let now = 10;
-		assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			now
+		));
 		assert_eq!(TemplateModule::get_period(&key).unwrap(), Period::Evidence);
 		let phase_data = get_the_phase_data();
 		let now2 = now + phase_data.evidence_length - 1;
@@ -72,7 +98,11 @@ fn evidence_period_test() {
 	new_test_ext().execute_with(|| {
 		let key = return_key_profile(0);
 		let now = 10;
-		assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			now
+		));
 		assert_eq!(TemplateModule::get_period(&key).unwrap(), Period::Evidence);
 		let phase_data = get_the_phase_data();
 		let now2 = now + phase_data.evidence_length;
@@ -89,7 +119,11 @@ fn end_of_time_staking_period() {
 	new_test_ext().execute_with(|| {
 		let key = return_key_profile(0);
 		let now = 10;
-		assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			now
+		));
 		assert_eq!(TemplateModule::get_period(&key).unwrap(), Period::Evidence);
 		let phase_data = get_the_phase_data();
 		let now2 = now + phase_data.evidence_length + phase_data.end_of_staking_time - 1;
@@ -109,7 +143,11 @@ fn check_time_for_staking_not_over_test() {
 	new_test_ext().execute_with(|| {
 		let key = return_key_profile(0);
 		let now = 10;
-		assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			now
+		));
 		assert_eq!(TemplateModule::get_period(&key).unwrap(), Period::Evidence);
 		let phase_data = get_the_phase_data();
 		let now2 = now + phase_data.evidence_length + phase_data.end_of_staking_time - 1;
@@ -128,7 +166,11 @@ fn apply_juror() {
 	new_test_ext().execute_with(|| {
 		let key = return_key_profile(0);
 		let now = 10;
-		assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			now
+		));
 		assert_eq!(TemplateModule::get_period(&key).unwrap(), Period::Evidence);
 		let phase_data = get_the_phase_data();
 		let now2 = now + phase_data.evidence_length;
@@ -148,6 +190,13 @@ fn apply_juror() {
 				j * 100
 			));
 		}
+		// Stake is reserved
+		assert_eq!(400, Balances::reserved_balance(4));
+		assert_eq!(300000 - 400, Balances::free_balance(4));
+		assert_noop!(
+			TemplateModule::apply_jurors_helper(key.clone(), phase_data.clone(), 4, 400),
+			Error::<Test>::AlreadyStaked
+		);
 	});
 }
 
@@ -156,7 +205,11 @@ fn challenger_win_test() {
 	new_test_ext().execute_with(|| {
 		let key = return_key_profile(0);
 		let now = 10;
-		assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			now
+		));
 		assert_eq!(TemplateModule::get_period(&key).unwrap(), Period::Evidence);
 		let phase_data = get_the_phase_data();
 
@@ -247,6 +300,11 @@ fn challenger_win_test() {
 		let new_now = vote_start_time + phase_data.vote_length;
 		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
 		let period = TemplateModule::get_period(key.clone());
+		assert_eq!(Some(Period::Appeal), period);
+		let appeal_start_time = TemplateModule::appeal_start_time(key.clone());
+		let new_now = appeal_start_time + phase_data.appeal_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+		let period = TemplateModule::get_period(key.clone());
 		assert_eq!(Some(Period::Execution), period);
 
 		let balance = Balances::free_balance(4);
@@ -257,7 +315,7 @@ fn challenger_win_test() {
 			4
 		));
 		let balance = Balances::free_balance(4);
-		assert_eq!(300025, balance);
+		assert_eq!(300093, balance);
 		let balance = Balances::free_balance(7);
 		// println!("{:?}", balance);
 		assert_eq!(299300, balance);
@@ -267,7 +325,7 @@ fn challenger_win_test() {
 			7
 		));
 		let balance = Balances::free_balance(7);
-		assert_eq!(300025, balance);
+		assert_eq!(300093, balance);
 		let balance = Balances::free_balance(13);
 		assert_eq!(298700, balance);
 		assert_ok!(TemplateModule::get_incentives_two_choice_helper(
@@ -276,7 +334,7 @@ fn challenger_win_test() {
 			13
 		));
 		let balance = Balances::free_balance(13);
-		assert_eq!(300025, balance);
+		assert_eq!(300093, balance);
 		let balance = Balances::free_balance(14);
 		assert_eq!(298600, balance);
 		assert_ok!(TemplateModule::get_incentives_two_choice_helper(
@@ -284,8 +342,9 @@ fn challenger_win_test() {
 			phase_data.clone(),
 			14
 		));
+		// Last winner gets the remainder of the penalties: 375 - 93 * 3
 		let balance = Balances::free_balance(14);
-		assert_eq!(300025, balance);
+		assert_eq!(300096, balance);
 		let balance = Balances::free_balance(15);
 		assert_eq!(298500, balance);
 		assert_ok!(TemplateModule::get_incentives_two_choice_helper(
@@ -303,7 +362,11 @@ fn challenger_win_test_jurors_incentive_in_one_go() {
 	new_test_ext().execute_with(|| {
 		let key = return_key_profile(0);
 		let now = 10;
-		assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			now
+		));
 		assert_eq!(TemplateModule::get_period(&key).unwrap(), Period::Evidence);
 		let game_type = return_game_type_profile_approval();
 
@@ -399,6 +462,11 @@ fn challenger_win_test_jurors_incentive_in_one_go() {
 		let new_now = vote_start_time + phase_data.vote_length;
 		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
 		let period = TemplateModule::get_period(key.clone());
+		assert_eq!(Some(Period::Appeal), period);
+		let appeal_start_time = TemplateModule::appeal_start_time(key.clone());
+		let new_now = appeal_start_time + phase_data.appeal_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+		let period = TemplateModule::get_period(key.clone());
 		assert_eq!(Some(Period::Execution), period);
 		let balance = Balances::free_balance(4);
 		assert_eq!(299600, balance);
@@ -416,13 +484,14 @@ fn challenger_win_test_jurors_incentive_in_one_go() {
 			phase_data.clone()
 		));
 		let balance = Balances::free_balance(4);
-		assert_eq!(300025, balance);
+		assert_eq!(300093, balance);
 		let balance = Balances::free_balance(7);
-		assert_eq!(300025, balance);
+		assert_eq!(300093, balance);
 		let balance = Balances::free_balance(13);
-		assert_eq!(300025, balance);
+		assert_eq!(300093, balance);
+		// Last winner gets the remainder of the penalties: 375 - 93 * 3
 		let balance = Balances::free_balance(14);
-		assert_eq!(300025, balance);
+		assert_eq!(300096, balance);
 		let balance = Balances::free_balance(15);
 		assert_eq!(299625, balance);
 	});
@@ -433,7 +502,11 @@ fn challenger_lost_test() {
 	new_test_ext().execute_with(|| {
 		let key = return_key_profile(0);
 		let now = 10;
-		assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			now
+		));
 		assert_eq!(TemplateModule::get_period(&key).unwrap(), Period::Evidence);
 		// let game_type = return_game_type_profile_approval();
 		let phase_data = get_the_phase_data();
@@ -527,6 +600,11 @@ fn challenger_lost_test() {
 		let new_now = vote_start_time + phase_data.vote_length;
 		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
 		let period = TemplateModule::get_period(key.clone());
+		assert_eq!(Some(Period::Appeal), period);
+		let appeal_start_time = TemplateModule::appeal_start_time(key.clone());
+		let new_now = appeal_start_time + phase_data.appeal_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+		let period = TemplateModule::get_period(key.clone());
 		assert_eq!(Some(Period::Execution), period);
 
 		let balance = Balances::free_balance(4);
@@ -537,7 +615,7 @@ fn challenger_lost_test() {
 			4
 		));
 		let balance = Balances::free_balance(4);
-		assert_eq!(300025, balance);
+		assert_eq!(300093, balance);
 		let balance = Balances::free_balance(7);
 		// println!("{:?}", balance);
 		assert_eq!(299300, balance);
@@ -547,7 +625,7 @@ fn challenger_lost_test() {
 			7
 		));
 		let balance = Balances::free_balance(7);
-		assert_eq!(300025, balance);
+		assert_eq!(300093, balance);
 		let balance = Balances::free_balance(13);
 		assert_eq!(298700, balance);
 		assert_ok!(TemplateModule::get_incentives_two_choice_helper(
@@ -556,7 +634,7 @@ fn challenger_lost_test() {
 			13
 		));
 		let balance = Balances::free_balance(13);
-		assert_eq!(300025, balance);
+		assert_eq!(300093, balance);
 		let balance = Balances::free_balance(14);
 		assert_eq!(298600, balance);
 		assert_ok!(TemplateModule::get_incentives_two_choice_helper(
@@ -564,8 +642,9 @@ fn challenger_lost_test() {
 			phase_data.clone(),
 			14
 		));
+		// Last winner gets the remainder of the penalties: 375 - 93 * 3
 		let balance = Balances::free_balance(14);
-		assert_eq!(300025, balance);
+		assert_eq!(300096, balance);
 		let balance = Balances::free_balance(15);
 		assert_eq!(298500, balance);
 		assert_ok!(TemplateModule::get_incentives_two_choice_helper(
@@ -581,10 +660,17 @@ fn challenger_lost_test() {
 #[test]
 fn score_schelling_game_test() {
 	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 		let key = return_key_profile(0);
 		let now = 10;
-		assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			now
+		));
 		assert_eq!(TemplateModule::get_period(&key).unwrap(), Period::Evidence);
+		assert_ok!(TemplateModule::set_score_game_helper(key.clone()));
+		assert_eq!(TemplateModule::game_kind(key.clone()), Some(GameKind::Score));
 		let game_type = return_game_type_profile_approval();
 		// let min_short_block_length = return_min_short_block_length();
 		// let min_long_block_length = return_min_long_block_length();
@@ -628,6 +714,11 @@ fn score_schelling_game_test() {
 		assert_eq!(300000, balance);
 		let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
 		assert_ok!(TemplateModule::commit_vote_for_score_helper(key.clone(), 4, hash));
+		// Two choice votes can not be committed in a score game
+		assert_noop!(
+			TemplateModule::commit_vote_helper(key.clone(), 7, hash),
+			Error::<Test>::GameKindDontMatch
+		);
 		let hash = sp_io::hashing::keccak_256("1salt2".as_bytes());
 		assert_ok!(TemplateModule::commit_vote_for_score_helper(key.clone(), 7, hash));
 		let hash = sp_io::hashing::keccak_256("5salt3".as_bytes());
@@ -684,6 +775,11 @@ fn score_schelling_game_test() {
 		let new_now = vote_start_time + phase_data.commit_length;
 		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
 		let period = TemplateModule::get_period(key.clone());
+		assert_eq!(Some(Period::Appeal), period);
+		let appeal_start_time = TemplateModule::appeal_start_time(key.clone());
+		let new_now = appeal_start_time + phase_data.appeal_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+		let period = TemplateModule::get_period(key.clone());
 		assert_eq!(Some(Period::Execution), period);
 		let reveal_score = TemplateModule::reveal_score_values(key.clone());
 		assert_eq!(vec![1000, 1000, 5000, 1000, 7000], reveal_score);
@@ -705,16 +801,2576 @@ fn score_schelling_game_test() {
 		));
 		let mean_values = TemplateModule::new_mean_reveal_score(key.clone());
 		assert_eq!(2000, mean_values);
+		System::assert_has_event(
+			Event::ScoreVoteRevealed { key: key.clone(), juror: 4, score: 1 }.into(),
+		);
+		System::assert_has_event(Event::ScoreMeanDecided { key: key.clone(), mean: 2000 }.into());
 		let balance = Balances::free_balance(4);
 		// println!("{:?}", balance);
-		assert_eq!(300033, balance);
+		assert_eq!(300233, balance);
 		let balance = Balances::free_balance(7);
-		assert_eq!(300033, balance);
+		assert_eq!(300233, balance);
 		let balance = Balances::free_balance(13); // Balance deducted as voted 5
 		assert_eq!(299675, balance);
+		// Last winner gets the remainder of the penalties: 700 - 233 * 2
 		let balance = Balances::free_balance(14);
-		assert_eq!(300033, balance);
+		assert_eq!(300234, balance);
 		let balance = Balances::free_balance(15); // Balance deducted as voted 7
 		assert_eq!(299625, balance);
 	});
 }
+
+#[test]
+fn appeal_test() {
+	new_test_ext().execute_with(|| {
+		let key = return_key_profile(0);
+		let now = 10;
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			now
+		));
+		let phase_data = get_the_phase_data();
+		let staking_start_time = now + phase_data.evidence_length;
+		assert_ok!(TemplateModule::set_to_staking_period(
+			key.clone(),
+			phase_data.clone(),
+			staking_start_time
+		));
+		assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+		for j in 4..30 {
+			assert_ok!(TemplateModule::apply_jurors_helper(
+				key.clone(),
+				phase_data.clone(),
+				j,
+				j * 100
+			));
+		}
+		let new_now = staking_start_time + phase_data.staking_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+		assert_ok!(TemplateModule::draw_jurors_helper(key.clone(), phase_data.clone(), 5));
+		let drawn_jurors = TemplateModule::drawn_jurors(key.clone());
+		assert_eq!(vec![(4, 400), (7, 700), (13, 1300), (14, 1400), (15, 1500)], drawn_jurors);
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+		assert_ok!(TemplateModule::unstaking_helper(key.clone(), 5));
+		let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
+		assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 4, hash));
+		let hash = sp_io::hashing::keccak_256("1salt2".as_bytes());
+		assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 7, hash));
+		let hash = sp_io::hashing::keccak_256("1salt3".as_bytes());
+		assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 13, hash));
+		let hash = sp_io::hashing::keccak_256("1salt4".as_bytes());
+		assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 14, hash));
+		let hash = sp_io::hashing::keccak_256("0salt5".as_bytes());
+		assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 15, hash));
+		let commit_start_time = TemplateModule::commit_start_time(key.clone());
+		let new_now = commit_start_time + phase_data.commit_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+		assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
+			key.clone(),
+			4,
+			1,
+			"salt".as_bytes().to_vec()
+		));
+		assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
+			key.clone(),
+			7,
+			1,
+			"salt2".as_bytes().to_vec()
+		));
+		assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
+			key.clone(),
+			13,
+			1,
+			"salt3".as_bytes().to_vec()
+		));
+		assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
+			key.clone(),
+			14,
+			1,
+			"salt4".as_bytes().to_vec()
+		));
+		assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
+			key.clone(),
+			15,
+			0,
+			"salt5".as_bytes().to_vec()
+		));
+		let vote_start_time = TemplateModule::vote_start_time(key.clone());
+		let new_now = vote_start_time + phase_data.vote_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+		let period = TemplateModule::get_period(key.clone());
+		assert_eq!(Some(Period::Appeal), period);
+		assert_noop!(
+			TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()),
+			Error::<Test>::AppealPeriodNotOver
+		);
+
+		// Appeal fee for a jury of 11 jurors: 100 * 11 / 5
+		assert_eq!(TemplateModule::get_appeal_fee(key.clone(), phase_data.clone()), 220);
+		assert_ok!(TemplateModule::appeal_helper(key.clone(), phase_data.clone(), 2, new_now));
+		assert_eq!(Balances::free_balance(2), 200000 - 220);
+		assert_eq!(Balances::free_balance(TemplateModule::juror_stake_account()), 220);
+		assert_eq!(TemplateModule::appeal_round(key.clone()), 1);
+		assert_eq!(TemplateModule::appellants(key.clone()), vec![(2, 220)]);
+		assert_eq!(TemplateModule::past_round_jurors(key.clone(), 0).len(), 5);
+		assert_eq!(TemplateModule::get_period(key.clone()), Some(Period::Staking));
+		assert_eq!(TemplateModule::decision_count(key.clone()), (0, 0));
+		assert_noop!(
+			TemplateModule::appeal_helper(key.clone(), phase_data.clone(), 2, new_now),
+			Error::<Test>::PeriodDontMatch
+		);
+
+		// Second round with 11 jurors
+		let new_now = new_now + phase_data.staking_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+		assert_ok!(TemplateModule::draw_jurors_helper(key.clone(), phase_data.clone(), 5));
+		assert_noop!(
+			TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()),
+			Error::<Test>::MaxJurorNotDrawn
+		);
+		assert_ok!(TemplateModule::draw_jurors_helper(key.clone(), phase_data.clone(), 6));
+		assert_eq!(TemplateModule::draws_in_round(key.clone()), 11);
+		let drawn_jurors = TemplateModule::drawn_jurors(key.clone());
+		assert_eq!(drawn_jurors.len(), 11);
+		// Jurors who unstaked or were drawn in the first round are not drawn again
+		for (juror, _) in drawn_jurors.iter() {
+			assert!(![4, 5, 7, 13, 14, 15].contains(juror));
+		}
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+		let hash = sp_io::hashing::keccak_256("0salt".as_bytes());
+		for (juror, _) in drawn_jurors.iter() {
+			assert_ok!(TemplateModule::commit_vote_helper(key.clone(), *juror, hash));
+		}
+		let commit_start_time = TemplateModule::commit_start_time(key.clone());
+		let new_now = commit_start_time + phase_data.commit_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+		for (juror, _) in drawn_jurors.iter() {
+			assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
+				key.clone(),
+				*juror,
+				0,
+				"salt".as_bytes().to_vec()
+			));
+		}
+		let vote_start_time = TemplateModule::vote_start_time(key.clone());
+		let new_now = vote_start_time + phase_data.vote_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+		let appeal_start_time = TemplateModule::appeal_start_time(key.clone());
+		let new_now = appeal_start_time + phase_data.appeal_length;
+		assert_noop!(
+			TemplateModule::appeal_helper(key.clone(), phase_data.clone(), 1, new_now),
+			Error::<Test>::TimeForAppealOver
+		);
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+		assert_eq!(TemplateModule::get_period(key.clone()), Some(Period::Execution));
+
+		// First round jurors are settled against the final decision
+		// Juror 15 gets the penalties of the first round and the appeal fee
+		assert_ok!(TemplateModule::get_incentives_two_choice_helper(
+			key.clone(),
+			phase_data.clone(),
+			15
+		));
+		assert_eq!(Balances::free_balance(15), 300950 + 220);
+		assert_noop!(
+			TemplateModule::get_incentives_two_choice_helper(key.clone(), phase_data.clone(), 15),
+			Error::<Test>::CommitDoesNotExists
+		);
+		assert_ok!(TemplateModule::get_incentives_two_choice_helper(
+			key.clone(),
+			phase_data.clone(),
+			4
+		));
+		assert_eq!(Balances::free_balance(4), 299900);
+
+		// No juror lost in the final round
+		for (juror, _) in drawn_jurors.iter() {
+			assert_ok!(TemplateModule::get_incentives_two_choice_helper(
+				key.clone(),
+				phase_data.clone(),
+				*juror
+			));
+			assert_eq!(Balances::free_balance(*juror), 300000);
+		}
+	});
+}
+
+/// Game in execution period where juror 14 did not reveal the vote and juror 15 did not commit
+fn non_participation_game(key: SumTreeName<u64, u64>, phase_data: PhaseData<Test>) {
+	let now = 10;
+	assert_ok!(TemplateModule::set_to_evidence_period(
+		key.clone(),
+		return_game_type_profile_approval(),
+		now
+	));
+	let staking_start_time = now + phase_data.evidence_length;
+	assert_ok!(TemplateModule::set_to_staking_period(
+		key.clone(),
+		phase_data.clone(),
+		staking_start_time
+	));
+	assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+	for j in 4..30 {
+		assert_ok!(TemplateModule::apply_jurors_helper(
+			key.clone(),
+			phase_data.clone(),
+			j,
+			j * 100
+		));
+	}
+	let new_now = staking_start_time + phase_data.staking_length;
+	assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+	assert_ok!(TemplateModule::draw_jurors_helper(key.clone(), phase_data.clone(), 5));
+	let drawn_jurors = TemplateModule::drawn_jurors(key.clone());
+	assert_eq!(vec![(4, 400), (7, 700), (13, 1300), (14, 1400), (15, 1500)], drawn_jurors);
+	assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+	let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
+	assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 4, hash));
+	let hash = sp_io::hashing::keccak_256("1salt2".as_bytes());
+	assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 7, hash));
+	let hash = sp_io::hashing::keccak_256("1salt3".as_bytes());
+	assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 13, hash));
+	let hash = sp_io::hashing::keccak_256("0salt4".as_bytes());
+	assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 14, hash));
+	let commit_start_time = TemplateModule::commit_start_time(key.clone());
+	let new_now = commit_start_time + phase_data.commit_length;
+	assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+	assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
+		key.clone(),
+		4,
+		1,
+		"salt".as_bytes().to_vec()
+	));
+	assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
+		key.clone(),
+		7,
+		1,
+		"salt2".as_bytes().to_vec()
+	));
+	assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
+		key.clone(),
+		13,
+		1,
+		"salt3".as_bytes().to_vec()
+	));
+	assert_eq!((0, 3), TemplateModule::decision_count(key.clone()));
+	let vote_start_time = TemplateModule::vote_start_time(key.clone());
+	let new_now = vote_start_time + phase_data.vote_length;
+	assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+	let appeal_start_time = TemplateModule::appeal_start_time(key.clone());
+	let new_now = appeal_start_time + phase_data.appeal_length;
+	assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+	assert_eq!(Some(Period::Execution), TemplateModule::get_period(key.clone()));
+}
+
+#[test]
+fn non_participating_jurors_slashed_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		non_participation_game(key.clone(), phase_data.clone());
+
+		// Juror 14 did not reveal, 50% of 1400 is slashed
+		assert_eq!(298600, Balances::free_balance(14));
+		assert_ok!(TemplateModule::get_incentives_two_choice_helper(
+			key.clone(),
+			phase_data.clone(),
+			14
+		));
+		assert_eq!(299300, Balances::free_balance(14));
+		System::assert_has_event(
+			Event::JurorNotRevealed { key: key.clone(), juror: 14, slashed: 700 }.into(),
+		);
+		assert_noop!(
+			TemplateModule::get_incentives_two_choice_helper(key.clone(), phase_data.clone(), 14),
+			Error::<Test>::AlreadyGotIncentives
+		);
+
+		// Juror 15 did not commit, 50% of 1500 is slashed
+		assert_eq!(298500, Balances::free_balance(15));
+		assert_ok!(TemplateModule::get_incentives_two_choice_helper(
+			key.clone(),
+			phase_data.clone(),
+			15
+		));
+		assert_eq!(299250, Balances::free_balance(15));
+		System::assert_has_event(
+			Event::JurorNotCommitted { key: key.clone(), juror: 15, slashed: 750 }.into(),
+		);
+
+		// Coherent jurors share the slash (1450 / 3)
+		assert_ok!(TemplateModule::get_incentives_two_choice_helper(
+			key.clone(),
+			phase_data.clone(),
+			4
+		));
+		assert_eq!(300483, Balances::free_balance(4));
+
+		// Not a drawn juror
+		assert_noop!(
+			TemplateModule::get_incentives_two_choice_helper(key.clone(), phase_data.clone(), 5),
+			Error::<Test>::CommitDoesNotExists
+		);
+	});
+}
+
+#[test]
+fn non_participating_jurors_slashed_in_one_go_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		let total_issuance = Balances::total_issuance();
+		non_participation_game(key.clone(), phase_data.clone());
+		assert_eq!(1400, Balances::reserved_balance(14));
+
+		assert_ok!(TemplateModule::get_all_incentives_two_choice_helper(
+			key.clone(),
+			phase_data.clone()
+		));
+		assert_eq!(300483, Balances::free_balance(4));
+		assert_eq!(300483, Balances::free_balance(7));
+		// Last winner gets the remainder of 1450 / 3
+		assert_eq!(300484, Balances::free_balance(13));
+		assert_eq!(299300, Balances::free_balance(14));
+		assert_eq!(299250, Balances::free_balance(15));
+		for j in [4, 7, 13, 14, 15] {
+			assert_eq!(0, Balances::reserved_balance(j));
+		}
+		assert_eq!(0, Balances::free_balance(TemplateModule::juror_stake_account()));
+		// Stakes are not burned and minted
+		assert_eq!(total_issuance, Balances::total_issuance());
+		System::assert_has_event(
+			Event::JurorNotRevealed { key: key.clone(), juror: 14, slashed: 700 }.into(),
+		);
+		System::assert_has_event(
+			Event::JurorNotCommitted { key: key.clone(), juror: 15, slashed: 750 }.into(),
+		);
+	});
+}
+
+#[test]
+fn jurors_paid_one_by_one_are_not_paid_again_in_one_go_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		non_participation_game(key.clone(), phase_data.clone());
+		assert_ok!(TemplateModule::get_incentives_two_choice_helper(
+			key.clone(),
+			phase_data.clone(),
+			4
+		));
+		assert_eq!(300483, Balances::free_balance(4));
+
+		assert_ok!(TemplateModule::get_all_incentives_two_choice_helper(
+			key.clone(),
+			phase_data.clone()
+		));
+		assert_eq!(300483, Balances::free_balance(4));
+		assert_eq!(300483, Balances::free_balance(7));
+		assert_eq!(300484, Balances::free_balance(13));
+		assert_eq!(0, Balances::free_balance(TemplateModule::juror_stake_account()));
+	});
+}
+
+#[test]
+fn periods_change_in_on_initialize_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		assert_ok!(TemplateModule::set_phase_data(
+			RuntimeOrigin::root(),
+			return_game_type_profile_approval(),
+			phase_data.clone()
+		));
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			10
+		));
+		run_to_block(60);
+		assert_ok!(TemplateModule::set_to_staking_period(key.clone(), phase_data.clone(), 60));
+		assert_eq!(vec![key.clone()], TemplateModule::period_change_queue(110).to_vec());
+		assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+		for j in 4..30 {
+			assert_ok!(TemplateModule::apply_jurors_helper(
+				key.clone(),
+				phase_data.clone(),
+				j,
+				j * 100
+			));
+		}
+
+		run_to_block(109);
+		assert_eq!(Some(Period::Staking), TemplateModule::get_period(key.clone()));
+		run_to_block(110);
+		assert_eq!(Some(Period::Drawing), TemplateModule::get_period(key.clone()));
+		System::assert_last_event(
+			Event::PeriodChanged { key: key.clone(), period: Period::Drawing }.into(),
+		);
+
+		// Commit period starts in the block after all jurors are drawn
+		assert_ok!(TemplateModule::draw_jurors_helper(key.clone(), phase_data.clone(), 5));
+		assert_eq!(Some(Period::Drawing), TemplateModule::get_period(key.clone()));
+		run_to_block(111);
+		assert_eq!(Some(Period::Commit), TemplateModule::get_period(key.clone()));
+		assert_eq!(111, TemplateModule::commit_start_time(key.clone()));
+
+		run_to_block(161);
+		assert_eq!(Some(Period::Vote), TemplateModule::get_period(key.clone()));
+		run_to_block(211);
+		assert_eq!(Some(Period::Appeal), TemplateModule::get_period(key.clone()));
+		run_to_block(261);
+		assert_eq!(Some(Period::Execution), TemplateModule::get_period(key.clone()));
+		System::assert_last_event(
+			Event::PeriodChanged { key: key.clone(), period: Period::Execution }.into(),
+		);
+		assert_eq!(Some(phase_data), TemplateModule::game_phase_data(key.clone()));
+
+		// Execution period is not changed automatically
+		run_to_block(400);
+		assert_eq!(Some(Period::Execution), TemplateModule::get_period(key.clone()));
+	});
+}
+
+#[test]
+fn period_changes_over_the_limit_are_scheduled_in_next_block_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let phase_data = get_the_phase_data();
+		assert_ok!(TemplateModule::set_phase_data(
+			RuntimeOrigin::root(),
+			return_game_type_profile_approval(),
+			phase_data.clone()
+		));
+		// `MaxPeriodChangesPerBlock` is 10
+		for citizen_id in 0..12 {
+			let key = return_key_profile(citizen_id);
+			assert_ok!(TemplateModule::set_to_staking_period_pe(
+				key,
+				return_game_type_profile_approval(),
+				1
+			));
+		}
+		assert_eq!(10, TemplateModule::period_change_queue(51).len());
+		assert_eq!(
+			vec![return_key_profile(10), return_key_profile(11)],
+			TemplateModule::period_change_queue(52).to_vec()
+		);
+
+		run_to_block(51);
+		for citizen_id in 0..10 {
+			let key = return_key_profile(citizen_id);
+			assert_eq!(Some(Period::Drawing), TemplateModule::get_period(key));
+		}
+		assert!(TemplateModule::period_change_queue(51).is_empty());
+
+		// Manual period change still works for the games left over
+		let key = return_key_profile(10);
+		assert_eq!(Some(Period::Staking), TemplateModule::get_period(key.clone()));
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), 51));
+		assert_eq!(Some(Period::Drawing), TemplateModule::get_period(key.clone()));
+
+		// Game already in drawing period is left as it is
+		run_to_block(52);
+		assert_eq!(Some(Period::Drawing), TemplateModule::get_period(key));
+		assert_eq!(Some(Period::Drawing), TemplateModule::get_period(return_key_profile(11)));
+		assert!(TemplateModule::period_change_queue(52).is_empty());
+	});
+}
+
+#[test]
+fn period_end_block_overflow_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let mut phase_data = get_the_phase_data();
+		phase_data.staking_length = u64::MAX;
+		assert_ok!(TemplateModule::set_phase_data(
+			RuntimeOrigin::root(),
+			return_game_type_profile_approval(),
+			phase_data
+		));
+		assert_noop!(
+			TemplateModule::set_to_staking_period_pe(
+				return_key_profile(0),
+				return_game_type_profile_approval(),
+				1
+			),
+			Error::<Test>::BlockNumberOverflow
+		);
+	});
+}
+
+#[test]
+fn game_state_change_events_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		non_participation_game(key.clone(), phase_data.clone());
+
+		System::assert_has_event(
+			Event::PeriodChanged { key: key.clone(), period: Period::Staking }.into(),
+		);
+		System::assert_has_event(
+			Event::JurorApplied { key: key.clone(), juror: 5, stake: 500 }.into(),
+		);
+		System::assert_has_event(
+			Event::JurorDrawn { key: key.clone(), juror: 4, stake: 400 }.into(),
+		);
+		System::assert_has_event(Event::VoteCommitted { key: key.clone(), juror: 14 }.into());
+		System::assert_has_event(
+			Event::VoteRevealed { key: key.clone(), juror: 4, vote: RevealedVote::Yes }.into(),
+		);
+		System::assert_has_event(
+			Event::PeriodChanged { key: key.clone(), period: Period::Execution }.into(),
+		);
+
+		assert_ok!(TemplateModule::unstaking_helper(key.clone(), 5));
+		System::assert_last_event(
+			Event::JurorUnstaked { key: key.clone(), juror: 5, stake: 500 }.into(),
+		);
+
+		assert_ok!(TemplateModule::get_all_incentives_two_choice_helper(
+			key.clone(),
+			phase_data.clone()
+		));
+		System::assert_has_event(
+			Event::WinningDecisionDecided {
+				key: key.clone(),
+				decision: WinningDecision::WinnerYes,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn games_keep_phase_data_of_their_creation_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let game_type = return_game_type_profile_approval();
+		let phase_data = get_the_phase_data();
+		assert_noop!(
+			TemplateModule::set_phase_data(
+				RuntimeOrigin::signed(1),
+				game_type.clone(),
+				phase_data.clone()
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		let no_draws = PhaseData::create_with_data(50, 0, 3, 100, (100, 100));
+		assert_noop!(
+			TemplateModule::set_phase_data(RuntimeOrigin::root(), game_type.clone(), no_draws),
+			Error::<Test>::InvalidPhaseData
+		);
+
+		// Default phase data of the game type till it is set
+		let old_key = return_key_profile(0);
+		assert_ok!(TemplateModule::set_to_evidence_period(old_key.clone(), game_type.clone(), 1));
+		assert_eq!(
+			Some(PhaseData::default_of_game_type(&game_type)),
+			TemplateModule::game_phase_data(old_key.clone())
+		);
+
+		assert_ok!(TemplateModule::set_phase_data(
+			RuntimeOrigin::root(),
+			game_type.clone(),
+			phase_data.clone()
+		));
+		System::assert_last_event(Event::PhaseDataSet { game_type: game_type.clone() }.into());
+		assert_eq!(Some(phase_data.clone()), TemplateModule::game_type_phase_data(&game_type));
+
+		let new_key = return_key_profile(1);
+		assert_ok!(TemplateModule::set_to_evidence_period(new_key.clone(), game_type.clone(), 1));
+		assert_eq!(phase_data, TemplateModule::get_phase_data_of_game(new_key));
+		// Game in progress is not changed
+		assert_eq!(
+			PhaseData::default_of_game_type(&game_type),
+			TemplateModule::get_phase_data_of_game(old_key)
+		);
+	});
+}
+
+/// Game in commit period with jurors 4, 7, 13, 14 and 15 drawn
+fn multi_choice_game(key: SumTreeName<u64, u64>, phase_data: PhaseData<Test>) {
+	let now = 10;
+	assert_ok!(TemplateModule::set_to_evidence_period(
+		key.clone(),
+		return_game_type_profile_approval(),
+		now
+	));
+	assert_noop!(
+		TemplateModule::set_number_of_choices_helper(key.clone(), 1),
+		Error::<Test>::InvalidNumberOfChoices
+	);
+	assert_ok!(TemplateModule::set_number_of_choices_helper(key.clone(), 3));
+	let staking_start_time = now + phase_data.evidence_length;
+	assert_ok!(TemplateModule::set_to_staking_period(
+		key.clone(),
+		phase_data.clone(),
+		staking_start_time
+	));
+	assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+	for j in 4..30 {
+		assert_ok!(TemplateModule::apply_jurors_helper(
+			key.clone(),
+			phase_data.clone(),
+			j,
+			j * 100
+		));
+	}
+	let new_now = staking_start_time + phase_data.staking_length;
+	assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+	assert_ok!(TemplateModule::draw_jurors_helper(key.clone(), phase_data.clone(), 5));
+	assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+	assert_eq!(Some(Period::Commit), TemplateModule::get_period(key.clone()));
+}
+
+fn pass_commit_period(key: SumTreeName<u64, u64>, phase_data: PhaseData<Test>) {
+	let commit_start_time = TemplateModule::commit_start_time(key.clone());
+	let new_now = commit_start_time + phase_data.commit_length;
+	assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now));
+}
+
+#[test]
+fn multi_choice_game_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		multi_choice_game(key.clone(), phase_data.clone());
+
+		let votes =
+			[(4, 2, "salt"), (7, 2, "salt2"), (13, 2, "salt3"), (14, 0, "salt4"), (15, 1, "salt5")];
+		for (juror, choice, salt) in votes.iter() {
+			let hash = sp_io::hashing::keccak_256(format!("{}{}", choice, salt).as_bytes());
+			assert_ok!(TemplateModule::commit_vote_for_multi_choice_helper(
+				key.clone(),
+				*juror,
+				hash
+			));
+		}
+		pass_commit_period(key.clone(), phase_data.clone());
+
+		assert_noop!(
+			TemplateModule::reveal_vote_multi_choice_helper(
+				key.clone(),
+				4,
+				3,
+				"salt6".as_bytes().to_vec()
+			),
+			Error::<Test>::NotValidChoice
+		);
+		assert_noop!(
+			TemplateModule::reveal_vote_multi_choice_helper(
+				key.clone(),
+				4,
+				1,
+				"salt".as_bytes().to_vec()
+			),
+			Error::<Test>::CommitDoesNotMatch
+		);
+		for (juror, choice, salt) in votes.iter() {
+			assert_ok!(TemplateModule::reveal_vote_multi_choice_helper(
+				key.clone(),
+				*juror,
+				*choice,
+				salt.as_bytes().to_vec()
+			));
+		}
+		assert_eq!(vec![1, 1, 3], TemplateModule::multi_choice_decision_count(key.clone()));
+		assert_eq!(Some(2), TemplateModule::get_winning_choice(key.clone()));
+
+		let vote_start_time = TemplateModule::vote_start_time(key.clone());
+		let new_now = vote_start_time + phase_data.vote_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now));
+		let appeal_start_time = TemplateModule::appeal_start_time(key.clone());
+		let new_now = appeal_start_time + phase_data.appeal_length;
+		assert_noop!(
+			TemplateModule::appeal_helper(key.clone(), phase_data.clone(), 4, appeal_start_time),
+			Error::<Test>::AppealNotAllowed
+		);
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now));
+
+		assert_ok!(TemplateModule::get_incentives_multi_choice_helper(
+			key.clone(),
+			phase_data.clone()
+		));
+		// Losers lose a quarter of the stake, 350 + 375 is shared by the three winners
+		// Last winner gets the remainder of the division
+		assert_eq!(300241, Balances::free_balance(4));
+		assert_eq!(300241, Balances::free_balance(7));
+		assert_eq!(300243, Balances::free_balance(13));
+		assert_eq!(299650, Balances::free_balance(14));
+		assert_eq!(299625, Balances::free_balance(15));
+		System::assert_has_event(
+			Event::MultiChoiceVoteRevealed { key: key.clone(), juror: 15, choice: 1 }.into(),
+		);
+		System::assert_has_event(
+			Event::WinningChoiceDecided { key: key.clone(), choice: Some(2) }.into(),
+		);
+	});
+}
+
+#[test]
+fn multi_choice_game_tie_test() {
+	new_test_ext().execute_with(|| {
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		multi_choice_game(key.clone(), phase_data.clone());
+
+		// Juror 15 does not commit
+		let votes = [(4, 0, "salt"), (7, 0, "salt2"), (13, 1, "salt3"), (14, 1, "salt4")];
+		for (juror, choice, salt) in votes.iter() {
+			let hash = sp_io::hashing::keccak_256(format!("{}{}", choice, salt).as_bytes());
+			assert_ok!(TemplateModule::commit_vote_for_multi_choice_helper(
+				key.clone(),
+				*juror,
+				hash
+			));
+		}
+		assert_noop!(
+			TemplateModule::commit_vote_for_multi_choice_helper(key.clone(), 5, [0; 32]),
+			Error::<Test>::JurorDoesNotExists
+		);
+		pass_commit_period(key.clone(), phase_data.clone());
+		for (juror, choice, salt) in votes.iter() {
+			assert_ok!(TemplateModule::reveal_vote_multi_choice_helper(
+				key.clone(),
+				*juror,
+				*choice,
+				salt.as_bytes().to_vec()
+			));
+		}
+		assert_eq!(None, TemplateModule::get_winning_choice(key.clone()));
+
+		let vote_start_time = TemplateModule::vote_start_time(key.clone());
+		let new_now = vote_start_time + phase_data.vote_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now));
+		let appeal_start_time = TemplateModule::appeal_start_time(key.clone());
+		let new_now = appeal_start_time + phase_data.appeal_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now));
+		assert_ok!(TemplateModule::get_incentives_multi_choice_helper(
+			key.clone(),
+			phase_data.clone()
+		));
+
+		// No winner, jurors who revealed get back their stake and share the slash of juror 15
+		for juror in [4, 7, 13] {
+			assert_eq!(300187, Balances::free_balance(juror));
+		}
+		assert_eq!(300189, Balances::free_balance(14));
+		assert_eq!(299250, Balances::free_balance(15));
+		assert_eq!(0, Balances::free_balance(TemplateModule::juror_stake_account()));
+	});
+}
+
+#[test]
+fn score_aggregation_doc_examples_test() {
+	new_test_ext().execute_with(|| {
+		// Examples of Shivarthu.md, scores are multiplied by 1000
+		let items: Vec<i64> = vec![-10, 1, 1, 1, 5, 1, 1, 7].iter().map(|x| x * 1000).collect();
+		let items2: Vec<i64> =
+			vec![-10, -10, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0].iter().map(|x| x * 1000).collect();
+		let items3: Vec<i64> =
+			vec![-10, -10, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, -9, -8, -7, -6, -5, -4, -3, -2, -1]
+				.iter()
+				.map(|x| x * 1000)
+				.collect();
+		let range_point = RangePoint::MinusTenToPlusTen;
+
+		assert_eq!(
+			Some((1666, 3000)),
+			TemplateModule::get_score_and_window(
+				&items,
+				ScoreAggregation::MeanWithinStdDeviation,
+				range_point.clone()
+			)
+		);
+		assert_eq!(
+			Some((100, 3000)),
+			TemplateModule::get_score_and_window(
+				&items2,
+				ScoreAggregation::MeanWithinStdDeviation,
+				range_point.clone()
+			)
+		);
+		assert_eq!(
+			Some((-1400, 3000)),
+			TemplateModule::get_score_and_window(
+				&items3,
+				ScoreAggregation::MeanWithinStdDeviation,
+				range_point.clone()
+			)
+		);
+
+		assert_eq!(Some(1000), TemplateModule::median_integer(&items));
+		assert_eq!(Some(0), TemplateModule::median_absolute_deviation(&items));
+		assert_eq!(Some(0), TemplateModule::median_integer(&items2));
+		assert_eq!(Some(0), TemplateModule::median_absolute_deviation(&items2));
+		assert_eq!(Some(-1000), TemplateModule::median_integer(&items3));
+		assert_eq!(Some(1000), TemplateModule::median_absolute_deviation(&items3));
+		assert_eq!(
+			Some((-1000, 3000)),
+			TemplateModule::get_score_and_window(
+				&items3,
+				ScoreAggregation::MedianAbsoluteDeviation,
+				range_point.clone()
+			)
+		);
+		// Window is wider than the incentives range when the scores are spread
+		assert_eq!(
+			Some((-1000, 1000)),
+			TemplateModule::get_score_and_window(
+				&items3,
+				ScoreAggregation::MedianAbsoluteDeviation,
+				RangePoint::ZeroToFive
+			)
+		);
+
+		assert_eq!(Some(1000), TemplateModule::trimmed_mean_integer(&items, 25));
+		assert_eq!(Some(875), TemplateModule::trimmed_mean_integer(&items, 10));
+		assert_eq!(Some(0), TemplateModule::trimmed_mean_integer(&items2, 25));
+		assert_eq!(Some(-1000), TemplateModule::trimmed_mean_integer(&items2, 10));
+		assert_eq!(
+			Some((-1909, 3000)),
+			TemplateModule::get_score_and_window(
+				&items3,
+				ScoreAggregation::TrimmedMean(25),
+				range_point.clone()
+			)
+		);
+
+		assert_eq!(
+			None,
+			TemplateModule::get_score_and_window(
+				&vec![],
+				ScoreAggregation::MedianAbsoluteDeviation,
+				range_point
+			)
+		);
+	});
+}
+
+/// Score game of `SchellingGameType::ProfileScore` in execution period, drawn jurors 4, 7, 13, 14 and 15 reveal `votes`
+fn score_game(key: SumTreeName<u64, u64>, phase_data: PhaseData<Test>, votes: Vec<(u64, i64)>) {
+	let now = 10;
+	assert_ok!(TemplateModule::set_to_evidence_period(
+		key.clone(),
+		SchellingGameType::ProfileScore,
+		now
+	));
+	assert_ok!(TemplateModule::set_score_game_helper(key.clone()));
+	let staking_start_time = now + phase_data.evidence_length;
+	assert_ok!(TemplateModule::set_to_staking_period(
+		key.clone(),
+		phase_data.clone(),
+		staking_start_time
+	));
+	assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+	for j in 4..30 {
+		assert_ok!(TemplateModule::apply_jurors_helper(
+			key.clone(),
+			phase_data.clone(),
+			j,
+			j * 100
+		));
+	}
+	let new_now = staking_start_time + phase_data.staking_length;
+	assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+	assert_ok!(TemplateModule::draw_jurors_helper(key.clone(), phase_data.clone(), 5));
+	assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now.clone()));
+	for (juror, vote) in votes.iter() {
+		let hash = sp_io::hashing::keccak_256(format!("{}salt", vote).as_bytes());
+		assert_ok!(TemplateModule::commit_vote_for_score_helper(key.clone(), *juror, hash));
+	}
+	pass_commit_period(key.clone(), phase_data.clone());
+	for (juror, vote) in votes {
+		assert_ok!(TemplateModule::reveal_vote_score_helper(
+			key.clone(),
+			juror,
+			vote,
+			"salt".as_bytes().to_vec()
+		));
+	}
+	let vote_start_time = TemplateModule::vote_start_time(key.clone());
+	let new_now = vote_start_time + phase_data.vote_length;
+	assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now));
+	let appeal_start_time = TemplateModule::appeal_start_time(key.clone());
+	let new_now = appeal_start_time + phase_data.appeal_length;
+	assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now));
+	assert_eq!(Some(Period::Execution), TemplateModule::get_period(key.clone()));
+}
+
+#[test]
+fn set_score_aggregation_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let game_type = SchellingGameType::ProfileScore;
+		assert_noop!(
+			TemplateModule::set_score_aggregation(
+				RuntimeOrigin::signed(1),
+				game_type.clone(),
+				ScoreAggregation::MedianAbsoluteDeviation
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::set_score_aggregation(
+				RuntimeOrigin::root(),
+				game_type.clone(),
+				ScoreAggregation::TrimmedMean(50)
+			),
+			Error::<Test>::InvalidScoreAggregation
+		);
+
+		let old_key = return_key_profile(0);
+		assert_ok!(TemplateModule::set_to_evidence_period(old_key.clone(), game_type.clone(), 1));
+
+		assert_ok!(TemplateModule::set_score_aggregation(
+			RuntimeOrigin::root(),
+			game_type.clone(),
+			ScoreAggregation::TrimmedMean(25)
+		));
+		System::assert_last_event(
+			Event::ScoreAggregationSet {
+				game_type: game_type.clone(),
+				aggregation: ScoreAggregation::TrimmedMean(25),
+			}
+			.into(),
+		);
+
+		let new_key = return_key_profile(1);
+		assert_ok!(TemplateModule::set_to_evidence_period(new_key.clone(), game_type, 1));
+		assert_eq!(
+			ScoreAggregation::TrimmedMean(25),
+			TemplateModule::game_score_aggregation(new_key)
+		);
+		// Game in progress is not changed
+		assert_eq!(
+			ScoreAggregation::MeanWithinStdDeviation,
+			TemplateModule::game_score_aggregation(old_key)
+		);
+	});
+}
+
+#[test]
+fn median_score_schelling_game_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::set_score_aggregation(
+			RuntimeOrigin::root(),
+			SchellingGameType::ProfileScore,
+			ScoreAggregation::MedianAbsoluteDeviation
+		));
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		score_game(
+			key.clone(),
+			phase_data.clone(),
+			vec![(4, 1), (7, 1), (13, 5), (14, 1), (15, 7)],
+		);
+		assert_ok!(TemplateModule::get_incentives_score_schelling_helper(
+			key.clone(),
+			phase_data,
+			RangePoint::ZeroToTen
+		));
+		// Median is 1, mean within standard deviation would be 2
+		assert_eq!(1000, TemplateModule::new_mean_reveal_score(key.clone()));
+		System::assert_has_event(Event::ScoreMeanDecided { key, mean: 1000 }.into());
+		assert_eq!(300233, Balances::free_balance(4));
+		assert_eq!(300233, Balances::free_balance(7));
+		assert_eq!(299675, Balances::free_balance(13));
+		assert_eq!(300234, Balances::free_balance(14));
+		assert_eq!(299625, Balances::free_balance(15));
+	});
+}
+
+#[test]
+fn incentive_curve_test() {
+	new_test_ext().execute_with(|| {
+		let flat = IncentiveCurve::Flat;
+		let linear = IncentiveCurve::Linear { cap: Perbill::from_percent(50) };
+		let quadratic = IncentiveCurve::Quadratic { cap: Perbill::from_percent(100) };
+		for range_point in
+			[RangePoint::ZeroToTen, RangePoint::MinusTenToPlusTen, RangePoint::ZeroToFive]
+		{
+			let window = TemplateModule::get_incentives_range(range_point);
+			let stake = 1000;
+
+			// Flat curve gives the same incentives at any distance
+			assert_eq!(1, TemplateModule::get_score_winner_weight(0, window, &flat));
+			assert_eq!(1, TemplateModule::get_score_winner_weight(window, window, &flat));
+			assert_eq!(
+				250,
+				TemplateModule::get_score_looser_penalty(stake, window + 1, window, &flat)
+			);
+			assert_eq!(
+				250,
+				TemplateModule::get_score_looser_penalty(stake, window * 3, window, &flat)
+			);
+
+			assert_eq!(window as u64, TemplateModule::get_score_winner_weight(0, window, &linear));
+			assert_eq!(
+				(window / 2) as u64,
+				TemplateModule::get_score_winner_weight(window / 2, window, &linear)
+			);
+			assert_eq!(0, TemplateModule::get_score_winner_weight(window, window, &linear));
+			assert_eq!(
+				250,
+				TemplateModule::get_score_looser_penalty(
+					stake,
+					window + window / 2,
+					window,
+					&linear
+				)
+			);
+			// Penalty is capped
+			assert_eq!(
+				500,
+				TemplateModule::get_score_looser_penalty(stake, window * 2, window, &linear)
+			);
+			assert_eq!(
+				500,
+				TemplateModule::get_score_looser_penalty(stake, window * 3, window, &linear)
+			);
+
+			assert_eq!(
+				window as u64,
+				TemplateModule::get_score_winner_weight(0, window, &quadratic)
+			);
+			assert_eq!(
+				(window / 4) as u64,
+				TemplateModule::get_score_winner_weight(window / 2, window, &quadratic)
+			);
+			assert_eq!(0, TemplateModule::get_score_winner_weight(window, window, &quadratic));
+			assert_eq!(
+				250,
+				TemplateModule::get_score_looser_penalty(
+					stake,
+					window + window / 2,
+					window,
+					&quadratic
+				)
+			);
+			assert_eq!(
+				1000,
+				TemplateModule::get_score_looser_penalty(stake, window * 3, window, &quadratic)
+			);
+		}
+	});
+}
+
+#[test]
+fn linear_incentive_curve_score_schelling_game_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let curve = IncentiveCurve::Linear { cap: Perbill::from_percent(50) };
+		assert_noop!(
+			TemplateModule::set_incentive_curve(
+				RuntimeOrigin::signed(1),
+				SchellingGameType::ProfileScore,
+				curve.clone()
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::set_incentive_curve(
+			RuntimeOrigin::root(),
+			SchellingGameType::ProfileScore,
+			curve.clone()
+		));
+		System::assert_last_event(
+			Event::IncentiveCurveSet {
+				game_type: SchellingGameType::ProfileScore,
+				curve: curve.clone(),
+			}
+			.into(),
+		);
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		score_game(
+			key.clone(),
+			phase_data.clone(),
+			vec![(4, 2), (7, 1), (13, 4), (14, 2), (15, 7)],
+		);
+		assert_eq!(curve, TemplateModule::game_incentive_curve(key.clone()));
+		assert_ok!(TemplateModule::get_incentives_score_schelling_helper(
+			key.clone(),
+			phase_data,
+			RangePoint::ZeroToTen
+		));
+		assert_eq!(2666, TemplateModule::new_mean_reveal_score(key));
+		// 7 is 166 outside the window of 1500, loses 50% of 700 * 166 / 1500
+		assert_eq!(299962, Balances::free_balance(7));
+		// 15 is more than the window outside, loses 50% of the stake
+		assert_eq!(299250, Balances::free_balance(15));
+		// Penalties 788 are shared by the closeness to the score, 834, 166 and 834
+		// Last winner gets the remainder of the division
+		assert_eq!(300358, Balances::free_balance(4));
+		assert_eq!(300071, Balances::free_balance(13));
+		assert_eq!(300359, Balances::free_balance(14));
+	});
+}
+
+#[test]
+fn settled_game_is_archived_and_removed_test() {
+	let mut ext = new_test_ext();
+	let key = return_key_profile(0);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		let phase_data = get_the_phase_data();
+		non_participation_game(key.clone(), phase_data.clone());
+		for j in [4, 7, 13, 14] {
+			assert_ok!(TemplateModule::get_incentives_two_choice_helper(
+				key.clone(),
+				phase_data.clone(),
+				j
+			));
+		}
+		assert_eq!(None, TemplateModule::game_result(key.clone()));
+
+		// Last juror gets the incentives
+		assert_ok!(TemplateModule::get_incentives_two_choice_helper(
+			key.clone(),
+			phase_data.clone(),
+			15
+		));
+		let result = GameResult {
+			outcome: GameOutcome::TwoChoice(WinningDecision::WinnerYes),
+			appeal_rounds: 0,
+			settled_at: 1,
+		};
+		assert_eq!(Some(result.clone()), TemplateModule::game_result(key.clone()));
+		System::assert_last_event(Event::GameArchived { key: key.clone(), result }.into());
+		assert_eq!(vec![key.clone()], TemplateModule::games_to_clean_up());
+
+		// Jurors who were not drawn are still staked after the game is resolved
+		assert_eq!(500, Balances::reserved_balance(5));
+		assert_eq!(Ok(Some(500)), SortitionSumGame::stake_of(key.clone(), 5));
+
+		// No weight left in the block
+		TemplateModule::on_idle(1, Weight::zero());
+		assert_eq!(4, crate::VoteCommits::<Test>::iter_prefix(&key).count());
+	});
+
+	// Storage is removed from the backend over several blocks, like on chain
+	ext.commit_all().unwrap();
+	ext.execute_with(|| {
+		// `MaxVoteRemovalsPerBlock` is 3, 21 jurors who were not drawn get their stake back first
+		TemplateModule::on_idle(1, Weight::MAX);
+		assert_eq!(4, crate::VoteCommits::<Test>::iter_prefix(&key).count());
+		assert_eq!(Some(Period::Execution), TemplateModule::get_period(key.clone()));
+		assert_eq!(vec![key.clone()], TemplateModule::games_to_clean_up());
+	});
+	let mut block = 1;
+	while ext.execute_with(|| !TemplateModule::games_to_clean_up().is_empty()) {
+		ext.commit_all().unwrap();
+		block += 1;
+		assert!(block < 50);
+		ext.execute_with(|| {
+			System::set_block_number(block);
+			TemplateModule::on_idle(block, Weight::MAX);
+		});
+	}
+	ext.execute_with(|| {
+		System::assert_last_event(Event::GameStorageRemoved { key: key.clone() }.into());
+		assert_eq!(0, Balances::reserved_balance(5));
+		assert_eq!(300000, Balances::free_balance(5));
+		assert_noop!(
+			SortitionSumGame::stake_of(key.clone(), 5),
+			sortition_sum_game::Error::<Test>::TreeDoesnotExist
+		);
+		assert_eq!(0, crate::VoteCommits::<Test>::iter_prefix(&key).count());
+		assert_eq!(None, TemplateModule::get_period(key.clone()));
+		assert_eq!((0, 0), TemplateModule::decision_count(key.clone()));
+		assert!(TemplateModule::drawn_jurors(key.clone()).is_empty());
+		assert!(TemplateModule::juror_incentive_distribution(key.clone()).is_empty());
+		assert_eq!(IncentivePool::default(), TemplateModule::round_incentive_pool(key.clone(), 0));
+		assert_eq!(None, TemplateModule::game_phase_data(key.clone()));
+		assert!(TemplateModule::games_to_clean_up().is_empty());
+
+		// Result is read from the archive
+		assert_eq!(
+			WinningDecision::WinnerYes,
+			TemplateModule::get_winning_decision_value(key.clone())
+		);
+		assert_eq!(Some(Period::Execution), TemplateModule::get_period_of_game(key.clone()));
+		assert_noop!(
+			TemplateModule::set_to_evidence_period(
+				key.clone(),
+				return_game_type_profile_approval(),
+				10
+			),
+			Error::<Test>::PeriodExists
+		);
+	});
+}
+
+#[test]
+fn stakes_are_kept_in_snapshot_when_drawing_starts_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		let now = 10;
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			now
+		));
+		let staking_start_time = now + phase_data.evidence_length;
+		assert_ok!(TemplateModule::set_to_staking_period(
+			key.clone(),
+			phase_data.clone(),
+			staking_start_time
+		));
+		assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+		for j in 4..9 {
+			assert_ok!(TemplateModule::apply_jurors_helper(
+				key.clone(),
+				phase_data.clone(),
+				j,
+				j * 100
+			));
+		}
+		assert_eq!(0, SortitionSumGame::get_snapshot_count(key.clone()));
+		let new_now = staking_start_time + phase_data.staking_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now));
+		assert_eq!(1, SortitionSumGame::get_snapshot_count(key.clone()));
+		assert_eq!(Some(3000), SortitionSumGame::get_snapshot_total_stake(key.clone(), 0));
+
+		// Drawn jurors leave the tree, their stake at the draw is in the snapshot
+		assert_ok!(TemplateModule::draw_jurors_helper(key.clone(), phase_data.clone(), 5));
+		assert_eq!(Ok(None), SortitionSumGame::stake_of(key.clone(), 4));
+		assert_eq!(Some(400), SortitionSumGame::get_snapshot_stake_of(key.clone(), 0, 4));
+		assert_eq!(Some(800), SortitionSumGame::get_snapshot_stake_of(key.clone(), 0, 8));
+		assert_eq!(None, SortitionSumGame::get_snapshot_stake_of(key.clone(), 0, 9));
+
+		// Snapshots are removed with the storage of the game, over several blocks
+		TemplateModule::archive_game(key.clone(), GameOutcome::Voided);
+		for block in 1..6 {
+			TemplateModule::on_idle(block, Weight::MAX);
+		}
+		assert_eq!(0, Balances::reserved_balance(4));
+		assert_eq!(0, SortitionSumGame::get_snapshot_count(key.clone()));
+		assert_eq!(None, SortitionSumGame::get_snapshot_hash(key, 0));
+	});
+}
+
+#[test]
+fn settled_score_game_is_archived_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		score_game(
+			key.clone(),
+			phase_data.clone(),
+			vec![(4, 1), (7, 1), (13, 5), (14, 1), (15, 7)],
+		);
+		assert_ok!(TemplateModule::get_incentives_score_schelling_helper(
+			key.clone(),
+			phase_data,
+			RangePoint::ZeroToTen
+		));
+		assert_eq!(
+			Some(GameOutcome::Score(2000)),
+			TemplateModule::game_result(key.clone()).map(|result| result.outcome)
+		);
+
+		// 21 jurors who were not drawn get their stake back over several blocks
+		assert_eq!(500, Balances::reserved_balance(5));
+		for block in 1..10 {
+			TemplateModule::on_idle(block, Weight::MAX);
+		}
+		assert_eq!(0, Balances::reserved_balance(5));
+		assert_eq!(0, TemplateModule::new_mean_reveal_score(key.clone()));
+		assert_eq!(2000, TemplateModule::get_mean_value(key.clone()));
+		assert!(TemplateModule::games_to_clean_up().is_empty());
+	});
+}
+
+#[test]
+fn juror_entropy_beacon_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		let now = 10;
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			now
+		));
+		assert_noop!(
+			TemplateModule::commit_draw_entropy(
+				RuntimeOrigin::signed(4),
+				key.clone(),
+				sp_io::hashing::keccak_256(&[4u8; 32])
+			),
+			Error::<Test>::PeriodDontMatch
+		);
+		let staking_start_time = now + phase_data.evidence_length;
+		assert_ok!(TemplateModule::set_to_staking_period(
+			key.clone(),
+			phase_data.clone(),
+			staking_start_time
+		));
+		assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+		for j in 4..30 {
+			assert_ok!(TemplateModule::apply_jurors_helper(
+				key.clone(),
+				phase_data.clone(),
+				j,
+				j * 100
+			));
+		}
+
+		// Only jurors who applied contribute entropy
+		assert_noop!(
+			TemplateModule::commit_draw_entropy(
+				RuntimeOrigin::signed(3),
+				key.clone(),
+				sp_io::hashing::keccak_256(&[3u8; 32])
+			),
+			Error::<Test>::StakeDoesNotExists
+		);
+		for j in [4u8, 7, 13] {
+			assert_ok!(TemplateModule::commit_draw_entropy(
+				RuntimeOrigin::signed(j as u64),
+				key.clone(),
+				sp_io::hashing::keccak_256(&[j; 32])
+			));
+		}
+		System::assert_last_event(
+			Event::DrawEntropyCommitted { key: key.clone(), juror: 13 }.into(),
+		);
+		assert_noop!(
+			TemplateModule::commit_draw_entropy(
+				RuntimeOrigin::signed(4),
+				key.clone(),
+				sp_io::hashing::keccak_256(&[5u8; 32])
+			),
+			Error::<Test>::EntropyAlreadyCommitted
+		);
+		assert_noop!(
+			TemplateModule::reveal_draw_entropy(RuntimeOrigin::signed(4), key.clone(), [4u8; 32]),
+			Error::<Test>::PeriodDontMatch
+		);
+
+		let new_now = staking_start_time + phase_data.staking_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now));
+		assert_noop!(
+			TemplateModule::reveal_draw_entropy(RuntimeOrigin::signed(4), key.clone(), [5u8; 32]),
+			Error::<Test>::CommitDoesNotMatch
+		);
+		assert_ok!(TemplateModule::reveal_draw_entropy(
+			RuntimeOrigin::signed(4),
+			key.clone(),
+			[4u8; 32]
+		));
+		assert_ok!(TemplateModule::reveal_draw_entropy(
+			RuntimeOrigin::signed(7),
+			key.clone(),
+			[7u8; 32]
+		));
+		System::assert_last_event(Event::DrawEntropyRevealed { key: key.clone(), juror: 7 }.into());
+		let entropy = sp_io::hashing::blake2_256(
+			&(sp_io::hashing::blake2_256(&[4u8; 32]), [7u8; 32]).encode(),
+		);
+		assert_eq!(Some(entropy), TemplateModule::draw_entropy(key.clone()));
+
+		// Random number of the draw mixes the block randomness with the entropy of the jurors
+		let subject = 0u64.encode();
+		let random_seed = sp_io::hashing::blake2_256(
+			&(TestRandomness::<Test>::random(&subject), entropy).encode(),
+		);
+		let random_number = u64::decode(&mut random_seed.as_ref()).unwrap();
+		assert_eq!(random_number, JurorBeacon::<Test>::random_number(&key, &subject));
+		assert_ne!(
+			BlockRandomness::<Test>::random_number(&key, &subject),
+			JurorBeacon::<Test>::random_number(&key, &subject)
+		);
+		// Games without entropy use the block randomness
+		let other_key = return_key_profile(1);
+		assert_eq!(
+			BlockRandomness::<Test>::random_number(&other_key, &subject),
+			JurorBeacon::<Test>::random_number(&other_key, &subject)
+		);
+
+		assert_ok!(TemplateModule::draw_jurors_helper(key.clone(), phase_data.clone(), 5));
+		assert_eq!(5, TemplateModule::drawn_jurors(key.clone()).len());
+		// Entropy can not be revealed after the drawing started
+		assert_noop!(
+			TemplateModule::reveal_draw_entropy(RuntimeOrigin::signed(13), key.clone(), [13u8; 32]),
+			Error::<Test>::EntropyRevealClosed
+		);
+	});
+}
+
+#[test]
+fn juror_records_updated_at_incentives_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		non_participation_game(key.clone(), phase_data.clone());
+		assert_eq!(JurorRecord::default(), TemplateModule::juror_record(4));
+
+		assert_ok!(TemplateModule::get_all_incentives_two_choice_helper(
+			key.clone(),
+			phase_data.clone()
+		));
+		let coherent = JurorRecord {
+			games_drawn: 1,
+			committed: 1,
+			revealed: 1,
+			coherent: 1,
+			rewarded: 483,
+			..Default::default()
+		};
+		for j in [4, 7] {
+			assert_eq!(coherent, TemplateModule::juror_record(j));
+		}
+		// Last winner gets the remainder of the division
+		assert_eq!(JurorRecord { rewarded: 484, ..coherent }, TemplateModule::juror_record(13));
+		assert_eq!(
+			JurorRecord { games_drawn: 1, committed: 1, slashed: 700, ..Default::default() },
+			TemplateModule::juror_record(14)
+		);
+		assert_eq!(
+			JurorRecord { games_drawn: 1, slashed: 750, ..Default::default() },
+			TemplateModule::juror_record(15)
+		);
+		// Jurors who were not drawn have no record
+		assert_eq!(JurorRecord::default(), TemplateModule::juror_record(5));
+	});
+}
+
+#[test]
+fn reputation_multiplier_test() {
+	frame_support::parameter_types! {
+		pub const MinVotes: u64 = 3;
+		pub const MinRate: Perbill = Perbill::from_percent(50);
+		pub const Bonus: Perbill = Perbill::from_percent(10);
+		pub const MaxBonus: Perbill = Perbill::from_percent(25);
+	}
+	type Multiplier = CoherenceMultiplier<MinVotes, MinRate, Bonus, MaxBonus>;
+
+	let record = |games_drawn, revealed, coherent, incoherent| JurorRecord {
+		games_drawn,
+		committed: revealed,
+		revealed,
+		coherent,
+		incoherent,
+		..Default::default()
+	};
+	// New jurors are drawn by their stake
+	assert_eq!(Some(Perbill::zero()), Multiplier::bonus(&JurorRecord::default()));
+	assert_eq!(Some(Perbill::from_percent(20)), Multiplier::bonus(&record(2, 2, 2, 0)));
+	assert_eq!(Some(Perbill::from_percent(25)), Multiplier::bonus(&record(5, 5, 5, 0)));
+	// Not enough decided votes to exclude the juror, draws are not decided
+	assert_eq!(Some(Perbill::zero()), Multiplier::bonus(&record(2, 2, 0, 2)));
+	assert_eq!(Some(Perbill::zero()), Multiplier::bonus(&record(4, 4, 0, 2)));
+	// Coherence rate below the minimum, not revealing counts against the juror
+	assert_eq!(None, Multiplier::bonus(&record(3, 3, 1, 2)));
+	assert_eq!(None, Multiplier::bonus(&record(3, 1, 1, 0)));
+	assert_eq!(Some(Perbill::from_percent(20)), Multiplier::bonus(&record(4, 4, 2, 2)));
+	assert_eq!(Some(Perbill::zero()), <() as ReputationMultiplier>::bonus(&record(3, 3, 0, 3)));
+
+	new_test_ext().execute_with(|| {
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			10
+		));
+		assert_ok!(TemplateModule::set_to_staking_period(
+			key.clone(),
+			phase_data.clone(),
+			10 + phase_data.evidence_length
+		));
+		assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+		crate::JurorRecords::<Test>::insert(4, record(3, 3, 1, 2));
+		crate::JurorRecords::<Test>::insert(5, record(3, 3, 3, 0));
+
+		assert_noop!(
+			TemplateModule::apply_jurors_helper(key.clone(), phase_data.clone(), 4, 400),
+			Error::<Test>::JurorReputationTooLow
+		);
+		assert_ok!(TemplateModule::apply_jurors_helper(key.clone(), phase_data.clone(), 5, 500));
+		assert_ok!(TemplateModule::apply_jurors_helper(key.clone(), phase_data.clone(), 6, 600));
+		// Weight in the draw includes the bonus, the stake does not
+		assert_eq!(Ok(Some(650)), SortitionSumGame::stake_of(key.clone(), 5));
+		assert_eq!(500, Balances::reserved_balance(5));
+		assert_eq!(Some(500), TemplateModule::juror_stakes(key.clone(), 5));
+		assert_eq!(Ok(Some(500)), TemplateModule::get_applied_stake(key.clone(), 5));
+		assert_eq!(Ok(Some(600)), SortitionSumGame::stake_of(key.clone(), 6));
+		assert_eq!(None, TemplateModule::juror_stakes(key.clone(), 6));
+		assert_eq!(Ok(Some(600)), TemplateModule::get_applied_stake(key.clone(), 6));
+	});
+}
+
+#[test]
+fn court_registry_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let phase_data = get_the_phase_data();
+		assert_noop!(
+			TemplateModule::create_court(
+				RuntimeOrigin::signed(1),
+				1,
+				None,
+				100,
+				phase_data.clone()
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::create_court(
+			RuntimeOrigin::root(),
+			1,
+			None,
+			100,
+			phase_data.clone()
+		));
+		System::assert_last_event(Event::CourtCreated { court_id: 1, parent: None }.into());
+		assert_ok!(TemplateModule::create_court(
+			RuntimeOrigin::root(),
+			2,
+			Some(1),
+			200,
+			phase_data.clone()
+		));
+		assert_noop!(
+			TemplateModule::create_court(RuntimeOrigin::root(), 2, None, 100, phase_data.clone()),
+			Error::<Test>::CourtExists
+		);
+		assert_noop!(
+			TemplateModule::create_court(
+				RuntimeOrigin::root(),
+				3,
+				Some(99),
+				100,
+				phase_data.clone()
+			),
+			Error::<Test>::CourtDoesNotExist
+		);
+		assert_eq!(vec![2, 1], TemplateModule::get_court_and_ancestors(2));
+
+		assert_noop!(
+			TemplateModule::stake_in_court(RuntimeOrigin::signed(4), 2, 199),
+			Error::<Test>::JurorStakeLessThanMin
+		);
+		assert_noop!(
+			TemplateModule::stake_in_court(RuntimeOrigin::signed(4), 99, 500),
+			Error::<Test>::CourtDoesNotExist
+		);
+		assert_ok!(TemplateModule::stake_in_court(RuntimeOrigin::signed(4), 2, 500));
+		System::assert_last_event(
+			Event::JurorStakedInCourt { court_id: 2, juror: 4, stake: 500 }.into(),
+		);
+		assert_eq!(Some((2, 500)), TemplateModule::juror_court(4));
+		assert_eq!(500, Balances::reserved_balance(4));
+		// Jurors stake in one court at a time
+		assert_noop!(
+			TemplateModule::stake_in_court(RuntimeOrigin::signed(4), 1, 500),
+			Error::<Test>::AlreadyStaked
+		);
+
+		assert_ok!(TemplateModule::unstake_from_court(RuntimeOrigin::signed(4)));
+		System::assert_last_event(
+			Event::JurorUnstakedFromCourt { court_id: 2, juror: 4, stake: 500 }.into(),
+		);
+		assert_eq!(0, Balances::reserved_balance(4));
+		assert_eq!(None, TemplateModule::juror_court(4));
+		assert_noop!(
+			TemplateModule::unstake_from_court(RuntimeOrigin::signed(4)),
+			Error::<Test>::StakeDoesNotExists
+		);
+	});
+}
+
+#[test]
+fn court_game_draws_from_court_and_ancestors_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let phase_data = get_the_phase_data();
+		assert_ok!(TemplateModule::create_court(
+			RuntimeOrigin::root(),
+			1,
+			None,
+			100,
+			phase_data.clone()
+		));
+		assert_ok!(TemplateModule::create_court(
+			RuntimeOrigin::root(),
+			2,
+			Some(1),
+			100,
+			phase_data.clone()
+		));
+		for j in 4..11 {
+			assert_ok!(TemplateModule::stake_in_court(RuntimeOrigin::signed(j), 1, j * 100));
+		}
+		for j in 20..26 {
+			assert_ok!(TemplateModule::stake_in_court(RuntimeOrigin::signed(j), 2, j * 100));
+		}
+
+		// Game of the top court draws jurors of the top court only
+		let start_game = |key: SumTreeName<u64, u64>, court_id| {
+			assert_ok!(TemplateModule::set_to_evidence_period(
+				key.clone(),
+				return_game_type_profile_approval(),
+				10
+			));
+			assert_ok!(TemplateModule::set_court_of_game_helper(key.clone(), court_id));
+			System::assert_last_event(Event::GameCourtSet { key: key.clone(), court_id }.into());
+			let staking_start_time = 10 + phase_data.evidence_length;
+			assert_ok!(TemplateModule::set_to_staking_period(
+				key.clone(),
+				phase_data.clone(),
+				staking_start_time
+			));
+			assert_noop!(
+				TemplateModule::apply_jurors_helper(key.clone(), phase_data.clone(), 30, 3000),
+				Error::<Test>::JurorsStakeInCourt
+			);
+			let new_now = staking_start_time + phase_data.staking_length;
+			assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now));
+			assert_ok!(TemplateModule::draw_jurors_helper(key.clone(), phase_data.clone(), 5));
+			TemplateModule::drawn_jurors(key)
+		};
+
+		let top_court_jurors = start_game(return_key_profile(0), 1);
+		assert_eq!(5, top_court_jurors.len());
+		for (juror, stake) in top_court_jurors.iter() {
+			assert!((4..11).contains(juror));
+			assert_eq!(juror * 100, *stake);
+			// Drawn juror stays in the court, the stake stays reserved for the game
+			assert_eq!(Some((1, juror * 100)), TemplateModule::juror_court(juror));
+			assert_eq!(Some(return_key_profile(0)), TemplateModule::court_draw(juror));
+			assert_eq!(juror * 100, Balances::reserved_balance(juror));
+		}
+		assert_noop!(
+			TemplateModule::unstake_from_court(RuntimeOrigin::signed(top_court_jurors[0].0)),
+			Error::<Test>::SelectedAsJuror
+		);
+
+		// Game of the child court draws jurors of the child court and of the top court
+		// Jurors drawn for the game of the top court are not drawn again
+		let child_court_jurors = start_game(return_key_profile(1), 2);
+		assert_eq!(5, child_court_jurors.len());
+		for (juror, _) in child_court_jurors.iter() {
+			assert!((4..11).contains(juror) || (20..26).contains(juror));
+			assert_eq!(Some(return_key_profile(1)), TemplateModule::court_draw(juror));
+		}
+		let not_drawn = (4..11).chain(20..26).filter(|j| TemplateModule::court_draw(j).is_none());
+		assert_eq!(3, not_drawn.count());
+
+		// Juror is drawn again after the game returns the stake
+		let (juror, stake) = top_court_jurors[0];
+		TemplateModule::return_juror_stake(&return_key_profile(0), &juror, stake);
+		assert_eq!(None, TemplateModule::court_draw(juror));
+		assert_eq!(Some((1, stake)), TemplateModule::juror_court(juror));
+		assert_eq!(
+			Ok(Some(stake)),
+			SortitionSumGame::stake_of(TemplateModule::court_tree_key(1), juror)
+		);
+		assert_eq!(stake, Balances::reserved_balance(juror));
+
+		// Juror leaves the court when the rest of the stake is less than the minimum stake
+		let (juror, stake) = top_court_jurors[1];
+		TemplateModule::return_juror_stake(&return_key_profile(0), &juror, 50);
+		System::assert_last_event(
+			Event::JurorUnstakedFromCourt { court_id: 1, juror, stake: 50 }.into(),
+		);
+		assert_eq!(None, TemplateModule::court_draw(juror));
+		assert_eq!(None, TemplateModule::juror_court(juror));
+		assert_eq!(stake - 50, Balances::reserved_balance(juror));
+	});
+}
+
+#[test]
+fn drawing_deadline_with_min_jurors_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			10
+		));
+		assert_ok!(TemplateModule::set_to_staking_period(key.clone(), phase_data.clone(), 60));
+		assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+		// `min_number_juror_staked` is 3 and `max_draws` is 5
+		for j in 4..7 {
+			assert_ok!(TemplateModule::apply_jurors_helper(
+				key.clone(),
+				phase_data.clone(),
+				j,
+				j * 100
+			));
+		}
+		run_to_block(110);
+		assert_eq!(Some(Period::Drawing), TemplateModule::get_period(key.clone()));
+		assert_eq!(110, TemplateModule::drawing_start_time(key.clone()));
+		assert_eq!(vec![key.clone()], TemplateModule::period_change_queue(160).to_vec());
+		assert_ok!(TemplateModule::draw_jurors_helper(key.clone(), phase_data.clone(), 3));
+		assert_eq!(3, TemplateModule::draws_in_round(key.clone()));
+
+		assert_noop!(
+			TemplateModule::change_period(key.clone(), phase_data.clone(), 159),
+			Error::<Test>::MaxJurorNotDrawn
+		);
+		run_to_block(159);
+		assert_eq!(Some(Period::Drawing), TemplateModule::get_period(key.clone()));
+
+		// Game goes on with the drawn jurors after the deadline
+		run_to_block(160);
+		assert_eq!(Some(Period::Commit), TemplateModule::get_period(key.clone()));
+		assert_eq!(160, TemplateModule::commit_start_time(key.clone()));
+		assert_eq!(3, TemplateModule::drawn_jurors(key.clone()).len());
+		assert!(VoidedGames::get().is_empty());
+	});
+}
+
+#[test]
+fn drawing_deadline_voids_game_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			10
+		));
+		assert_ok!(TemplateModule::set_to_staking_period(key.clone(), phase_data.clone(), 60));
+		assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+		for j in 4..7 {
+			assert_ok!(TemplateModule::apply_jurors_helper(
+				key.clone(),
+				phase_data.clone(),
+				j,
+				j * 100
+			));
+			assert_eq!(j * 100, Balances::reserved_balance(j));
+		}
+		run_to_block(110);
+		assert_ok!(TemplateModule::draw_jurors_helper(key.clone(), phase_data.clone(), 2));
+		assert_eq!(2, TemplateModule::draws_in_round(key.clone()));
+
+		// Fewer than `min_number_juror_staked` jurors are drawn
+		run_to_block(160);
+		assert_eq!(Some(Period::Execution), TemplateModule::get_period(key.clone()));
+		System::assert_has_event(Event::GameVoided { key: key.clone() }.into());
+		assert_eq!(vec![key.clone()], VoidedGames::get());
+		assert_eq!(
+			Some(GameOutcome::Voided),
+			TemplateModule::game_result(key.clone()).map(|result| result.outcome)
+		);
+		// Stakes are returned in `on_idle`, the drawn jurors and the jurors who were not drawn got back their stake
+		assert_eq!(400, Balances::reserved_balance(4));
+		TemplateModule::on_idle(160, Weight::MAX);
+		for j in 4..7 {
+			assert_eq!(0, Balances::reserved_balance(j));
+			assert_eq!(300000, Balances::free_balance(j));
+		}
+		assert!(TemplateModule::drawn_jurors(key.clone()).is_empty());
+		assert_noop!(
+			TemplateModule::get_all_incentives_two_choice_helper(key.clone(), phase_data.clone()),
+			Error::<Test>::GameVoided
+		);
+		assert!(TemplateModule::unstaking_helper(key.clone(), 6).is_err());
+		assert_eq!(0, Balances::reserved_balance(6));
+	});
+}
+
+#[test]
+fn resolved_two_choice_game_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		non_participation_game(key.clone(), get_the_phase_data());
+		let outcome = GameOutcome::TwoChoice(WinningDecision::WinnerYes);
+		System::assert_has_event(
+			Event::GameResolved {
+				key: key.clone(),
+				game_type: return_game_type_profile_approval(),
+				outcome: outcome.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(
+			vec![(key.clone(), return_game_type_profile_approval(), outcome)],
+			ResolvedGames::get()
+		);
+	});
+}
+
+#[test]
+fn resolved_score_game_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		score_game(
+			key.clone(),
+			get_the_phase_data(),
+			vec![(4, 1), (7, 1), (13, 5), (14, 1), (15, 7)],
+		);
+		// Outcome is known before the incentives are distributed
+		assert_eq!(
+			vec![(key.clone(), SchellingGameType::ProfileScore, GameOutcome::Score(2000))],
+			ResolvedGames::get()
+		);
+		assert_eq!(None, TemplateModule::game_result(key.clone()));
+	});
+}
+
+#[test]
+fn versioned_vote_commit_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let phase_data = get_the_phase_data();
+		let legacy_key = return_key_profile(1);
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			legacy_key.clone(),
+			return_game_type_profile_approval(),
+			10
+		));
+		VoteCommitVersion::set(CommitVersion::V1);
+		let key = return_key_profile(0);
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			10
+		));
+		// Game keeps the format it was created with
+		assert_eq!(CommitVersion::V0, TemplateModule::game_commit_version(legacy_key));
+		assert_eq!(CommitVersion::V1, TemplateModule::game_commit_version(key.clone()));
+
+		assert_ok!(TemplateModule::set_to_staking_period(key.clone(), phase_data.clone(), 60));
+		assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+		for j in 4..30 {
+			assert_ok!(TemplateModule::apply_jurors_helper(
+				key.clone(),
+				phase_data.clone(),
+				j,
+				j * 100
+			));
+		}
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), 110));
+		assert_ok!(TemplateModule::draw_jurors_helper(key.clone(), phase_data.clone(), 5));
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), 110));
+
+		let genesis_hash = System::block_hash(0);
+		let salt = "salt".as_bytes();
+		let hash = vote_commit(CommitVersion::V1, &genesis_hash, &key, &4u64, 1u128, salt);
+		assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 4, hash));
+		// Commit of juror 4 copied by juror 7
+		assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 7, hash));
+		let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
+		assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 13, hash));
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), 160));
+
+		assert_ok!(TemplateModule::reveal_vote_two_choice_helper(key.clone(), 4, 1, salt.to_vec()));
+		assert_noop!(
+			TemplateModule::reveal_vote_two_choice_helper(key.clone(), 7, 1, salt.to_vec()),
+			Error::<Test>::CommitDoesNotMatch
+		);
+		// Commit in the old format is not accepted in the game
+		assert_noop!(
+			TemplateModule::reveal_vote_two_choice_helper(key.clone(), 13, 1, salt.to_vec()),
+			Error::<Test>::CommitDoesNotMatch
+		);
+		assert_eq!((0, 1), TemplateModule::decision_count(key.clone()));
+	});
+}
+
+fn evidence_content() -> Content {
+	Content::IPFS(
+		"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+			.as_bytes()
+			.to_vec(),
+	)
+}
+
+#[test]
+fn submit_evidence_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		assert_noop!(
+			TemplateModule::submit_evidence(
+				RuntimeOrigin::signed(4),
+				key.clone(),
+				evidence_content(),
+				None
+			),
+			Error::<Test>::PeriodDoesNotExists
+		);
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			10
+		));
+		assert_noop!(
+			TemplateModule::submit_evidence(
+				RuntimeOrigin::signed(4),
+				key.clone(),
+				Content::None,
+				None
+			),
+			ContentError::ContentIsEmpty
+		);
+		assert_noop!(
+			TemplateModule::submit_evidence(
+				RuntimeOrigin::signed(4),
+				key.clone(),
+				Content::IPFS(b"bafkrei".to_vec()),
+				None
+			),
+			ContentError::InvalidIpfsCid
+		);
+		assert_ok!(TemplateModule::submit_evidence(
+			RuntimeOrigin::signed(4),
+			key.clone(),
+			evidence_content(),
+			None
+		));
+		System::assert_last_event(
+			Event::EvidenceSubmitted {
+				key: key.clone(),
+				evidence_id: 1,
+				submitter: 4,
+				reply_to: None,
+			}
+			.into(),
+		);
+		// `EvidenceDeposit` is 100
+		assert_eq!(100, Balances::reserved_balance(4));
+
+		// Replies to the evidence
+		assert_noop!(
+			TemplateModule::submit_evidence(
+				RuntimeOrigin::signed(5),
+				key.clone(),
+				evidence_content(),
+				Some(2)
+			),
+			Error::<Test>::EvidenceDoesNotExist
+		);
+		assert_ok!(TemplateModule::submit_evidence(
+			RuntimeOrigin::signed(5),
+			key.clone(),
+			evidence_content(),
+			Some(1)
+		));
+		assert_ok!(TemplateModule::submit_evidence(
+			RuntimeOrigin::signed(4),
+			key.clone(),
+			evidence_content(),
+			Some(1)
+		));
+		assert_eq!(200, Balances::reserved_balance(4));
+
+		let evidence = TemplateModule::get_game_evidence(key.clone(), 0, 10);
+		assert_eq!(vec![1], evidence.iter().map(|(id, _)| *id).collect::<Vec<_>>());
+		assert_eq!(4, evidence[0].1.submitter);
+		assert_eq!(evidence_content(), evidence[0].1.content);
+		let replies = TemplateModule::get_evidence_replies(key.clone(), 1, 0, 10);
+		assert_eq!(vec![2, 3], replies.iter().map(|(id, _)| *id).collect::<Vec<_>>());
+		assert!(replies.iter().all(|(_, evidence)| evidence.reply_to == Some(1)));
+		let replies = TemplateModule::get_evidence_replies(key.clone(), 1, 1, 10);
+		assert_eq!(vec![3], replies.iter().map(|(id, _)| *id).collect::<Vec<_>>());
+		assert!(TemplateModule::get_evidence_replies(key.clone(), 1, 2, 10).is_empty());
+		assert_eq!(1, TemplateModule::get_evidence_replies(key.clone(), 1, 0, 1).len());
+
+		// Evidence is not submitted after `Period::Evidence`, replies are submitted in `Period::Staking`
+		let phase_data = get_the_phase_data();
+		assert_ok!(TemplateModule::set_to_staking_period(key.clone(), phase_data, 60));
+		assert_noop!(
+			TemplateModule::submit_evidence(
+				RuntimeOrigin::signed(4),
+				key.clone(),
+				evidence_content(),
+				None
+			),
+			Error::<Test>::PeriodDontMatch
+		);
+		assert_ok!(TemplateModule::submit_evidence(
+			RuntimeOrigin::signed(6),
+			key.clone(),
+			evidence_content(),
+			Some(1)
+		));
+	});
+}
+
+#[test]
+fn max_evidence_per_game_test() {
+	new_test_ext().execute_with(|| {
+		let key = return_key_profile(0);
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			10
+		));
+		// `MaxEvidencePerGame` is 3
+		for who in [4, 5, 6] {
+			assert_ok!(TemplateModule::submit_evidence(
+				RuntimeOrigin::signed(who),
+				key.clone(),
+				evidence_content(),
+				None
+			));
+		}
+		assert_noop!(
+			TemplateModule::submit_evidence(
+				RuntimeOrigin::signed(7),
+				key.clone(),
+				evidence_content(),
+				None
+			),
+			Error::<Test>::MaxEvidenceReached
+		);
+		// Replies have their own limit
+		assert_ok!(TemplateModule::submit_evidence(
+			RuntimeOrigin::signed(7),
+			key.clone(),
+			evidence_content(),
+			Some(1)
+		));
+	});
+}
+
+#[test]
+fn evidence_reply_depth_test() {
+	new_test_ext().execute_with(|| {
+		let key = return_key_profile(0);
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			10
+		));
+		assert_ok!(TemplateModule::submit_evidence(
+			RuntimeOrigin::signed(4),
+			key.clone(),
+			evidence_content(),
+			None
+		));
+		// `MAX_EVIDENCE_REPLY_DEPTH` is 3
+		for evidence_id in 1..=MAX_EVIDENCE_REPLY_DEPTH as u64 {
+			assert_ok!(TemplateModule::submit_evidence(
+				RuntimeOrigin::signed(5),
+				key.clone(),
+				evidence_content(),
+				Some(evidence_id)
+			));
+		}
+		assert_noop!(
+			TemplateModule::submit_evidence(
+				RuntimeOrigin::signed(5),
+				key.clone(),
+				evidence_content(),
+				Some(4)
+			),
+			Error::<Test>::MaxReplyDepthReached
+		);
+		assert_ok!(TemplateModule::submit_evidence(
+			RuntimeOrigin::signed(5),
+			key.clone(),
+			evidence_content(),
+			Some(3)
+		));
+	});
+}
+
+#[test]
+fn evidence_in_staking_period_without_evidence_period_test() {
+	new_test_ext().execute_with(|| {
+		let key = return_key_profile(0);
+		assert_ok!(TemplateModule::set_to_staking_period_pe(
+			key.clone(),
+			return_game_type_profile_approval(),
+			1
+		));
+		assert_ok!(TemplateModule::submit_evidence(
+			RuntimeOrigin::signed(4),
+			key.clone(),
+			evidence_content(),
+			None
+		));
+		assert_eq!(1, TemplateModule::get_game_evidence(key, 0, 10).len());
+	});
+}
+
+#[test]
+fn evidence_deposit_returned_when_game_storage_removed_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			10
+		));
+		assert_ok!(TemplateModule::submit_evidence(
+			RuntimeOrigin::signed(4),
+			key.clone(),
+			evidence_content(),
+			None
+		));
+		assert_ok!(TemplateModule::submit_evidence(
+			RuntimeOrigin::signed(5),
+			key.clone(),
+			evidence_content(),
+			Some(1)
+		));
+		assert_eq!(100, Balances::reserved_balance(4));
+		assert_eq!(100, Balances::reserved_balance(5));
+
+		TemplateModule::archive_game(key.clone(), GameOutcome::Voided);
+		TemplateModule::on_idle(1, Weight::MAX);
+		System::assert_last_event(Event::GameStorageRemoved { key: key.clone() }.into());
+		assert_eq!(0, Balances::reserved_balance(4));
+		assert_eq!(0, Balances::reserved_balance(5));
+		assert!(TemplateModule::get_game_evidence(key.clone(), 0, 10).is_empty());
+		assert!(TemplateModule::get_evidence_replies(key, 1, 0, 10).is_empty());
+	});
+}
+
+/// Balances of the jurors of the game and of the juror stake account
+fn juror_balances(jurors: &[u64]) -> Vec<(u64, u64)> {
+	let mut balances = jurors
+		.iter()
+		.map(|juror| (Balances::free_balance(juror), Balances::reserved_balance(juror)))
+		.collect::<Vec<_>>();
+	balances.push((Balances::free_balance(TemplateModule::juror_stake_account()), 0));
+	balances
+}
+
+#[test]
+fn distribute_incentives_in_pages_test() {
+	let key = return_key_profile(0);
+	let phase_data = get_the_phase_data();
+	let jurors = [4, 7, 13, 14, 15];
+	let balances_in_one_go = new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		non_participation_game(key.clone(), phase_data.clone());
+		assert_ok!(TemplateModule::get_all_incentives_two_choice_helper(
+			key.clone(),
+			phase_data.clone()
+		));
+		juror_balances(&jurors)
+	});
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			TemplateModule::distribute_incentives(RuntimeOrigin::signed(1), key.clone(), 2),
+			Error::<Test>::PeriodDoesNotExists
+		);
+		non_participation_game(key.clone(), phase_data.clone());
+		assert_ok!(TemplateModule::distribute_incentives(RuntimeOrigin::signed(1), key.clone(), 2));
+		let progress = IncentiveProgress {
+			round: 0,
+			stage: IncentiveStage::Penalties,
+			next_juror: 2,
+			penalties: 0,
+			winners: 2,
+			winner_weight: 2,
+			paid: 0,
+			paid_winners: 0,
+			score_window: None,
+		};
+		System::assert_last_event(
+			Event::IncentivePageDistributed {
+				key: key.clone(),
+				jurors: 2,
+				progress: progress.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(Some(progress), TemplateModule::incentive_progress(key.clone()));
+		assert_noop!(
+			TemplateModule::get_incentives_two_choice_helper(key.clone(), phase_data.clone(), 4),
+			Error::<Test>::IncentivesBeingDistributed
+		);
+		assert_noop!(
+			TemplateModule::get_all_incentives_two_choice_helper(key.clone(), phase_data.clone()),
+			Error::<Test>::IncentivesBeingDistributed
+		);
+
+		// Penalties of jurors 14 and 15 are collected before the winners get their share
+		assert_ok!(TemplateModule::distribute_incentives(RuntimeOrigin::signed(1), key.clone(), 2));
+		assert_ok!(TemplateModule::distribute_incentives(RuntimeOrigin::signed(1), key.clone(), 2));
+		let progress = TemplateModule::incentive_progress(key.clone()).unwrap();
+		assert_eq!(IncentiveStage::Rewards, progress.stage);
+		assert_eq!(1, progress.next_juror);
+		assert_eq!(1450, progress.penalties);
+		assert_eq!(300483, Balances::free_balance(4));
+		assert_eq!(0, Balances::reserved_balance(14));
+
+		assert_ok!(TemplateModule::distribute_incentives(RuntimeOrigin::signed(1), key.clone(), 2));
+		assert_ok!(TemplateModule::distribute_incentives(RuntimeOrigin::signed(1), key.clone(), 2));
+		System::assert_has_event(Event::IncentivesDistributed { key: key.clone() }.into());
+		assert_eq!(
+			Some(GameOutcome::TwoChoice(WinningDecision::WinnerYes)),
+			TemplateModule::game_result(key.clone()).map(|result| result.outcome)
+		);
+		assert_eq!(None, TemplateModule::incentive_progress(key.clone()));
+		assert_eq!(balances_in_one_go, juror_balances(&jurors));
+
+		assert_noop!(
+			TemplateModule::distribute_incentives(RuntimeOrigin::signed(1), key.clone(), 2),
+			Error::<Test>::AlreadyGotIncentives
+		);
+		// Jurors are removed from the game
+		assert_noop!(
+			TemplateModule::get_incentives_two_choice_helper(key.clone(), phase_data.clone(), 4),
+			Error::<Test>::StakeDoesNotExists
+		);
+	});
+}
+
+#[test]
+fn distribute_incentives_not_started_after_incentives_of_juror_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		non_participation_game(key.clone(), phase_data.clone());
+		assert_ok!(TemplateModule::get_incentives_two_choice_helper(
+			key.clone(),
+			phase_data.clone(),
+			4
+		));
+		assert_noop!(
+			TemplateModule::distribute_incentives(RuntimeOrigin::signed(1), key.clone(), 5),
+			Error::<Test>::IncentivesBeingDistributed
+		);
+	});
+}
+
+#[test]
+fn distribute_incentives_score_game_test() {
+	let key = return_key_profile(0);
+	let phase_data = get_the_phase_data();
+	let votes = vec![(4, 1), (7, 1), (13, 5), (14, 1), (15, 7)];
+	let jurors = [4, 7, 13, 14, 15];
+	let balances_in_one_go = new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		score_game(key.clone(), phase_data.clone(), votes.clone());
+		assert_ok!(TemplateModule::get_incentives_score_schelling_helper(
+			key.clone(),
+			phase_data.clone(),
+			RangePoint::ZeroToTen
+		));
+		juror_balances(&jurors)
+	});
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		score_game(key.clone(), phase_data.clone(), votes);
+		assert_ok!(TemplateModule::distribute_incentives(RuntimeOrigin::signed(1), key.clone(), 3));
+		System::assert_has_event(Event::ScoreMeanDecided { key: key.clone(), mean: 2000 }.into());
+		assert_noop!(
+			TemplateModule::get_incentives_score_schelling_helper(
+				key.clone(),
+				phase_data.clone(),
+				RangePoint::ZeroToTen
+			),
+			Error::<Test>::IncentivesBeingDistributed
+		);
+		for _ in 0..3 {
+			assert_ok!(TemplateModule::distribute_incentives(
+				RuntimeOrigin::signed(1),
+				key.clone(),
+				3
+			));
+		}
+		assert_eq!(
+			Some(GameOutcome::Score(2000)),
+			TemplateModule::game_result(key.clone()).map(|result| result.outcome)
+		);
+		assert_eq!(balances_in_one_go, juror_balances(&jurors));
+	});
+}
+
+#[test]
+fn set_range_point_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let game_type = SchellingGameType::ProfileScore;
+		assert_noop!(
+			TemplateModule::set_range_point(
+				RuntimeOrigin::signed(1),
+				game_type.clone(),
+				RangePoint::ZeroToFive
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		let old_key = return_key_profile(0);
+		assert_ok!(TemplateModule::set_to_evidence_period(old_key.clone(), game_type.clone(), 10));
+		assert_ok!(TemplateModule::set_range_point(
+			RuntimeOrigin::root(),
+			game_type.clone(),
+			RangePoint::ZeroToFive
+		));
+		System::assert_last_event(
+			Event::RangePointSet {
+				game_type: game_type.clone(),
+				range_point: RangePoint::ZeroToFive,
+			}
+			.into(),
+		);
+		let new_key = return_key_profile(1);
+		assert_ok!(TemplateModule::set_to_evidence_period(new_key.clone(), game_type, 10));
+		assert_eq!(RangePoint::ZeroToTen, TemplateModule::game_range_point(old_key));
+		assert_eq!(RangePoint::ZeroToFive, TemplateModule::game_range_point(new_key));
+	});
+}
+
+#[test]
+fn delegate_stake_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let phase_data = get_the_phase_data();
+		let court_key = TemplateModule::court_tree_key(1);
+		let target = DelegationTarget::Court(1);
+		assert_noop!(
+			TemplateModule::delegate_stake(RuntimeOrigin::signed(30), 4, target.clone(), 600),
+			Error::<Test>::CourtDoesNotExist
+		);
+		assert_ok!(TemplateModule::create_court(RuntimeOrigin::root(), 1, None, 100, phase_data));
+		assert_ok!(TemplateModule::stake_in_court(RuntimeOrigin::signed(4), 1, 400));
+		assert_noop!(
+			TemplateModule::delegate_stake(RuntimeOrigin::signed(4), 4, target.clone(), 600),
+			Error::<Test>::InvalidDelegation
+		);
+		assert_noop!(
+			TemplateModule::delegate_stake(RuntimeOrigin::signed(30), 4, target.clone(), 0),
+			Error::<Test>::InvalidDelegation
+		);
+
+		// Delegated stake adds to the weight of the juror staked in the court
+		assert_ok!(TemplateModule::delegate_stake(
+			RuntimeOrigin::signed(30),
+			4,
+			target.clone(),
+			600
+		));
+		System::assert_last_event(
+			Event::StakeDelegated { delegator: 30, juror: 4, target: target.clone(), amount: 600 }
+				.into(),
+		);
+		assert_eq!(600, Balances::reserved_balance(30));
+		assert_eq!(Ok(Some(1000)), SortitionSumGame::stake_of(court_key.clone(), 4));
+		assert_ok!(TemplateModule::delegate_stake(
+			RuntimeOrigin::signed(30),
+			4,
+			target.clone(),
+			100
+		));
+		assert_eq!(vec![(30, 700)], TemplateModule::delegations(4, target.clone()));
+		assert_eq!(Ok(Some(1100)), SortitionSumGame::stake_of(court_key.clone(), 4));
+
+		// Juror gets the delegated stake when staking in the court
+		assert_ok!(TemplateModule::delegate_stake(
+			RuntimeOrigin::signed(31),
+			5,
+			target.clone(),
+			100
+		));
+		assert_eq!(Ok(None), SortitionSumGame::stake_of(court_key.clone(), 5));
+		assert_ok!(TemplateModule::stake_in_court(RuntimeOrigin::signed(5), 1, 500));
+		assert_eq!(Ok(Some(600)), SortitionSumGame::stake_of(court_key.clone(), 5));
+
+		assert_noop!(
+			TemplateModule::withdraw_delegation(RuntimeOrigin::signed(31), 4, target.clone()),
+			Error::<Test>::DelegationDoesNotExist
+		);
+		assert_ok!(TemplateModule::withdraw_delegation(
+			RuntimeOrigin::signed(30),
+			4,
+			target.clone()
+		));
+		System::assert_last_event(
+			Event::DelegationWithdrawn {
+				delegator: 30,
+				juror: 4,
+				target: target.clone(),
+				amount: 700,
+			}
+			.into(),
+		);
+		assert_eq!(0, Balances::reserved_balance(30));
+		assert!(TemplateModule::delegations(4, target).is_empty());
+		assert_eq!(Ok(Some(400)), SortitionSumGame::stake_of(court_key, 4));
+
+		// At most `MaxDelegatorsPerJuror` delegators
+		let target = DelegationTarget::GameType(return_game_type_profile_approval());
+		for delegator in 30..33 {
+			assert_ok!(TemplateModule::delegate_stake(
+				RuntimeOrigin::signed(delegator),
+				6,
+				target.clone(),
+				100
+			));
+		}
+		assert_noop!(
+			TemplateModule::delegate_stake(RuntimeOrigin::signed(33), 6, target.clone(), 100),
+			Error::<Test>::MaxDelegatorsReached
+		);
+		assert_ok!(TemplateModule::delegate_stake(RuntimeOrigin::signed(30), 6, target, 100));
+	});
+}
+
+#[test]
+fn delegators_share_incentives_of_juror_test() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = return_key_profile(0);
+		let phase_data = get_the_phase_data();
+		let target = DelegationTarget::GameType(return_game_type_profile_approval());
+		assert_ok!(TemplateModule::delegate_stake(
+			RuntimeOrigin::signed(30),
+			4,
+			target.clone(),
+			300
+		));
+		assert_ok!(TemplateModule::delegate_stake(
+			RuntimeOrigin::signed(31),
+			4,
+			target.clone(),
+			100
+		));
+		assert_ok!(TemplateModule::delegate_stake(
+			RuntimeOrigin::signed(32),
+			5,
+			target.clone(),
+			400
+		));
+
+		let now = 10;
+		assert_ok!(TemplateModule::set_to_evidence_period(
+			key.clone(),
+			return_game_type_profile_approval(),
+			now
+		));
+		let staking_start_time = now + phase_data.evidence_length;
+		assert_ok!(TemplateModule::set_to_staking_period(
+			key.clone(),
+			phase_data.clone(),
+			staking_start_time
+		));
+		assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+		for j in 4..9 {
+			assert_ok!(TemplateModule::apply_jurors_helper(
+				key.clone(),
+				phase_data.clone(),
+				j,
+				j * 100
+			));
+		}
+		// Weight of the juror includes the delegated stake, the reserved stake does not
+		assert_eq!(Ok(Some(800)), SortitionSumGame::stake_of(key.clone(), 4));
+		assert_eq!(Ok(Some(400)), TemplateModule::get_applied_stake(key.clone(), 4));
+		assert_eq!(1, TemplateModule::juror_active_games(4));
+		assert_noop!(
+			TemplateModule::withdraw_delegation(RuntimeOrigin::signed(30), 4, target.clone()),
+			Error::<Test>::JurorInActiveGame
+		);
+
+		// All the jurors who applied are drawn
+		let new_now = staking_start_time + phase_data.staking_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now));
+		assert_ok!(TemplateModule::draw_jurors_helper(key.clone(), phase_data.clone(), 5));
+		assert_eq!(
+			vec![(4, 400), (5, 500), (6, 600), (7, 700), (8, 800)],
+			TemplateModule::drawn_jurors(key.clone())
+		);
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now));
+		for (juror, vote) in [(4, "1salt"), (5, "0salt"), (6, "1salt"), (7, "1salt")] {
+			let hash = sp_io::hashing::keccak_256(vote.as_bytes());
+			assert_ok!(TemplateModule::commit_vote_helper(key.clone(), juror, hash));
+		}
+		let commit_start_time = TemplateModule::commit_start_time(key.clone());
+		let new_now = commit_start_time + phase_data.commit_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now));
+		for (juror, choice) in [(4, 1), (5, 0), (6, 1), (7, 1)] {
+			assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
+				key.clone(),
+				juror,
+				choice,
+				"salt".as_bytes().to_vec()
+			));
+		}
+		let vote_start_time = TemplateModule::vote_start_time(key.clone());
+		let new_now = vote_start_time + phase_data.vote_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now));
+		let appeal_start_time = TemplateModule::appeal_start_time(key.clone());
+		let new_now = appeal_start_time + phase_data.appeal_length;
+		assert_ok!(TemplateModule::change_period(key.clone(), phase_data.clone(), new_now));
+
+		// Penalties of juror 5 (125) and juror 8 (400) are shared by the 3 winners
+		assert_ok!(TemplateModule::get_all_incentives_two_choice_helper(
+			key.clone(),
+			phase_data.clone()
+		));
+		// Delegators of juror 4 get 175 * 300 / 800 and 175 * 100 / 800 of the reward before the juror
+		System::assert_has_event(
+			Event::DelegatorsIncentivesShared {
+				key: key.clone(),
+				juror: 4,
+				rewarded: 86,
+				slashed: 0,
+			}
+			.into(),
+		);
+		assert_eq!(300089, Balances::free_balance(4));
+		assert_eq!(299765, Balances::free_balance(30));
+		assert_eq!(299921, Balances::free_balance(31));
+		// Juror 5 lost 125 of the stake of 500, the delegated stake loses 400 * 125 / 500
+		System::assert_has_event(
+			Event::DelegatorsIncentivesShared {
+				key: key.clone(),
+				juror: 5,
+				rewarded: 0,
+				slashed: 100,
+			}
+			.into(),
+		);
+		assert_eq!(299875, Balances::free_balance(5));
+		assert_eq!(300, Balances::reserved_balance(32));
+		assert_eq!(vec![(32, 300)], TemplateModule::delegations(5, target.clone()));
+
+		// Delegations are withdrawn after the storage of the game is removed
+		assert_noop!(
+			TemplateModule::withdraw_delegation(RuntimeOrigin::signed(30), 4, target.clone()),
+			Error::<Test>::JurorInActiveGame
+		);
+		// `MaxVoteRemovalsPerBlock` of the stakes and delegations of the game are removed in a block
+		TemplateModule::on_idle(1, Weight::MAX);
+		TemplateModule::on_idle(2, Weight::MAX);
+		System::assert_last_event(Event::GameStorageRemoved { key: key.clone() }.into());
+		assert_eq!(0, TemplateModule::juror_active_games(4));
+		assert_eq!(None, TemplateModule::game_delegations(key, 4));
+		assert_ok!(TemplateModule::withdraw_delegation(
+			RuntimeOrigin::signed(30),
+			4,
+			target.clone()
+		));
+		assert_eq!(300065, Balances::free_balance(30));
+		assert_ok!(TemplateModule::withdraw_delegation(RuntimeOrigin::signed(32), 5, target));
+		assert_eq!(299900, Balances::free_balance(32));
+	});
+}
@@ -3,6 +3,15 @@ use frame_support::sp_std::prelude::*;
 use scale_info::TypeInfo;
 use super::*;
 
+/// Maximum number of appeals that can be made on a single game
+pub const MAX_APPEAL_ROUNDS: u64 = 3;
+
+/// Number of blocks after the end of the period searched for room in `PeriodChangeQueue`
+pub const MAX_PERIOD_CHANGE_DELAY: u32 = 10;
+
+/// Maximum number of choices in multi choice schelling game
+pub const MAX_NUMBER_OF_CHOICES: u32 = 100;
+
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub enum Period {
@@ -27,7 +36,7 @@ pub enum SchellingGameType {
 }
 
 
-#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 pub struct PhaseData<T: Config> {
 	pub evidence_length: T::BlockNumber,
@@ -40,7 +49,7 @@ pub struct PhaseData<T: Config> {
 	pub max_draws: u64,
 	pub min_number_juror_staked: u64,
 	pub min_juror_stake: BalanceOf<T>,
-	pub juror_incentives: (u64, u64), // (looser burn, winner mint)
+	pub juror_incentives: (u64, u64), // (looser burn, winner mint), winner mint prices the appeal fee
 }
 
 
@@ -98,15 +107,207 @@ pub struct ScoreCommitVote {
 }
 
 
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct MultiChoiceCommitVote {
+	pub commit: [u8; 32],
+	pub votestatus: VoteStatus,
+	pub revealed_vote: Option<u32>,
+}
+
 
 /// RangePoint enum to determine whether score values are from
 /// 1) ZeroToTen: 0 to 10 
 /// 2) MinusTenToPlusTen: -10 to +10
 /// 3) ZeroToFive: 0 to 5
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Default, Encode, Decode, MaxEncodedLen, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub enum RangePoint {
+	#[default]
 	ZeroToTen,
 	MinusTenToPlusTen,
 	ZeroToFive,
-}
\ No newline at end of file
+}
+
+/// Statistic of the revealed scores that decides the score and the winners of score schelling game
+/// 1) MeanWithinStdDeviation: mean of the scores within one standard deviation of the mean,
+///    jurors within the incentives range of `RangePoint` win
+/// 2) MedianAbsoluteDeviation: median of the scores, jurors within the median absolute deviation
+///    of the median win, the window is never smaller than the incentives range of `RangePoint`
+/// 3) TrimmedMean(percent): mean after removing `percent` of the lowest and of the highest scores,
+///    jurors within the incentives range of `RangePoint` win
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Default, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum ScoreAggregation {
+	#[default]
+	MeanWithinStdDeviation,
+	MedianAbsoluteDeviation,
+	TrimmedMean(u8),
+}
+
+/// How the incentives of score schelling game jurors change with the distance of their score from the score of the game
+/// 1) Flat: jurors within the window share the penalties equally, jurors outside it lose a quarter of the stake
+/// 2) Linear { cap }: jurors within the window share the penalties by `window - distance`,
+///    jurors outside it lose `cap` of the stake times `(distance - window) / window`, at most `cap`
+/// 3) Quadratic { cap }: same as linear with the squares, `(window - distance)^2 / window` and
+///    `((distance - window) / window)^2`
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Default, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum IncentiveCurve {
+	#[default]
+	Flat,
+	Linear { cap: Perbill },
+	Quadratic { cap: Perbill },
+}
+
+/// Format of the vote commits of a game, a game keeps the format it was created with
+/// 1) V0: `keccak_256(choice ++ salt)`, the choice is a decimal string
+/// 2) V1: `keccak_256` of the SCALE encoded `(genesis hash, key of the game, juror, choice, salt)`,
+///    a commit can not be copied by another juror or replayed in another game or chain
+#[derive(
+	PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default, Encode, Decode, MaxEncodedLen, TypeInfo,
+)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum CommitVersion {
+	#[default]
+	V0,
+	V1,
+}
+
+/// Kind of votes of the game, two choice when the game is created
+/// Set to `Score` or `MultiChoice` by the consumer pallet before the commit period
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum GameKind {
+	TwoChoice,
+	Score,
+	MultiChoice,
+}
+
+/// Result of the game kept after its storage is removed
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum GameOutcome {
+	TwoChoice(WinningDecision),
+	Score(i64),
+	MultiChoice(Option<u32>),
+	/// Too few jurors were drawn before the drawing deadline
+	Voided,
+}
+
+/// Compact record of a settled game
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct GameResult<BlockNumber> {
+	pub outcome: GameOutcome,
+	pub appeal_rounds: u64,
+	pub settled_at: BlockNumber,
+}
+
+/// Votes of the juror in all the games, updated when the juror gets the incentives
+/// Every round of an appealed game the juror was drawn in is counted as a game
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Default, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct JurorRecord {
+	pub games_drawn: u64,
+	pub committed: u64,
+	pub revealed: u64,
+	pub coherent: u64,
+	pub incoherent: u64,
+	pub rewarded: u64,
+	pub slashed: u64,
+}
+
+/// Id of a court, courts of the departments can use the id of the department
+pub type CourtId = u64;
+
+/// Jurors of the game type or of the court get the weight of the stake delegated to them
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum DelegationTarget {
+	GameType(SchellingGameType),
+	Court(CourtId),
+}
+
+/// Number of children of the nodes of the sortition tree of a court
+pub const COURT_TREE_K: u64 = 3;
+
+/// Maximum number of courts from a court to the top court, including both
+pub const MAX_COURT_DEPTH: u32 = 8;
+
+/// Court of specialized jurors, disputes of the court draw jurors staked in it and in its ancestors
+#[derive(Encode, Decode, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct Court<T: Config> {
+	pub parent: Option<CourtId>,
+	pub min_stake: BalanceOf<T>,
+	pub phase_data: PhaseData<T>,
+}
+
+/// Id of an evidence, replies to an evidence have their own ids
+pub type EvidenceId = u64;
+
+/// Id of the first evidence
+pub const FIRST_EVIDENCE_ID: EvidenceId = 1;
+
+/// Maximum number of evidence from a reply to the evidence of the game, excluding the evidence of the game
+pub const MAX_EVIDENCE_REPLY_DEPTH: u32 = 3;
+
+/// Evidence submitted for a game, `reply_to` is the evidence of the game it replies to
+/// Deposit is returned to the submitter when the storage of the game is removed
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Evidence<AccountId, Balance, BlockNumber> {
+	pub submitter: AccountId,
+	pub content: Content,
+	pub reply_to: Option<EvidenceId>,
+	pub deposit: Balance,
+	pub submitted_at: BlockNumber,
+}
+
+/// Stage of the incentives of a round distributed in pages
+/// 1) Penalties: losing and non participating jurors are slashed and get back the rest of their stake
+/// 2) Rewards: winning jurors get back their stake and their share of the penalties of the round
+#[derive(
+	PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default, Encode, Decode, MaxEncodedLen, TypeInfo,
+)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum IncentiveStage {
+	#[default]
+	Penalties,
+	Rewards,
+}
+
+/// Incentives of a round of two choice schelling game that are not paid yet
+/// Penalties of the jurors and the appeal fee of the round are held in the juror stake account
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct IncentivePool {
+	pub incentives: u64,
+	/// Jurors of the round who voted for the winning decision, or who revealed the vote in a draw
+	pub winners: u64,
+	/// Incentives paid to the winners so far
+	pub paid: u64,
+	pub paid_winners: u64,
+}
+
+/// Progress of the incentives of a game distributed in pages
+/// Rounds of an appealed game are distributed one after the other, the current round last
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct IncentiveProgress {
+	pub round: u64,
+	pub stage: IncentiveStage,
+	/// Index of the next juror of the round
+	pub next_juror: u32,
+	/// Penalties of the round collected so far, winners are paid from it
+	pub penalties: u64,
+	pub winners: u64,
+	/// Sum of the weights of the winners of the round, winners share the penalties by their weight
+	pub winner_weight: u64,
+	/// Penalties paid to the winners of the round so far
+	pub paid: u64,
+	pub paid_winners: u64,
+	/// Score and window of score schelling game, set when the distribution starts
+	pub score_window: Option<(i64, i64)>,
+}
@@ -0,0 +1,111 @@
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+	/// Drawing deadline passed, the game goes on with the drawn jurors if they are at least `min_number_juror_staked`
+	/// Otherwise the game is voided
+	pub(super) fn end_drawing_after_deadline(
+		key: SumTreeNameType<T>,
+		phase_data: PhaseDataOf<T>,
+		now: BlockNumberOf<T>,
+	) -> DispatchResult {
+		let draws_in_round = <DrawsInRound<T>>::get(&key);
+		if draws_in_round > 0 && draws_in_round >= phase_data.min_number_juror_staked {
+			let commit_end_time = now
+				.checked_add(&phase_data.commit_length)
+				.ok_or(Error::<T>::BlockNumberOverflow)?;
+			<CommitStartTime<T>>::insert(&key, now);
+			Self::set_period(key.clone(), Period::Commit);
+			Self::schedule_period_change(key, commit_end_time);
+			Ok(())
+		} else {
+			Self::void_game(key)
+		}
+	}
+
+	/// Void the game, `OnGameVoided` is called so that the consumer pallet returns its own deposits
+	/// Stakes of the jurors of all the rounds and the appeal fees are returned in `on_idle` before the storage of the game is removed
+	pub(super) fn void_game(key: SumTreeNameType<T>) -> DispatchResult {
+		Self::set_period(key.clone(), Period::Execution);
+		Self::deposit_event(Event::GameVoided { key: key.clone() });
+		Self::archive_game(key.clone(), GameOutcome::Voided);
+		T::OnGameVoided::on_game_voided(key);
+		Ok(())
+	}
+
+	/// Return the stakes of at most `limit` drawn jurors of the voided game
+	pub(super) fn refund_voided_drawn_jurors(key: &SumTreeNameType<T>, limit: u32) -> (u32, bool) {
+		if !Self::is_game_voided(key.clone()) {
+			return (0, true)
+		}
+		let mut drawn_jurors = <DrawnJurors<T>>::get(key);
+		let refunded = drawn_jurors.len().min(limit as usize);
+		for (juror, stake) in drawn_jurors.drain(..refunded) {
+			Self::return_juror_stake(key, &juror, stake);
+		}
+		if drawn_jurors.is_empty() {
+			<DrawnJurors<T>>::remove(key);
+		} else {
+			<DrawnJurors<T>>::insert(key, &drawn_jurors);
+		}
+		(refunded as u32, drawn_jurors.is_empty())
+	}
+
+	/// Return the stakes of at most `limit` jurors of the past rounds of the voided game
+	pub(super) fn refund_voided_past_round_jurors(
+		key: &SumTreeNameType<T>,
+		limit: u32,
+	) -> (u32, bool) {
+		if !Self::is_game_voided(key.clone()) {
+			return (0, true)
+		}
+		let mut loops = 0u32;
+		for round in 0..<AppealRound<T>>::get(key) {
+			let mut jurors = <PastRoundJurors<T>>::get(key, round);
+			let refunded = jurors.len().min(limit.saturating_sub(loops) as usize);
+			for (juror, stake, _) in jurors.drain(..refunded) {
+				Self::return_juror_stake(key, &juror, stake);
+			}
+			loops = loops.saturating_add(refunded as u32);
+			if !jurors.is_empty() {
+				<PastRoundJurors<T>>::insert(key, round, jurors);
+				return (loops, false)
+			}
+			<PastRoundJurors<T>>::remove(key, round);
+		}
+		(loops, true)
+	}
+
+	/// Return the appeal fees of at most `limit` appellants of the voided game from the juror stake account
+	pub(super) fn refund_voided_appellants(key: &SumTreeNameType<T>, limit: u32) -> (u32, bool) {
+		if !Self::is_game_voided(key.clone()) {
+			return (0, true)
+		}
+		let mut appellants = <Appellants<T>>::get(key);
+		let refunded = appellants.len().min(limit as usize);
+		for (appellant, fee) in appellants.drain(..refunded) {
+			let _ = T::Currency::transfer(
+				&Self::juror_stake_account(),
+				&appellant,
+				fee,
+				ExistenceRequirement::AllowDeath,
+			);
+		}
+		if appellants.is_empty() {
+			<Appellants<T>>::remove(key);
+		} else {
+			<Appellants<T>>::insert(key, &appellants);
+		}
+		(refunded as u32, appellants.is_empty())
+	}
+
+	/// Game was voided, jurors of the game already got back their stake
+	pub(super) fn is_game_voided(key: SumTreeNameType<T>) -> bool {
+		matches!(<GameResults<T>>::get(&key), Some(GameResult { outcome: GameOutcome::Voided, .. }))
+	}
+
+	/// Incentives are not distributed for voided games
+	pub(super) fn ensure_game_not_voided(key: SumTreeNameType<T>) -> DispatchResult {
+		ensure!(!Self::is_game_voided(key), Error::<T>::GameVoided);
+		Ok(())
+	}
+}
@@ -37,6 +37,21 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn do_something() -> Weight;
 	fn cause_error() -> Weight;
+	fn change_scheduled_period() -> Weight;
+	fn set_phase_data() -> Weight;
+	fn set_score_aggregation() -> Weight;
+	fn set_incentive_curve() -> Weight;
+	fn remove_game_storage() -> Weight;
+	fn commit_draw_entropy() -> Weight;
+	fn reveal_draw_entropy() -> Weight;
+	fn create_court() -> Weight;
+	fn stake_in_court() -> Weight;
+	fn unstake_from_court() -> Weight;
+	fn submit_evidence() -> Weight;
+	fn set_range_point() -> Weight;
+	fn distribute_incentives(n: u32, ) -> Weight;
+	fn delegate_stake() -> Weight;
+	fn withdraw_delegation() -> Weight;
 }
 
 /// Weights for pallet_template using the Substrate node and recommended hardware.
@@ -63,6 +78,144 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: TemplateModule GamePhaseData (r:1 w:1)
+	/// Storage: TemplateModule PeriodName (r:1 w:1)
+	/// Storage: TemplateModule VoteStartTime (r:1 w:1)
+	/// Storage: TemplateModule PeriodChangeQueue (r:1 w:1)
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:0)
+	/// Storage: SortitionSumGame TreeNodes (r:1 w:0)
+	/// Storage: SortitionSumGame TreeStakesHash (r:1 w:0)
+	/// Storage: SortitionSumGame SnapshotCount (r:1 w:1)
+	/// Storage: SortitionSumGame TreeSnapshots (r:0 w:1)
+	fn change_scheduled_period() -> Weight {
+		Weight::from_parts(20_000_000, 3000)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: TemplateModule GameTypePhaseData (r:0 w:1)
+	fn set_phase_data() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule GameTypeScoreAggregation (r:0 w:1)
+	fn set_score_aggregation() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule GameTypeIncentiveCurve (r:0 w:1)
+	fn set_incentive_curve() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: SortitionSumGame SortitionSumTrees (r:0 w:1)
+	/// Storage: TemplateModule PeriodName (r:0 w:1)
+	/// Storage: TemplateModule GamePhaseData (r:0 w:1)
+	/// And the other storage of the game without the votes (r:0 w:27)
+	fn remove_game_storage() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(30_u64))
+	}
+	/// Storage: TemplateModule PeriodName (r:1 w:0)
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:0)
+	/// Storage: TemplateModule DrawEntropyCommits (r:1 w:1)
+	fn commit_draw_entropy() -> Weight {
+		Weight::from_parts(20_000_000, 3000)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule PeriodName (r:1 w:0)
+	/// Storage: TemplateModule DrawsInRound (r:1 w:0)
+	/// Storage: TemplateModule DrawEntropyCommits (r:1 w:1)
+	/// Storage: TemplateModule DrawEntropy (r:1 w:1)
+	fn reveal_draw_entropy() -> Weight {
+		Weight::from_parts(20_000_000, 3000)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule Courts (r:2 w:1)
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	fn create_court() -> Weight {
+		Weight::from_parts(20_000_000, 3000)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule Courts (r:1 w:0)
+	/// Storage: TemplateModule JurorCourts (r:1 w:1)
+	/// Storage: TemplateModule JurorRecords (r:1 w:0)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	fn stake_in_court() -> Weight {
+		Weight::from_parts(30_000_000, 3000)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule CourtDraws (r:1 w:0)
+	/// Storage: TemplateModule JurorCourts (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	fn unstake_from_court() -> Weight {
+		Weight::from_parts(30_000_000, 3000)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule PeriodName (r:1 w:0)
+	/// Storage: TemplateModule EvidenceStartTime (r:1 w:0)
+	/// Storage: TemplateModule Evidences (r:1 w:1)
+	/// Storage: TemplateModule EvidenceReplyIds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: TemplateModule NextEvidenceId (r:1 w:1)
+	fn submit_evidence() -> Weight {
+		Weight::from_parts(30_000_000, 3000)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplateModule GameTypeRangePoint (r:0 w:1)
+	fn set_range_point() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule PeriodName (r:1 w:0)
+	/// Storage: TemplateModule GameResults (r:1 w:0)
+	/// Storage: TemplateModule IncentiveProgresses (r:1 w:1)
+	/// Storage: TemplateModule AppealRound (r:1 w:0)
+	/// Storage: TemplateModule DecisionCount (r:1 w:0)
+	/// Storage: TemplateModule DrawnJurors (r:1 w:0)
+	/// Storage: TemplateModule VoteCommits (r:100 w:0)
+	/// Storage: TemplateModule JurorRecords (r:100 w:100)
+	/// Storage: TemplateModule GameDelegations (r:100 w:0)
+	/// Storage: System Account (r:100 w:100)
+	/// The range of component `n` is `[1, 100]`.
+	fn distribute_incentives(n: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 3000)
+			.saturating_add(Weight::from_parts(20_000_000, 2500).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	/// Storage: TemplateModule Courts (r:1 w:0)
+	/// Storage: TemplateModule Delegations (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: TemplateModule JurorCourts (r:1 w:0)
+	/// Storage: TemplateModule JurorRecords (r:1 w:0)
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	fn delegate_stake() -> Weight {
+		Weight::from_parts(35_000_000, 3500)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule JurorActiveGames (r:1 w:0)
+	/// Storage: TemplateModule Delegations (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: TemplateModule JurorCourts (r:1 w:0)
+	/// Storage: TemplateModule JurorRecords (r:1 w:0)
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	fn withdraw_delegation() -> Weight {
+		Weight::from_parts(35_000_000, 3500)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -88,4 +241,142 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: TemplateModule GamePhaseData (r:1 w:1)
+	/// Storage: TemplateModule PeriodName (r:1 w:1)
+	/// Storage: TemplateModule VoteStartTime (r:1 w:1)
+	/// Storage: TemplateModule PeriodChangeQueue (r:1 w:1)
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:0)
+	/// Storage: SortitionSumGame TreeNodes (r:1 w:0)
+	/// Storage: SortitionSumGame TreeStakesHash (r:1 w:0)
+	/// Storage: SortitionSumGame SnapshotCount (r:1 w:1)
+	/// Storage: SortitionSumGame TreeSnapshots (r:0 w:1)
+	fn change_scheduled_period() -> Weight {
+		Weight::from_parts(20_000_000, 3000)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: TemplateModule GameTypePhaseData (r:0 w:1)
+	fn set_phase_data() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule GameTypeScoreAggregation (r:0 w:1)
+	fn set_score_aggregation() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule GameTypeIncentiveCurve (r:0 w:1)
+	fn set_incentive_curve() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: SortitionSumGame SortitionSumTrees (r:0 w:1)
+	/// Storage: TemplateModule PeriodName (r:0 w:1)
+	/// Storage: TemplateModule GamePhaseData (r:0 w:1)
+	/// And the other storage of the game without the votes (r:0 w:27)
+	fn remove_game_storage() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(30_u64))
+	}
+	/// Storage: TemplateModule PeriodName (r:1 w:0)
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:0)
+	/// Storage: TemplateModule DrawEntropyCommits (r:1 w:1)
+	fn commit_draw_entropy() -> Weight {
+		Weight::from_parts(20_000_000, 3000)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule PeriodName (r:1 w:0)
+	/// Storage: TemplateModule DrawsInRound (r:1 w:0)
+	/// Storage: TemplateModule DrawEntropyCommits (r:1 w:1)
+	/// Storage: TemplateModule DrawEntropy (r:1 w:1)
+	fn reveal_draw_entropy() -> Weight {
+		Weight::from_parts(20_000_000, 3000)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule Courts (r:2 w:1)
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	fn create_court() -> Weight {
+		Weight::from_parts(20_000_000, 3000)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplateModule Courts (r:1 w:0)
+	/// Storage: TemplateModule JurorCourts (r:1 w:1)
+	/// Storage: TemplateModule JurorRecords (r:1 w:0)
+	/// Storage: TemplateModule Delegations (r:1 w:0)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	fn stake_in_court() -> Weight {
+		Weight::from_parts(30_000_000, 3000)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule CourtDraws (r:1 w:0)
+	/// Storage: TemplateModule JurorCourts (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	fn unstake_from_court() -> Weight {
+		Weight::from_parts(30_000_000, 3000)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule PeriodName (r:1 w:0)
+	/// Storage: TemplateModule EvidenceStartTime (r:1 w:0)
+	/// Storage: TemplateModule Evidences (r:1 w:1)
+	/// Storage: TemplateModule EvidenceReplyIds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: TemplateModule NextEvidenceId (r:1 w:1)
+	fn submit_evidence() -> Weight {
+		Weight::from_parts(30_000_000, 3000)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplateModule GameTypeRangePoint (r:0 w:1)
+	fn set_range_point() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplateModule PeriodName (r:1 w:0)
+	/// Storage: TemplateModule GameResults (r:1 w:0)
+	/// Storage: TemplateModule IncentiveProgresses (r:1 w:1)
+	/// Storage: TemplateModule AppealRound (r:1 w:0)
+	/// Storage: TemplateModule DecisionCount (r:1 w:0)
+	/// Storage: TemplateModule DrawnJurors (r:1 w:0)
+	/// Storage: TemplateModule VoteCommits (r:100 w:0)
+	/// Storage: TemplateModule JurorRecords (r:100 w:100)
+	/// Storage: TemplateModule GameDelegations (r:100 w:0)
+	/// Storage: System Account (r:100 w:100)
+	/// The range of component `n` is `[1, 100]`.
+	fn distribute_incentives(n: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 3000)
+			.saturating_add(Weight::from_parts(20_000_000, 2500).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	/// Storage: TemplateModule Courts (r:1 w:0)
+	/// Storage: TemplateModule Delegations (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: TemplateModule JurorCourts (r:1 w:0)
+	/// Storage: TemplateModule JurorRecords (r:1 w:0)
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	fn delegate_stake() -> Weight {
+		Weight::from_parts(35_000_000, 3500)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplateModule JurorActiveGames (r:1 w:0)
+	/// Storage: TemplateModule Delegations (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: TemplateModule JurorCourts (r:1 w:0)
+	/// Storage: TemplateModule JurorRecords (r:1 w:0)
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	fn withdraw_delegation() -> Weight {
+		Weight::from_parts(35_000_000, 3500)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
@@ -15,6 +15,13 @@ impl<T: Config> SharedStorageLink for Pallet<T> {
 	fn set_positive_externality_link(address: Self::AccountId, score: i64)-> DispatchResult {
 		Self::set_positive_externality(address, score)
 	}
+
+	fn move_citizen_link(
+		old_address: Self::AccountId,
+		new_address: Self::AccountId,
+	) -> DispatchResult {
+		Self::move_citizen(old_address, new_address)
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -36,4 +43,24 @@ impl<T: Config> Pallet<T> {
 		PositiveExternalityScore::<T>::insert(address,score);
 		Ok(())
 	}
+
+	pub(super) fn move_citizen(
+		old_address: T::AccountId,
+		new_address: T::AccountId,
+	) -> DispatchResult {
+		let mut members = ApprovedCitizenAddress::<T>::get();
+		if let Ok(index) = members.binary_search(&old_address) {
+			members.remove(index);
+			match members.binary_search(&new_address) {
+				Ok(_) => Err(Error::<T>::CitizenAlreadyApproved)?,
+				Err(index) => members.insert(index, new_address.clone()),
+			}
+			ApprovedCitizenAddress::<T>::put(members);
+		}
+		let score = PositiveExternalityScore::<T>::take(&old_address);
+		if score != 0 {
+			PositiveExternalityScore::<T>::insert(new_address, score);
+		}
+		Ok(())
+	}
 }
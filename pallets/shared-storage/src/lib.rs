@@ -99,6 +99,7 @@ pub mod pallet {
 		/// Errors should have helpful documentation associated with them.
 		StorageOverflow,
 		CitizenNotApproved,
+		CitizenAlreadyApproved,
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
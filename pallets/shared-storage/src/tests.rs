@@ -1,4 +1,4 @@
-use crate::{mock::*, Error, Event};
+use crate::{mock::*, ApprovedCitizenAddress, Error, Event};
 use frame_support::{assert_noop, assert_ok};
 
 #[test]
@@ -25,3 +25,16 @@ fn correct_error_for_none_value() {
 		);
 	});
 }
+
+#[test]
+fn move_citizen_moves_approval_and_score() {
+	new_test_ext().execute_with(|| {
+		ApprovedCitizenAddress::<Test>::put(vec![1, 3, 5]);
+		assert_ok!(TemplateModule::set_positive_externality(1, 10));
+		assert_ok!(TemplateModule::move_citizen(1, 4));
+		assert_eq!(TemplateModule::approved_citizen_address(), vec![3, 4, 5]);
+		assert_eq!(TemplateModule::positive_externality_score(1), 0);
+		assert_eq!(TemplateModule::positive_externality_score(4), 10);
+		assert_noop!(TemplateModule::move_citizen(3, 5), Error::<Test>::CitizenAlreadyApproved);
+	});
+}
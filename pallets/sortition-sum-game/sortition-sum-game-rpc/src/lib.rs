@@ -0,0 +1,270 @@
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sortition_sum_game::types::SumTreeName;
+use sortition_sum_game_runtime_api::SortitionSumGameApi as SortitionSumGameRuntimeApi;
+use sp_api::codec::Codec;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use sp_runtime::Perbill;
+use std::sync::Arc;
+
+#[rpc(client, server)]
+pub trait SortitionSumGameApi<BlockHash, AccountId, BlockNumber, Hash> {
+	#[method(name = "sortitionsumgame_jurors")]
+	fn get_jurors(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		offset: u64,
+		limit: u16,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AccountId, u64)>>;
+	#[method(name = "sortitionsumgame_jurorscount")]
+	fn get_jurors_count(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		at: Option<BlockHash>,
+	) -> RpcResult<u64>;
+	#[method(name = "sortitionsumgame_totalstake")]
+	fn total_stake(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<u64>>;
+	#[method(name = "sortitionsumgame_stakeof")]
+	fn stake_of(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<u64>>;
+	#[method(name = "sortitionsumgame_drawprobability")]
+	fn draw_probability(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Perbill>>;
+	#[method(name = "sortitionsumgame_snapshotcount")]
+	fn get_snapshot_count(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		at: Option<BlockHash>,
+	) -> RpcResult<u32>;
+	#[method(name = "sortitionsumgame_snapshottotalstake")]
+	fn get_snapshot_total_stake(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		index: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<u64>>;
+	#[method(name = "sortitionsumgame_snapshotstakeof")]
+	fn get_snapshot_stake_of(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		index: u32,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<u64>>;
+	#[method(name = "sortitionsumgame_snapshothash")]
+	fn get_snapshot_hash(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		index: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Hash>>;
+}
+
+/// A struct that implements the `SortitionSumGameApi`.
+pub struct SortitionSumGame<C, M> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> SortitionSumGame<C, M> {
+	/// Create new `SortitionSumGame` instance with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The transaction was not decodable.
+	DecodeError,
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::DecodeError => 2,
+		}
+	}
+}
+
+fn map_err(error: impl ToString, desc: &'static str) -> CallError {
+	CallError::Custom(ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string())))
+}
+
+impl<C, Block, AccountId, BlockNumber, Hash>
+	SortitionSumGameApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber, Hash>
+	for SortitionSumGame<C, Block>
+where
+	Block: BlockT,
+	AccountId: Codec,
+	BlockNumber: Codec,
+	Hash: Codec,
+	C: Send + Sync + 'static,
+	C: ProvideRuntimeApi<Block>,
+	C: HeaderBackend<Block>,
+	C::Api: SortitionSumGameRuntimeApi<Block, AccountId, BlockNumber, Hash>,
+{
+	fn get_jurors(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		offset: u64,
+		limit: u16,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(AccountId, u64)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+		let runtime_api_result = api.get_jurors(at, key, offset, limit);
+		let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query dispatch info."))?;
+		Ok(res)
+	}
+
+	fn get_jurors_count(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<u64> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+		let runtime_api_result = api.get_jurors_count(at, key);
+		let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query dispatch info."))?;
+		Ok(res)
+	}
+
+	fn total_stake(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<u64>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+		let runtime_api_result = api.total_stake(at, key);
+		let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query dispatch info."))?;
+		Ok(res)
+	}
+
+	fn stake_of(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<u64>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+		let runtime_api_result = api.stake_of(at, key, who);
+		let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query dispatch info."))?;
+		Ok(res)
+	}
+
+	fn draw_probability(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Perbill>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+		let runtime_api_result = api.draw_probability(at, key, who);
+		let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query dispatch info."))?;
+		Ok(res)
+	}
+
+	fn get_snapshot_count(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<u32> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+		let runtime_api_result = api.get_snapshot_count(at, key);
+		let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query dispatch info."))?;
+		Ok(res)
+	}
+
+	fn get_snapshot_total_stake(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		index: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<u64>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+		let runtime_api_result = api.get_snapshot_total_stake(at, key, index);
+		let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query dispatch info."))?;
+		Ok(res)
+	}
+
+	fn get_snapshot_stake_of(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		index: u32,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<u64>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+		let runtime_api_result = api.get_snapshot_stake_of(at, key, index, who);
+		let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query dispatch info."))?;
+		Ok(res)
+	}
+
+	fn get_snapshot_hash(
+		&self,
+		key: SumTreeName<AccountId, BlockNumber>,
+		index: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Hash>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+		let runtime_api_result = api.get_snapshot_hash(at, key, index);
+		let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query dispatch info."))?;
+		Ok(res)
+	}
+}
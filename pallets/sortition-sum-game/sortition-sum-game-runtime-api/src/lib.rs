@@ -0,0 +1,20 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::sp_runtime::Perbill;
+use frame_support::sp_std::prelude::*;
+use sortition_sum_game::types::SumTreeName;
+use sp_api::codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	pub trait SortitionSumGameApi<AccountId, BlockNumber, Hash> where AccountId: Codec, BlockNumber: Codec, Hash: Codec {
+		fn get_jurors(key: SumTreeName<AccountId, BlockNumber>, offset: u64, limit: u16) -> Vec<(AccountId, u64)>;
+		fn get_jurors_count(key: SumTreeName<AccountId, BlockNumber>) -> u64;
+		fn total_stake(key: SumTreeName<AccountId, BlockNumber>) -> Option<u64>;
+		fn stake_of(key: SumTreeName<AccountId, BlockNumber>, who: AccountId) -> Option<u64>;
+		fn draw_probability(key: SumTreeName<AccountId, BlockNumber>, who: AccountId) -> Option<Perbill>;
+		fn get_snapshot_count(key: SumTreeName<AccountId, BlockNumber>) -> u32;
+		fn get_snapshot_total_stake(key: SumTreeName<AccountId, BlockNumber>, index: u32) -> Option<u64>;
+		fn get_snapshot_stake_of(key: SumTreeName<AccountId, BlockNumber>, index: u32, who: AccountId) -> Option<u64>;
+		fn get_snapshot_hash(key: SumTreeName<AccountId, BlockNumber>, index: u32) -> Option<Hash>;
+	}
+}
@@ -2,25 +2,54 @@
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 
+use crate::migrations::{v0, v1};
 #[allow(unused)]
 use crate::Pallet as Template;
 use frame_benchmarking::v2::*;
+use frame_support::sp_std::collections::btree_map::BTreeMap;
 use frame_system::RawOrigin;
 
+fn tree_key<T: Config>() -> SumTreeNameType<T> {
+	SumTreeName::ProfileValidation {
+		citizen_address: account("citizen", 0, 0),
+		block_number: 1u32.into(),
+	}
+}
+
+/// Tree of `n` accounts with binary nodes, the deepest tree for the number of accounts
+fn create_tree_of<T: Config>(n: u32) -> SumTreeNameType<T> {
+	let key = tree_key::<T>();
+	Template::<T>::create_tree(key.clone(), 2).unwrap();
+	for i in 0..n {
+		Template::<T>::set(key.clone(), 100, account("juror", i, 0)).unwrap();
+	}
+	key
+}
+
+/// Same tree in the layout before version 1
+fn create_v0_tree_of<T: Config>(
+	n: u32,
+) -> (SumTreeNameType<T>, v0::SortitionSumTree<AccountIdOf<T>>) {
+	let key = create_tree_of::<T>(n);
+	let tree = SortitionSumTrees::<T>::get(&key).unwrap();
+	let ids_to_node_indexes = IdsToNodeIndexes::<T>::iter_prefix(&key).collect::<BTreeMap<_, _>>();
+	let node_indexes_to_ids = NodeIndexesToIds::<T>::iter_prefix(&key).collect::<BTreeMap<_, _>>();
+	let v0_tree = v0::SortitionSumTree {
+		k: tree.k,
+		stack: Vec::new(),
+		nodes: (0..tree.nodes_len).map(|index| TreeNodes::<T>::get(&key, index)).collect(),
+		ids_to_node_indexes,
+		node_indexes_to_ids,
+	};
+	Template::<T>::remove_tree(key.clone(), u32::MAX);
+	v0::SortitionSumTrees::<T>::insert(&key, &v0_tree);
+	(key, v0_tree)
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
 
-	#[benchmark]
-	fn do_something() {
-		let value = 100u32.into();
-		let caller: T::AccountId = whitelisted_caller();
-		#[extrinsic_call]
-		do_something(RawOrigin::Signed(caller), value);
-
-		assert_eq!(Something::<T>::get(), Some(value));
-	}
-
 	#[benchmark]
 	fn cause_error() {
 		Something::<T>::put(100u32);
@@ -31,5 +60,72 @@ mod benchmarks {
 		assert_eq!(Something::<T>::get(), Some(101u32));
 	}
 
+	#[benchmark]
+	fn set(n: Linear<1, 1000>) {
+		let key = create_tree_of::<T>(n);
+		let citizen_id: T::AccountId = account("juror", n, 0);
+
+		#[block]
+		{
+			Template::<T>::set(key.clone(), 100, citizen_id.clone()).unwrap();
+		}
+
+		assert_eq!(Template::<T>::stake_of(key, citizen_id), Ok(Some(100)));
+	}
+
+	#[benchmark]
+	fn draw(n: Linear<1, 1000>) {
+		let key = create_tree_of::<T>(n);
+
+		#[block]
+		{
+			Template::<T>::draw(key.clone(), (n as u64 * 100).saturating_sub(1)).unwrap();
+		}
+	}
+
+	#[benchmark]
+	fn draw_many(n: Linear<1, 100>) {
+		let key = create_tree_of::<T>(1000);
+		let seeds = (0..n as u64).collect::<Vec<_>>();
+
+		#[block]
+		{
+			Template::<T>::draw_many(key.clone(), seeds, n).unwrap();
+		}
+
+		assert_eq!(Template::<T>::total_stake(key), Ok((1000 - n as u64) * 100));
+	}
+
+	/// Set of an account in the layout before version 1, the whole tree is written for each parent
+	#[benchmark]
+	fn set_v0(n: Linear<1, 1000>) {
+		let (key, _) = create_v0_tree_of::<T>(n);
+
+		#[block]
+		{
+			let mut tree = v0::SortitionSumTrees::<T>::get(&key).unwrap();
+			let mut parent_index = tree.nodes.len() as u64 - 1;
+			tree.nodes[parent_index as usize] += 100;
+			while parent_index != 0 {
+				parent_index = (parent_index - 1) / tree.k;
+				tree.nodes[parent_index as usize] += 100;
+				v0::SortitionSumTrees::<T>::insert(&key, &tree);
+			}
+		}
+	}
+
+	#[benchmark]
+	fn migrate_tree(n: Linear<1, 1000>) {
+		let (key, v0_tree) = create_v0_tree_of::<T>(n);
+
+		#[block]
+		{
+			let tree = v1::migrate_tree::<T>(&key, v0_tree);
+			SortitionSumTrees::<T>::insert(&key, tree);
+		}
+
+		assert_eq!(Template::<T>::total_stake(key), Ok(n as u64 * 100));
+	}
+
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
 }
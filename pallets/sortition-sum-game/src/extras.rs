@@ -1,4 +1,6 @@
 use crate::*;
+use frame_support::sp_runtime::Perbill;
+use frame_support::sp_std::{collections::btree_set::BTreeSet, vec};
 
 impl<T: Config> SortitionSumGameLink for Pallet<T> {
 	type SumTreeName = SumTreeNameType<T>;
@@ -19,30 +21,50 @@ impl<T: Config> SortitionSumGameLink for Pallet<T> {
     fn draw_link(key: Self::SumTreeName, draw_number: u64) -> Result<Self::AccountId, DispatchError> {
         Self::draw(key, draw_number)
     }
-	fn remove_tree_link(key: Self::SumTreeName) -> DispatchResult {
-		Self::remove_tree(key)
+	fn draw_many_link(
+		key: Self::SumTreeName,
+		seeds: Vec<u64>,
+		n: u32,
+	) -> Result<Vec<(Self::AccountId, u64)>, DispatchError> {
+		Self::draw_many(key, seeds, n)
+	}
+	fn total_stake_link(key: Self::SumTreeName) -> Result<u64, DispatchError> {
+		Self::total_stake(key)
+	}
+	fn stakes_link(key: Self::SumTreeName) -> Result<Vec<(Self::AccountId, u64)>, DispatchError> {
+		Self::stakes(key)
+	}
+	fn remove_tree_link(key: Self::SumTreeName, limit: u32) -> (u32, bool) {
+		Self::remove_tree(key, limit)
+	}
+	fn drain_stakes_link(
+		key: Self::SumTreeName,
+		limit: u32,
+	) -> Result<(Vec<(Self::AccountId, u64)>, bool), DispatchError> {
+		Self::drain_stakes(key, limit)
+	}
+	fn snapshot_tree_link(key: Self::SumTreeName) -> Result<u32, DispatchError> {
+		Self::snapshot_tree(key)
+	}
+	fn remove_snapshots_link(key: Self::SumTreeName, limit: u32) -> (u32, bool) {
+		Self::remove_snapshots(key, limit)
 	}
 }
 
 impl<T: Config> Pallet<T> {
 	// SortitionSumTree
 	pub fn create_tree(key: SumTreeNameType<T>, k: u64) -> DispatchResult {
-		if k < 1 {
+		if k < 2 {
 			Err(Error::<T>::KMustGreaterThanOne)?
 		}
+		// Nodes of a removed tree that are not cleared yet
+		ensure!(<TreeNodes<T>>::iter_prefix(&key).next().is_none(), Error::<T>::TreeAlreadyExists);
 		let tree_option = <SortitionSumTrees<T>>::get(&key);
 		match tree_option {
 			Some(_tree) => Err(Error::<T>::TreeAlreadyExists)?,
 			None => {
-				let mut sum_tree = SortitionSumTree {
-					k,
-					stack: Vec::new(),
-					nodes: Vec::new(),
-					ids_to_node_indexes: BTreeMap::new(),
-					node_indexes_to_ids: BTreeMap::new(),
-				};
-
-				sum_tree.nodes.push(0);
+				// Root node is the only node of a new tree
+				let sum_tree = SortitionSumTree { k, nodes_len: 1, stack_len: 0 };
 
 				<SortitionSumTrees<T>>::insert(&key, &sum_tree);
 			},
@@ -51,53 +73,62 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub fn set(key: SumTreeNameType<T>, value: u64, citizen_id: AccountIdOf<T>) -> DispatchResult {
+		// Nodes written before an error are reverted, the tree is left as it was
+		frame_support::storage::with_storage_layer(|| {
+			let old_value = Self::stake_of(key.clone(), citizen_id.clone())?.unwrap_or(0);
+			Self::set_node(key.clone(), value, citizen_id.clone())?;
+			Self::on_stake_change(&key, &citizen_id, old_value, value);
+			Ok(())
+		})
+	}
+
+	fn set_node(key: SumTreeNameType<T>, value: u64, citizen_id: AccountIdOf<T>) -> DispatchResult {
 		let tree_option = <SortitionSumTrees<T>>::get(&key);
 
 		match tree_option {
 			None => Err(Error::<T>::TreeDoesnotExist)?,
-			Some(mut tree) => match tree.ids_to_node_indexes.get(&citizen_id) {
-				Some(tree_index_data) => {
-					let tree_index = *tree_index_data;
+			Some(tree) => match <IdsToNodeIndexes<T>>::get(&key, &citizen_id) {
+				Some(tree_index) => {
 					if tree_index == 0 {
-						Self::if_tree_index_zero(value, citizen_id, tree, tree_index, key);
+						Self::if_tree_index_zero(value, citizen_id, tree, key)?;
 					} else {
 						// Existing node
+						let node_value = <TreeNodes<T>>::get(&key, tree_index);
 						if value == 0 {
-							let value = tree.nodes[tree_index as usize];
-							tree.nodes[tree_index as usize] = 0;
-							tree.stack.push(tree_index);
-							tree.ids_to_node_indexes.remove(&citizen_id);
-							tree.node_indexes_to_ids.remove(&tree_index);
+							let mut tree = tree;
+							<TreeNodes<T>>::remove(&key, tree_index);
+							<TreeStack<T>>::insert(&key, tree.stack_len, tree_index);
+							tree.stack_len =
+								tree.stack_len.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+							<SortitionSumTrees<T>>::insert(&key, &tree);
+							<IdsToNodeIndexes<T>>::remove(&key, &citizen_id);
+							<NodeIndexesToIds<T>>::remove(&key, tree_index);
 
 							// UpdateParents 🟥
-							Self::update_parents(tree, tree_index, false, value, key);
-						} else if value != tree.nodes[tree_index as usize] {
-							let plus_or_minus = tree.nodes[tree_index as usize] <= value;
+							Self::update_parents(&key, tree.k, tree_index, false, node_value)?;
+						} else if value != node_value {
+							let plus_or_minus = node_value <= value;
 							let plus_or_minus_value = if plus_or_minus {
-								value
-									.checked_sub(tree.nodes[tree_index as usize])
-									.ok_or("StorageOverflow")?
+								value.checked_sub(node_value).ok_or(Error::<T>::StorageOverflow)?
 							} else {
-								(tree.nodes[tree_index as usize])
-									.checked_sub(value)
-									.ok_or("StorageOverflow")?
+								node_value.checked_sub(value).ok_or(Error::<T>::StorageOverflow)?
 							};
-							tree.nodes[tree_index as usize] = value;
+							<TreeNodes<T>>::insert(&key, tree_index, value);
 
 							// update parents 🟥
 							Self::update_parents(
-								tree,
+								&key,
+								tree.k,
 								tree_index,
 								plus_or_minus,
 								plus_or_minus_value,
-								key,
-							);
+							)?;
 						}
 					}
 				},
 
 				None => {
-					Self::if_tree_index_zero(value, citizen_id, tree, 0, key);
+					Self::if_tree_index_zero(value, citizen_id, tree, key)?;
 				},
 			},
 		}
@@ -105,125 +136,255 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Only the ancestors of the node are read and written, `O(log_k(n))` nodes
+	/// The ancestors are written only if none of them overflows
 	fn update_parents(
-		mut tree: SortitionSumTree<AccountIdOf<T>>,
+		key: &SumTreeNameType<T>,
+		k: u64,
 		tree_index: u64,
 		plus_or_minus: bool,
 		value: u64,
-		key: SumTreeNameType<T>,
-	) {
+	) -> DispatchResult {
+		let mut parents = Vec::new();
 		let mut parent_index = tree_index;
 		while parent_index != 0 {
-			parent_index = (parent_index - 1) / tree.k;
-			tree.nodes[parent_index as usize] = if plus_or_minus {
-				(tree.nodes[parent_index as usize]).checked_add(value).expect("StorageOverflow")
-			} else {
-				(tree.nodes[parent_index as usize]).checked_sub(value).expect("StorageOverflow")
-			};
-
-			<SortitionSumTrees<T>>::insert(&key, &tree);
+			parent_index = parent_index.saturating_sub(1) / k;
+			let node = <TreeNodes<T>>::get(key, parent_index);
+			let node =
+				if plus_or_minus { node.checked_add(value) } else { node.checked_sub(value) };
+			parents.push((parent_index, node.ok_or(Error::<T>::StorageOverflow)?));
+		}
+		for (parent_index, node) in parents {
+			<TreeNodes<T>>::insert(key, parent_index, node);
 		}
+		Ok(())
 	}
+
 	fn if_tree_index_zero(
 		value: u64,
 		citizen_id: AccountIdOf<T>,
-		mut tree: SortitionSumTree<AccountIdOf<T>>,
-		mut tree_index: u64,
+		mut tree: SortitionSumTree,
 		key: SumTreeNameType<T>,
-	) {
+	) -> DispatchResult {
 		// No existing node.
 		if value != 0 {
 			// Non zero value.
 			// Append.
 			// Add node.
-			if tree.stack.len() == 0 {
+			let tree_index;
+			if tree.stack_len == 0 {
 				// No vacant spots.
 				// Get the index and append the value.
-				tree_index = tree.nodes.len() as u64;
-				tree.nodes.push(value);
-
-				// println!("{}", tree_index);
+				tree_index = tree.nodes_len;
+				<TreeNodes<T>>::insert(&key, tree_index, value);
+				tree.nodes_len =
+					tree.nodes_len.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
 
 				// Potentially append a new node and make the parent a sum node.
 				if tree_index != 1 && (tree_index - 1) % tree.k == 0 {
 					// Is first child.
 					let parent_index = tree_index / tree.k;
-					let parent_id = tree.node_indexes_to_ids.get(&parent_index).unwrap().clone();
-					let new_index = tree_index + 1;
-					tree.nodes.push(*tree.nodes.get(parent_index as usize).unwrap());
-					tree.node_indexes_to_ids.remove(&parent_index);
-					tree.ids_to_node_indexes.insert(parent_id.clone(), new_index);
-					tree.node_indexes_to_ids.insert(new_index, parent_id);
+					let parent_id = <NodeIndexesToIds<T>>::take(&key, parent_index)
+						.ok_or(Error::<T>::CorruptedTree)?;
+					let new_index = tree_index.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+					let parent_value = <TreeNodes<T>>::get(&key, parent_index);
+					<TreeNodes<T>>::insert(&key, new_index, parent_value);
+					tree.nodes_len =
+						tree.nodes_len.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+					<IdsToNodeIndexes<T>>::insert(&key, &parent_id, new_index);
+					<NodeIndexesToIds<T>>::insert(&key, new_index, parent_id);
 				}
 			} else {
-				let tree_index = tree.stack.get(tree.stack.len() - 1);
-				tree.nodes[*tree_index.unwrap() as usize] = value;
-				tree.stack.pop();
+				// Fill the last vacant leaf
+				tree.stack_len -= 1;
+				tree_index =
+					<TreeStack<T>>::take(&key, tree.stack_len).ok_or(Error::<T>::CorruptedTree)?;
+				<TreeNodes<T>>::insert(&key, tree_index, value);
 			}
+			<SortitionSumTrees<T>>::insert(&key, &tree);
 
-			tree.ids_to_node_indexes.insert(citizen_id.clone(), tree_index);
-			tree.node_indexes_to_ids.insert(tree_index, citizen_id);
+			<IdsToNodeIndexes<T>>::insert(&key, &citizen_id, tree_index);
+			<NodeIndexesToIds<T>>::insert(&key, tree_index, citizen_id);
 
 			// update_parents 🟥
 
-			Self::update_parents(tree, tree_index, true, value, key);
+			Self::update_parents(&key, tree.k, tree_index, true, value)?;
 		}
+		Ok(())
 	}
 
 	pub fn stake_of(
 		key: SumTreeNameType<T>,
 		citizen_id: AccountIdOf<T>,
 	) -> Result<Option<u64>, DispatchError> {
-		let tree_option = <SortitionSumTrees<T>>::get(&key);
-		match tree_option {
-			None => Err(Error::<T>::TreeDoesnotExist)?,
-			Some(tree) => {
-				let tree_index_data;
-				match tree.ids_to_node_indexes.get(&citizen_id) {
-					Some(v) => tree_index_data = v,
-					None => return Ok(None),
-				}
+		ensure!(<SortitionSumTrees<T>>::contains_key(&key), Error::<T>::TreeDoesnotExist);
+		Ok(Self::stake_in_nodes(&key, &citizen_id))
+	}
 
-				let value: u64;
-				let tree_index = *tree_index_data;
-				if tree_index == 0 {
-					value = 0;
-				} else {
-					value = tree.nodes[tree_index as usize];
-				}
-				Ok(Some(value))
-			},
-		}
+	/// Stake of the account read from the nodes, they are kept until the tree is fully removed
+	pub(super) fn stake_in_nodes(
+		key: &SumTreeNameType<T>,
+		citizen_id: &AccountIdOf<T>,
+	) -> Option<u64> {
+		let tree_index = <IdsToNodeIndexes<T>>::get(key, citizen_id)?;
+		let value = if tree_index == 0 { 0 } else { <TreeNodes<T>>::get(key, tree_index) };
+		Some(value)
 	}
 
+	/// Sum of the stakes in the tree, the value of the root node
+	pub fn total_stake(key: SumTreeNameType<T>) -> Result<u64, DispatchError> {
+		ensure!(<SortitionSumTrees<T>>::contains_key(&key), Error::<T>::TreeDoesnotExist);
+		Ok(<TreeNodes<T>>::get(&key, 0))
+	}
+
+	/// Accounts in the tree with their stake, accounts set to zero are not in the tree
+	pub fn stakes(key: SumTreeNameType<T>) -> Result<Vec<(AccountIdOf<T>, u64)>, DispatchError> {
+		ensure!(<SortitionSumTrees<T>>::contains_key(&key), Error::<T>::TreeDoesnotExist);
+		let mut stakes = <IdsToNodeIndexes<T>>::iter_prefix(&key)
+			.map(|(citizen_id, index)| (citizen_id, <TreeNodes<T>>::get(&key, index)))
+			.collect::<Vec<_>>();
+		stakes.sort_by(|a, b| a.0.cmp(&b.0));
+		Ok(stakes)
+	}
+
+	/// Only the nodes on the path from the root to the drawn leaf and their siblings are read
 	pub fn draw(key: SumTreeNameType<T>, draw_number: u64) -> Result<AccountIdOf<T>, DispatchError> {
 		let tree_option = <SortitionSumTrees<T>>::get(&key);
 
 		match tree_option {
 			None => Err(Error::<T>::TreeDoesnotExist)?,
 			Some(tree) => {
+				let total_stake = <TreeNodes<T>>::get(&key, 0);
+				ensure!(total_stake != 0, Error::<T>::EmptyTree);
 				let mut tree_index = 0;
-				let mut current_draw_number = draw_number % tree.nodes[0];
+				let mut current_draw_number = draw_number % total_stake;
 
-				while (tree.k * tree_index) + 1 < (tree.nodes.len() as u64) {
-					for i in 1..tree.k + 1 {
-						let node_index = (tree.k * tree_index) + i;
-						let node_value = tree.nodes[node_index as usize];
+				while Self::first_child(tree.k, tree_index) < tree.nodes_len {
+					let first_child = Self::first_child(tree.k, tree_index);
+					// Children of a consistent tree sum up to their parent, one of them is drawn
+					let mut drawn_child = None;
+					for node_index in first_child..first_child.saturating_add(tree.k) {
+						let node_value = <TreeNodes<T>>::get(&key, node_index);
 
 						if current_draw_number >= node_value {
 							current_draw_number -= node_value;
 						} else {
-							tree_index = node_index;
+							drawn_child = Some(node_index);
 							break;
 						}
 					}
+					tree_index = drawn_child.ok_or(Error::<T>::CorruptedTree)?;
 				}
-				let account_id = tree.node_indexes_to_ids.get(&tree_index).unwrap().clone();
+				let account_id = <NodeIndexesToIds<T>>::get(&key, tree_index)
+					.ok_or(Error::<T>::CorruptedTree)?;
 				Ok(account_id)
 			},
 		}
 	}
 
+	/// Index of the first child of the node, children of the node are `k` nodes from it
+	pub(super) fn first_child(k: u64, tree_index: u64) -> u64 {
+		k.saturating_mul(tree_index).saturating_add(1)
+	}
+
+	/// Draw at most `n` distinct accounts without replacement, draw `i` uses `seeds[i % seeds.len()]`
+	/// Nodes are read once, each drawn account is removed from the copy before the next draw
+	/// The drawn accounts are removed from the tree and the changed nodes are written once
+	/// Returns the accounts with their stakes before the draw, fewer than `n` if the tree has fewer accounts
+	pub fn draw_many(
+		key: SumTreeNameType<T>,
+		seeds: Vec<u64>,
+		n: u32,
+	) -> Result<Vec<(AccountIdOf<T>, u64)>, DispatchError> {
+		let mut tree = <SortitionSumTrees<T>>::get(&key).ok_or(Error::<T>::TreeDoesnotExist)?;
+		if seeds.is_empty() {
+			return Ok(Vec::new())
+		}
+		let nodes_len = usize::try_from(tree.nodes_len).map_err(|_| Error::<T>::CorruptedTree)?;
+		let mut nodes = vec![0u64; nodes_len];
+		for (index, value) in <TreeNodes<T>>::iter_prefix(&key) {
+			if let Some(node) = nodes.get_mut(index as usize) {
+				*node = value;
+			}
+		}
+		let node = |nodes: &Vec<u64>, index: u64| nodes.get(index as usize).copied().unwrap_or(0);
+
+		let mut drawn_leaves = Vec::new();
+		let mut changed_nodes = BTreeSet::new();
+		for i in 0..n as usize {
+			let total_stake = node(&nodes, 0);
+			if total_stake == 0 {
+				break
+			}
+			let mut tree_index = 0;
+			let mut current_draw_number = seeds[i % seeds.len()] % total_stake;
+			while Self::first_child(tree.k, tree_index) < tree.nodes_len {
+				let first_child = Self::first_child(tree.k, tree_index);
+				let mut drawn_child = None;
+				for node_index in first_child..first_child.saturating_add(tree.k) {
+					let node_value = node(&nodes, node_index);
+
+					if current_draw_number >= node_value {
+						current_draw_number -= node_value;
+					} else {
+						drawn_child = Some(node_index);
+						break;
+					}
+				}
+				tree_index = drawn_child.ok_or(Error::<T>::CorruptedTree)?;
+			}
+
+			let stake = node(&nodes, tree_index);
+			let mut parent_index = tree_index;
+			let mut new_values = vec![(parent_index, 0)];
+			while parent_index != 0 {
+				parent_index = parent_index.saturating_sub(1) / tree.k;
+				let parent_value = node(&nodes, parent_index)
+					.checked_sub(stake)
+					.ok_or(Error::<T>::CorruptedTree)?;
+				new_values.push((parent_index, parent_value));
+			}
+			for (index, value) in new_values {
+				if let Some(node) = nodes.get_mut(index as usize) {
+					*node = value;
+				}
+				changed_nodes.insert(index);
+			}
+			drawn_leaves.push((tree_index, stake));
+		}
+		if drawn_leaves.is_empty() {
+			return Ok(Vec::new())
+		}
+
+		// Accounts are read before anything is written, a corrupted tree is left as it was
+		let mut drawn = Vec::with_capacity(drawn_leaves.len());
+		for (tree_index, stake) in drawn_leaves {
+			let citizen_id =
+				<NodeIndexesToIds<T>>::get(&key, tree_index).ok_or(Error::<T>::CorruptedTree)?;
+			drawn.push((tree_index, citizen_id, stake));
+		}
+		let stack_len = tree.stack_len.checked_add(drawn.len() as u64);
+		tree.stack_len = stack_len.ok_or(Error::<T>::StorageOverflow)?;
+		let mut stack_index = tree.stack_len - drawn.len() as u64;
+		for (tree_index, citizen_id, _) in drawn.iter() {
+			<NodeIndexesToIds<T>>::remove(&key, tree_index);
+			<IdsToNodeIndexes<T>>::remove(&key, citizen_id);
+			<TreeStack<T>>::insert(&key, stack_index, tree_index);
+			stack_index += 1;
+		}
+		for index in changed_nodes {
+			match node(&nodes, index) {
+				0 => <TreeNodes<T>>::remove(&key, index),
+				value => <TreeNodes<T>>::insert(&key, index, value),
+			}
+		}
+		<SortitionSumTrees<T>>::insert(&key, &tree);
+		for (_, citizen_id, stake) in drawn.iter() {
+			Self::on_stake_change(&key, citizen_id, *stake, 0);
+		}
+		Ok(drawn.into_iter().map(|(_, citizen_id, stake)| (citizen_id, stake)).collect())
+	}
+
 	/**
 	 *  @dev Query the leaves of a tree. Note that if `startIndex == 0`, the tree is empty and the root node will be returned.
 	 *  @param key The key of the tree to get the leaves from.
@@ -244,26 +405,20 @@ impl<T: Config> Pallet<T> {
 			None => Err(Error::<T>::TreeDoesnotExist)?,
 			Some(tree) => {
 				let mut start_index = 0;
-				for i in 0..tree.nodes.len() {
-					if (tree.k * i as u64) + 1 >= tree.nodes.len() as u64 {
-						start_index = i as u64;
+				for i in 0..tree.nodes_len {
+					if Self::first_child(tree.k, i) >= tree.nodes_len {
+						start_index = i;
 						break;
 					}
 				}
-				let loop_start_index = start_index + cursor;
-
-				// let value = if loop_start_index + count > tree.nodes.len() as u64 {
-				// 	tree.nodes.len() as u64 - loop_start_index
-				// } else {
-				// 	count
-				// };
+				let loop_start_index = start_index.saturating_add(cursor);
 
 				let mut values = Vec::new();
 				let mut values_index = 0;
 				let mut has_more = false;
-				for j in loop_start_index..tree.nodes.len() as u64 {
+				for j in loop_start_index..tree.nodes_len {
 					if values_index < count {
-						values.push(tree.nodes[j as usize]);
+						values.push(<TreeNodes<T>>::get(&key, j));
 						values_index = values_index + 1;
 					} else {
 						has_more = true;
@@ -276,8 +431,97 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	pub fn remove_tree(key: SumTreeNameType<T>)-> DispatchResult {
+	/// Set the stake of at most `limit` accounts of the tree to zero, each one updates the parents of its leaf
+	/// Returns the accounts with their stakes before and whether no account is left in the tree
+	pub fn drain_stakes(
+		key: SumTreeNameType<T>,
+		limit: u32,
+	) -> Result<(Vec<(AccountIdOf<T>, u64)>, bool), DispatchError> {
+		ensure!(<SortitionSumTrees<T>>::contains_key(&key), Error::<T>::TreeDoesnotExist);
+		let accounts =
+			<IdsToNodeIndexes<T>>::iter_prefix(&key).take(limit as usize).collect::<Vec<_>>();
+		let mut drained = Vec::with_capacity(accounts.len());
+		for (citizen_id, tree_index) in accounts {
+			if tree_index == 0 {
+				// Account without a leaf, setting it to zero would not remove it
+				<IdsToNodeIndexes<T>>::remove(&key, &citizen_id);
+				drained.push((citizen_id, 0));
+				continue
+			}
+			let stake = <TreeNodes<T>>::get(&key, tree_index);
+			Self::set(key.clone(), 0, citizen_id.clone())?;
+			drained.push((citizen_id, stake));
+		}
+		Ok((drained, <IdsToNodeIndexes<T>>::iter_prefix(&key).next().is_none()))
+	}
+
+	/// Remove the tree and at most `limit` of its accounts and nodes, the rest are removed by the next calls
+	/// Stakes of the accounts are kept for the last snapshot, snapshots can be read after the tree is removed
+	/// Returns the number of removed items and whether all the items of the tree are removed
+	pub fn remove_tree(key: SumTreeNameType<T>, limit: u32) -> (u32, bool) {
 		<SortitionSumTrees<T>>::remove(&key);
-		Ok(())
+		<TreeStakesHash<T>>::remove(&key);
+		let mut loops = 0u32;
+		for (citizen_id, tree_index) in
+			<IdsToNodeIndexes<T>>::drain_prefix(&key).take(limit as usize)
+		{
+			let stake = if tree_index == 0 { 0 } else { <TreeNodes<T>>::get(&key, tree_index) };
+			Self::keep_stake_for_last_snapshot(&key, &citizen_id, stake);
+			loops += 1;
+		}
+		if <IdsToNodeIndexes<T>>::iter_prefix(&key).next().is_some() {
+			return (loops, false)
+		}
+		let ids = <NodeIndexesToIds<T>>::clear_prefix(&key, limit.saturating_sub(loops), None);
+		loops = loops.saturating_add(ids.loops);
+		if ids.maybe_cursor.is_some() {
+			return (loops, false)
+		}
+		let nodes = <TreeNodes<T>>::clear_prefix(&key, limit.saturating_sub(loops), None);
+		loops = loops.saturating_add(nodes.loops);
+		if nodes.maybe_cursor.is_some() {
+			return (loops, false)
+		}
+		let stack = <TreeStack<T>>::clear_prefix(&key, limit.saturating_sub(loops), None);
+		loops = loops.saturating_add(stack.loops);
+		(loops, stack.maybe_cursor.is_none())
+	}
+
+	// Runtime api
+
+	/// Accounts staked in the tree with their stake sorted by account, at most `limit` from `offset`
+	pub fn get_jurors(
+		key: SumTreeNameType<T>,
+		offset: u64,
+		limit: u16,
+	) -> Vec<(AccountIdOf<T>, u64)> {
+		Self::stakes(key)
+			.unwrap_or_default()
+			.into_iter()
+			.skip(offset as usize)
+			.take(limit as usize)
+			.collect()
+	}
+
+	pub fn get_jurors_count(key: SumTreeNameType<T>) -> u64 {
+		<IdsToNodeIndexes<T>>::iter_prefix(&key).count() as u64
+	}
+
+	pub fn get_total_stake(key: SumTreeNameType<T>) -> Option<u64> {
+		Self::total_stake(key).ok()
+	}
+
+	pub fn get_stake_of(key: SumTreeNameType<T>, who: AccountIdOf<T>) -> Option<u64> {
+		Self::stake_of(key, who).ok().flatten()
+	}
+
+	/// Odds of the account to be drawn in the next draw of the tree
+	pub fn get_draw_probability(key: SumTreeNameType<T>, who: AccountIdOf<T>) -> Option<Perbill> {
+		let total_stake = Self::total_stake(key.clone()).ok()?;
+		let stake = Self::stake_of(key, who).ok()?.unwrap_or(0);
+		if total_stake == 0 {
+			return Some(Perbill::zero())
+		}
+		Some(Perbill::from_rational(stake, total_stake))
 	}
 }
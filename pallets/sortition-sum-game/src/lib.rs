@@ -18,14 +18,19 @@ pub use weights::*;
 
 pub mod types;
 mod extras;
+mod snapshot;
+#[cfg(any(feature = "try-runtime", test))]
+mod try_state;
+pub mod migrations;
 
-use crate::types::{SortitionSumTree, SumTreeName};
-use frame_support::sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+use crate::types::{SortitionSumTree, SumTreeName, TreeSnapshot};
+use frame_support::sp_std::vec::Vec;
 use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
 use sortition_sum_game_link::SortitionSumGameLink;
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
 type SumTreeNameType<T> = SumTreeName<AccountIdOf<T>, BlockNumberOf<T>>;
+type TreeSnapshotOf<T> = TreeSnapshot<BlockNumberOf<T>, <T as frame_system::Config>::Hash>;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -33,7 +38,11 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// Nodes of the sortition sum trees are kept in `TreeNodes` since version 1
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -62,12 +71,94 @@ pub mod pallet {
 		/// Event documentation should end with an array that provides descriptive names for event
 		/// parameters. [something, who]
 		SomethingStored { something: u32, who: T::AccountId },
+		/// Snapshot `index` of the tree is taken
+		TreeSnapshotTaken { key: SumTreeNameType<T>, index: u32, stakes_hash: T::Hash },
 	}
 
 	#[pallet::storage]
 	#[pallet::getter(fn sortition_sum_trees)]
 	pub type SortitionSumTrees<T> =
-		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, SortitionSumTree<AccountIdOf<T>>>;
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, SortitionSumTree>;
+
+	/// Value of the node of the tree: the stake of a leaf, the sum of the children of the other nodes
+	#[pallet::storage]
+	#[pallet::getter(fn tree_node)]
+	pub type TreeNodes<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Twox64Concat,
+		u64,
+		u64,
+		ValueQuery,
+	>;
+
+	/// Vacant leaves of the tree by their position in the stack, the last one is filled first
+	#[pallet::storage]
+	#[pallet::getter(fn tree_stack)]
+	pub type TreeStack<T> =
+		StorageDoubleMap<_, Blake2_128Concat, SumTreeNameType<T>, Twox64Concat, u64, u64>;
+
+	/// Leaf of the account in the tree
+	#[pallet::storage]
+	#[pallet::getter(fn ids_to_node_indexes)]
+	pub type IdsToNodeIndexes<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		u64,
+	>;
+
+	/// Account of the leaf of the tree
+	#[pallet::storage]
+	#[pallet::getter(fn node_indexes_to_ids)]
+	pub type NodeIndexesToIds<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Twox64Concat,
+		u64,
+		AccountIdOf<T>,
+	>;
+
+	/// Snapshots of the tree by their index, the first snapshot has index 0
+	#[pallet::storage]
+	#[pallet::getter(fn tree_snapshot)]
+	pub type TreeSnapshots<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Twox64Concat,
+		u32,
+		TreeSnapshotOf<T>,
+	>;
+
+	/// Number of snapshots taken of the tree
+	#[pallet::storage]
+	#[pallet::getter(fn snapshot_count)]
+	pub type SnapshotCount<T> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, u32, ValueQuery>;
+
+	/// Stake of the account at the snapshot, kept on the first change of the stake after the snapshot
+	/// Stakes not changed after the snapshot are read from the next snapshots or the tree
+	#[pallet::storage]
+	#[pallet::getter(fn snapshot_stake)]
+	pub type SnapshotStakes<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Blake2_128Concat,
+		(u32, AccountIdOf<T>),
+		u64,
+	>;
+
+	/// Hash of all the changes of the stakes of the tree, updated on every change
+	#[pallet::storage]
+	#[pallet::getter(fn tree_stakes_hash)]
+	pub type TreeStakesHash<T: Config> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, T::Hash, ValueQuery>;
 
 	#[pallet::error]
 	pub enum Error<T> {
@@ -76,6 +167,19 @@ pub mod pallet {
 		KMustGreaterThanOne,
 		TreeAlreadyExists,
 		TreeDoesnotExist,
+		SnapshotDoesNotExist,
+		/// Nodes or accounts of the tree do not match, the tree is left as it was
+		CorruptedTree,
+		/// Tree has no stake to draw from
+		EmptyTree,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -0,0 +1,106 @@
+use crate::*;
+
+/// Layout of the sortition sum trees before version 1, the whole tree is kept in one value
+pub mod v0 {
+	use super::*;
+	use frame_support::sp_std::collections::btree_map::BTreeMap;
+	use scale_info::TypeInfo;
+
+	#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct SortitionSumTree<AccountId> {
+		pub k: u64,
+		pub stack: Vec<u64>,
+		pub nodes: Vec<u64>,
+		pub ids_to_node_indexes: BTreeMap<AccountId, u64>, // citizen id, node index
+		pub node_indexes_to_ids: BTreeMap<u64, AccountId>, // node index, citizen id
+	}
+
+	#[frame_support::storage_alias]
+	pub type SortitionSumTrees<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		SortitionSumTree<AccountIdOf<T>>,
+	>;
+}
+
+/// Move the nodes and the accounts of the sortition sum trees to their own storage
+pub mod v1 {
+	use super::*;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version >= 1 {
+				return T::DbWeight::get().reads(1)
+			}
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			SortitionSumTrees::<T>::translate::<v0::SortitionSumTree<AccountIdOf<T>>, _>(
+				|key, tree| {
+					weight =
+						weight.saturating_add(T::WeightInfo::migrate_tree(tree.nodes.len() as u32));
+					Some(migrate_tree::<T>(&key, tree))
+				},
+			);
+			StorageVersion::new(1).put::<Pallet<T>>();
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let trees = v0::SortitionSumTrees::<T>::iter_keys().count() as u64;
+			Ok(trees.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let trees: u64 =
+				Decode::decode(&mut &state[..]).map_err(|_| "Failed to decode tree count")?;
+			ensure!(
+				SortitionSumTrees::<T>::iter_keys().count() as u64 == trees,
+				"Sortition sum trees are lost in the migration"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == StorageVersion::new(1),
+				"Storage version is not updated"
+			);
+			Ok(())
+		}
+	}
+
+	/// Write the nodes, the vacant leaves and the accounts of the tree to their own storage
+	/// Accounts kept at index 0 and leaves without accounts were left by filling a vacant leaf before version 1,
+	/// their stake is not in the sums of the parents, so the leaves are made vacant again
+	pub(crate) fn migrate_tree<T: Config>(
+		key: &SumTreeNameType<T>,
+		tree: v0::SortitionSumTree<AccountIdOf<T>>,
+	) -> SortitionSumTree {
+		let nodes_len = tree.nodes.len() as u64;
+		let mut stack = tree.stack;
+		for (index, citizen_id) in tree.node_indexes_to_ids.into_iter() {
+			if index != 0 && tree.ids_to_node_indexes.get(&citizen_id) == Some(&index) {
+				<IdsToNodeIndexes<T>>::insert(key, &citizen_id, index);
+				<NodeIndexesToIds<T>>::insert(key, index, citizen_id);
+			}
+		}
+		for (index, value) in tree.nodes.into_iter().enumerate() {
+			let index = index as u64;
+			let is_leaf = index != 0 && tree.k.saturating_mul(index).saturating_add(1) >= nodes_len;
+			if is_leaf && !<NodeIndexesToIds<T>>::contains_key(key, index) {
+				if !stack.contains(&index) {
+					stack.push(index);
+				}
+			} else if value != 0 {
+				<TreeNodes<T>>::insert(key, index, value);
+			}
+		}
+		for (position, index) in stack.iter().enumerate() {
+			<TreeStack<T>>::insert(key, position as u64, index);
+		}
+		SortitionSumTree { k: tree.k, nodes_len, stack_len: stack.len() as u64 }
+	}
+}
@@ -0,0 +1,106 @@
+use crate::*;
+use frame_support::sp_runtime::traits::Hash;
+
+impl<T: Config> Pallet<T> {
+	/// Keep the total stake of the tree and the hash of the changes of its stakes, returns the index of the snapshot
+	/// Stakes at the snapshot are kept by `set` and `draw_many` when they change after it, the snapshot is `O(1)`
+	pub fn snapshot_tree(key: SumTreeNameType<T>) -> Result<u32, DispatchError> {
+		ensure!(<SortitionSumTrees<T>>::contains_key(&key), Error::<T>::TreeDoesnotExist);
+		let stakes_hash = <TreeStakesHash<T>>::get(&key);
+		let index = <SnapshotCount<T>>::get(&key);
+		let next_index = index.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+		let snapshot = TreeSnapshot {
+			block_number: <frame_system::Pallet<T>>::block_number(),
+			total_stake: <TreeNodes<T>>::get(&key, 0),
+			stakes_hash,
+		};
+		<TreeSnapshots<T>>::insert(&key, index, snapshot);
+		<SnapshotCount<T>>::insert(&key, next_index);
+		Self::deposit_event(Event::TreeSnapshotTaken { key, index, stakes_hash });
+		Ok(index)
+	}
+
+	/// Stake of the account is changed from `old_value` to `value`
+	/// The old stake is kept for the last snapshot on the first change after it
+	pub(super) fn on_stake_change(
+		key: &SumTreeNameType<T>,
+		citizen_id: &AccountIdOf<T>,
+		old_value: u64,
+		value: u64,
+	) {
+		if old_value == value {
+			return
+		}
+		Self::keep_stake_for_last_snapshot(key, citizen_id, old_value);
+		<TreeStakesHash<T>>::mutate(key, |hash| {
+			*hash = T::Hashing::hash_of(&(*hash, citizen_id, value));
+		});
+	}
+
+	/// Keep the stake of the account for the last snapshot, unless a stake is already kept for it
+	pub(super) fn keep_stake_for_last_snapshot(
+		key: &SumTreeNameType<T>,
+		citizen_id: &AccountIdOf<T>,
+		stake: u64,
+	) {
+		if let Some(index) = <SnapshotCount<T>>::get(key).checked_sub(1) {
+			let snapshot_key = (index, citizen_id.clone());
+			if !<SnapshotStakes<T>>::contains_key(key, &snapshot_key) {
+				<SnapshotStakes<T>>::insert(key, snapshot_key, stake);
+			}
+		}
+	}
+
+	/// Stake of the account at the snapshot, `None` if the account had no stake
+	/// It is the stake kept on the first change after the snapshot or a later snapshot, else the stake in the tree
+	pub fn stake_at_snapshot(
+		key: SumTreeNameType<T>,
+		index: u32,
+		citizen_id: AccountIdOf<T>,
+	) -> Result<Option<u64>, DispatchError> {
+		ensure!(<TreeSnapshots<T>>::contains_key(&key, index), Error::<T>::SnapshotDoesNotExist);
+		let stake = (index..<SnapshotCount<T>>::get(&key))
+			.find_map(|later| <SnapshotStakes<T>>::get(&key, (later, citizen_id.clone())))
+			.or_else(|| Self::stake_in_nodes(&key, &citizen_id));
+		Ok(stake.filter(|stake| *stake != 0))
+	}
+
+	/// Remove at most `limit` snapshots and kept stakes of the tree
+	/// Returns the number of removed items and whether all of them are removed
+	pub fn remove_snapshots(key: SumTreeNameType<T>, limit: u32) -> (u32, bool) {
+		let snapshots = <TreeSnapshots<T>>::clear_prefix(&key, limit, None);
+		let limit = limit.saturating_sub(snapshots.loops);
+		if snapshots.maybe_cursor.is_some() || limit == 0 {
+			return (snapshots.loops, false)
+		}
+		let stakes = <SnapshotStakes<T>>::clear_prefix(&key, limit, None);
+		let loops = snapshots.loops.saturating_add(stakes.loops);
+		if stakes.maybe_cursor.is_some() {
+			return (loops, false)
+		}
+		<SnapshotCount<T>>::remove(&key);
+		(loops, true)
+	}
+
+	// Runtime api
+
+	pub fn get_snapshot_count(key: SumTreeNameType<T>) -> u32 {
+		<SnapshotCount<T>>::get(&key)
+	}
+
+	pub fn get_snapshot_total_stake(key: SumTreeNameType<T>, index: u32) -> Option<u64> {
+		<TreeSnapshots<T>>::get(&key, index).map(|snapshot| snapshot.total_stake)
+	}
+
+	pub fn get_snapshot_stake_of(
+		key: SumTreeNameType<T>,
+		index: u32,
+		who: AccountIdOf<T>,
+	) -> Option<u64> {
+		Self::stake_at_snapshot(key, index, who).ok().flatten()
+	}
+
+	pub fn get_snapshot_hash(key: SumTreeNameType<T>, index: u32) -> Option<T::Hash> {
+		<TreeSnapshots<T>>::get(&key, index).map(|snapshot| snapshot.stakes_hash)
+	}
+}
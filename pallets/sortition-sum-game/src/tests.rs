@@ -1,5 +1,16 @@
-use crate::{mock::*, types::SumTreeName, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	migrations::{v0, v1::MigrateToV1},
+	mock::*,
+	types::SumTreeName,
+	Error, Event, NodeIndexesToIds, TreeNodes,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use proptest::prelude::*;
+use sp_runtime::{traits::Hash, Perbill};
+use std::collections::BTreeMap;
 
 #[test]
 fn it_works_for_default_value() {
@@ -40,3 +51,393 @@ fn correct_error_for_none_value() {
 		println!("{:?}", data);
 	});
 }
+
+#[test]
+fn set_fills_the_vacant_leaf() {
+	new_test_ext().execute_with(|| {
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 10 };
+		assert_ok!(TemplateModule::create_tree(key.clone(), 2));
+		assert_ok!(TemplateModule::set(key.clone(), 10, 1));
+		assert_ok!(TemplateModule::set(key.clone(), 20, 2));
+		assert_ok!(TemplateModule::set(key.clone(), 30, 3));
+		let index = TemplateModule::ids_to_node_indexes(key.clone(), 2).unwrap();
+		assert_ok!(TemplateModule::set(key.clone(), 0, 2));
+		assert_eq!(TemplateModule::stake_of(key.clone(), 2), Ok(None));
+		assert_eq!(TemplateModule::total_stake(key.clone()), Ok(40));
+		assert_ok!(TemplateModule::set(key.clone(), 50, 4));
+		assert_eq!(TemplateModule::ids_to_node_indexes(key.clone(), 4), Some(index));
+		assert_eq!(TemplateModule::node_indexes_to_ids(key.clone(), index), Some(4));
+		assert_eq!(TemplateModule::stake_of(key.clone(), 4), Ok(Some(50)));
+		assert_eq!(TemplateModule::total_stake(key.clone()), Ok(90));
+		assert_eq!(TemplateModule::stakes(key.clone()), Ok(vec![(1, 10), (3, 30), (4, 50)]));
+		assert_eq!(TemplateModule::tree_stack(key.clone(), 0), None);
+		assert!(TemplateModule::remove_tree(key.clone(), u32::MAX).1);
+		assert_eq!(TemplateModule::tree_node(key.clone(), 0), 0);
+		assert_eq!(TemplateModule::ids_to_node_indexes(key.clone(), 4), None);
+		assert_noop!(TemplateModule::total_stake(key), Error::<Test>::TreeDoesnotExist);
+	});
+}
+
+#[test]
+fn remove_tree_removes_at_most_limit_items() {
+	new_test_ext().execute_with(|| {
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 10 };
+		assert_ok!(TemplateModule::create_tree(key.clone(), 2));
+		assert_ok!(TemplateModule::set(key.clone(), 10, 1));
+		assert_ok!(TemplateModule::set(key.clone(), 20, 2));
+		assert_ok!(TemplateModule::set(key.clone(), 30, 3));
+		assert_eq!(TemplateModule::remove_tree(key.clone(), 2), (2, false));
+		assert_noop!(TemplateModule::total_stake(key.clone()), Error::<Test>::TreeDoesnotExist);
+		assert_noop!(TemplateModule::create_tree(key.clone(), 2), Error::<Test>::TreeAlreadyExists);
+		let mut calls = 1;
+		while !TemplateModule::remove_tree(key.clone(), 2).1 {
+			calls += 1;
+		}
+		// 3 accounts with their leaves and the nodes of the tree
+		assert!(calls >= 4);
+		assert_eq!(TemplateModule::tree_node(key.clone(), 0), 0);
+		assert_eq!(TemplateModule::ids_to_node_indexes(key.clone(), 3), None);
+		assert_noop!(TemplateModule::total_stake(key.clone()), Error::<Test>::TreeDoesnotExist);
+		assert_eq!(TemplateModule::remove_tree(key.clone(), 2), (0, true));
+		assert_ok!(TemplateModule::create_tree(key, 2));
+	});
+}
+
+#[test]
+fn migrate_tree_to_per_node_storage() {
+	new_test_ext().execute_with(|| {
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 10 };
+		let tree = v0::SortitionSumTree {
+			k: 2,
+			stack: vec![],
+			nodes: vec![60, 40, 20, 30, 10],
+			ids_to_node_indexes: [(1, 4), (2, 2), (3, 3)].into_iter().collect(),
+			node_indexes_to_ids: [(2, 2), (3, 3), (4, 1)].into_iter().collect(),
+		};
+		v0::SortitionSumTrees::<Test>::insert(&key, tree);
+		StorageVersion::new(0).put::<TemplateModule>();
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), StorageVersion::new(1));
+		assert_eq!(TemplateModule::stake_of(key.clone(), 1), Ok(Some(10)));
+		assert_eq!(TemplateModule::stake_of(key.clone(), 2), Ok(Some(20)));
+		assert_eq!(TemplateModule::stake_of(key.clone(), 3), Ok(Some(30)));
+		assert_eq!(TemplateModule::total_stake(key.clone()), Ok(60));
+		assert_eq!(TemplateModule::draw(key.clone(), 0), Ok(3));
+		assert_eq!(TemplateModule::draw(key.clone(), 35), Ok(1));
+		assert_eq!(TemplateModule::draw(key.clone(), 45), Ok(2));
+		assert_ok!(TemplateModule::set(key.clone(), 5, 4));
+		assert_eq!(TemplateModule::total_stake(key), Ok(65));
+	});
+}
+
+/// Pseudo random number of the test case `i`
+fn random_number(i: u64) -> u64 {
+	let hash = sp_core::hashing::blake2_256(&i.to_le_bytes());
+	u64::from_le_bytes(hash[..8].try_into().unwrap())
+}
+
+/// Tree of `accounts` accounts with pseudo random stakes, accounts start from 1
+fn create_random_tree(
+	key: &SumTreeName<u64, u64>,
+	k: u64,
+	accounts: u64,
+	case: u64,
+) -> Vec<(u64, u64)> {
+	assert_ok!(TemplateModule::create_tree(key.clone(), k));
+	let mut stakes = vec![];
+	for citizen_id in 1..=accounts {
+		let stake = random_number(case * 1000 + citizen_id) % 1000 + 1;
+		assert_ok!(TemplateModule::set(key.clone(), stake, citizen_id));
+		stakes.push((citizen_id, stake));
+	}
+	stakes
+}
+
+#[test]
+fn draw_many_draws_distinct_accounts_with_their_stakes() {
+	new_test_ext().execute_with(|| {
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 10 };
+		for case in 0..200 {
+			let k = case % 4 + 2;
+			let accounts = random_number(case) % 20 + 1;
+			let n = (random_number(case + 1) % (accounts + 3)) as u32;
+			let stakes = create_random_tree(&key, k, accounts, case);
+			let total = TemplateModule::total_stake(key.clone()).unwrap();
+			let seeds = (0..3).map(|i| random_number(case * 10 + i)).collect();
+
+			let drawn = TemplateModule::draw_many(key.clone(), seeds, n).unwrap();
+
+			assert_eq!(drawn.len() as u64, accounts.min(n as u64));
+			let mut drawn_ids = drawn.iter().map(|(citizen_id, _)| *citizen_id).collect::<Vec<_>>();
+			drawn_ids.sort();
+			drawn_ids.dedup();
+			assert_eq!(drawn_ids.len(), drawn.len());
+			for (citizen_id, stake) in drawn.iter() {
+				assert_eq!(stakes[*citizen_id as usize - 1].1, *stake);
+				assert_eq!(TemplateModule::stake_of(key.clone(), *citizen_id), Ok(None));
+			}
+			let remaining = stakes
+				.iter()
+				.filter(|(citizen_id, _)| !drawn_ids.contains(citizen_id))
+				.cloned()
+				.collect::<Vec<_>>();
+			assert_eq!(TemplateModule::stakes(key.clone()), Ok(remaining));
+			let drawn_stake = drawn.iter().map(|(_, stake)| stake).sum::<u64>();
+			assert_eq!(TemplateModule::total_stake(key.clone()), Ok(total - drawn_stake));
+			assert!(TemplateModule::remove_tree(key.clone(), u32::MAX).1);
+		}
+	});
+}
+
+#[test]
+fn draw_many_draws_the_accounts_of_draw_and_set() {
+	new_test_ext().execute_with(|| {
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 10 };
+		let other_key = SumTreeName::ProfileValidation { citizen_address: 2, block_number: 10 };
+		for case in 0..100 {
+			let k = case % 3 + 2;
+			let accounts = random_number(case) % 15 + 1;
+			create_random_tree(&key, k, accounts, case);
+			create_random_tree(&other_key, k, accounts, case);
+			let seeds = (0..accounts).map(|i| random_number(case * 100 + i)).collect::<Vec<_>>();
+
+			let drawn =
+				TemplateModule::draw_many(key.clone(), seeds.clone(), accounts as u32).unwrap();
+
+			for (seed, (citizen_id, stake)) in seeds.into_iter().zip(drawn) {
+				assert_eq!(TemplateModule::draw(other_key.clone(), seed), Ok(citizen_id));
+				assert_eq!(
+					TemplateModule::stake_of(other_key.clone(), citizen_id),
+					Ok(Some(stake))
+				);
+				assert_ok!(TemplateModule::set(other_key.clone(), 0, citizen_id));
+			}
+			assert_eq!(TemplateModule::total_stake(key.clone()), Ok(0));
+			assert_eq!(TemplateModule::draw_many(key.clone(), vec![1], 1), Ok(vec![]));
+			assert!(TemplateModule::remove_tree(key.clone(), u32::MAX).1);
+			assert!(TemplateModule::remove_tree(other_key.clone(), u32::MAX).1);
+		}
+	});
+}
+
+#[test]
+fn draw_many_selection_is_proportional_to_stake() {
+	new_test_ext().execute_with(|| {
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 10 };
+		let stakes = [(1, 100), (2, 200), (3, 300), (4, 400), (5, 1000)];
+		let total = 2000;
+		let draws = 5000;
+		let mut times_drawn = [0u64; 5];
+		for case in 0..draws {
+			assert_ok!(TemplateModule::create_tree(key.clone(), 2));
+			for (citizen_id, stake) in stakes {
+				assert_ok!(TemplateModule::set(key.clone(), stake, citizen_id));
+			}
+			let drawn = TemplateModule::draw_many(key.clone(), vec![random_number(case)], 1);
+			times_drawn[drawn.unwrap()[0].0 as usize - 1] += 1;
+			assert!(TemplateModule::remove_tree(key.clone(), u32::MAX).1);
+		}
+		for (index, (_, stake)) in stakes.iter().enumerate() {
+			let expected = draws * stake / total;
+			// Within 3% of the draws, more than 8 standard deviations
+			assert!(times_drawn[index].abs_diff(expected) < draws * 3 / 100);
+		}
+	});
+}
+
+#[test]
+fn snapshot_keeps_the_stakes_of_the_tree() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 10 };
+		assert_noop!(TemplateModule::snapshot_tree(key.clone()), Error::<Test>::TreeDoesnotExist);
+		assert_ok!(TemplateModule::create_tree(key.clone(), 2));
+		assert_ok!(TemplateModule::set(key.clone(), 10, 1));
+		assert_ok!(TemplateModule::set(key.clone(), 20, 2));
+		assert_ok!(TemplateModule::set(key.clone(), 30, 3));
+		let stakes_hash = [(1u64, 10u64), (2, 20), (3, 30)].iter().fold(
+			<Test as frame_system::Config>::Hash::default(),
+			|hash, (who, stake)| {
+				<Test as frame_system::Config>::Hashing::hash_of(&(hash, who, stake))
+			},
+		);
+
+		assert_eq!(TemplateModule::snapshot_tree(key.clone()), Ok(0));
+		System::assert_last_event(
+			Event::TreeSnapshotTaken { key: key.clone(), index: 0, stakes_hash }.into(),
+		);
+		assert_ok!(TemplateModule::set(key.clone(), 0, 2));
+		assert_ok!(TemplateModule::set(key.clone(), 40, 4));
+		assert_eq!(TemplateModule::draw_many(key.clone(), vec![0], 1), Ok(vec![(3, 30)]));
+		assert_eq!(TemplateModule::snapshot_tree(key.clone()), Ok(1));
+
+		// Only the stakes changed after the snapshot are kept
+		assert_eq!(TemplateModule::snapshot_stake(key.clone(), (0, 1)), None);
+		assert_eq!(TemplateModule::snapshot_stake(key.clone(), (0, 2)), Some(20));
+		assert_eq!(TemplateModule::snapshot_stake(key.clone(), (0, 3)), Some(30));
+		assert_eq!(TemplateModule::snapshot_stake(key.clone(), (0, 4)), Some(0));
+		assert_eq!(TemplateModule::get_snapshot_count(key.clone()), 2);
+		assert_eq!(TemplateModule::get_snapshot_total_stake(key.clone(), 0), Some(60));
+		assert_eq!(TemplateModule::get_snapshot_hash(key.clone(), 0), Some(stakes_hash));
+		assert_eq!(TemplateModule::stake_at_snapshot(key.clone(), 0, 1), Ok(Some(10)));
+		assert_eq!(TemplateModule::stake_at_snapshot(key.clone(), 0, 2), Ok(Some(20)));
+		assert_eq!(TemplateModule::stake_at_snapshot(key.clone(), 0, 4), Ok(None));
+		assert_eq!(TemplateModule::get_snapshot_total_stake(key.clone(), 1), Some(70));
+		assert_eq!(TemplateModule::stake_at_snapshot(key.clone(), 1, 3), Ok(None));
+		assert_eq!(TemplateModule::stake_at_snapshot(key.clone(), 1, 4), Ok(Some(40)));
+		assert_noop!(
+			TemplateModule::stake_at_snapshot(key.clone(), 2, 1),
+			Error::<Test>::SnapshotDoesNotExist
+		);
+
+		// Snapshots are kept after the tree is removed
+		assert!(TemplateModule::remove_tree(key.clone(), u32::MAX).1);
+		assert_eq!(TemplateModule::get_snapshot_stake_of(key.clone(), 0, 3), Some(30));
+		assert_eq!(TemplateModule::get_snapshot_stake_of(key.clone(), 0, 1), Some(10));
+		assert_eq!(TemplateModule::get_snapshot_stake_of(key.clone(), 1, 4), Some(40));
+		// 2 snapshots, 3 stakes kept for the first one and 2 for the last one when the tree is removed
+		assert_eq!(TemplateModule::remove_snapshots(key.clone(), 2), (2, false));
+		assert_eq!(TemplateModule::remove_snapshots(key.clone(), 10), (5, true));
+		assert_eq!(TemplateModule::get_snapshot_count(key.clone()), 0);
+		assert_eq!(TemplateModule::get_snapshot_hash(key, 0), None);
+	});
+}
+
+#[test]
+fn runtime_api_lists_jurors_and_draw_probability() {
+	new_test_ext().execute_with(|| {
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 10 };
+		assert_eq!(TemplateModule::get_total_stake(key.clone()), None);
+		assert_eq!(TemplateModule::get_draw_probability(key.clone(), 1), None);
+		assert!(TemplateModule::get_jurors(key.clone(), 0, 10).is_empty());
+		assert_ok!(TemplateModule::create_tree(key.clone(), 3));
+		assert_eq!(TemplateModule::get_draw_probability(key.clone(), 1), Some(Perbill::zero()));
+		for (citizen_id, stake) in [(4, 400), (2, 200), (1, 100), (3, 300)] {
+			assert_ok!(TemplateModule::set(key.clone(), stake, citizen_id));
+		}
+
+		assert_eq!(TemplateModule::get_jurors_count(key.clone()), 4);
+		assert_eq!(
+			TemplateModule::get_jurors(key.clone(), 0, 3),
+			vec![(1, 100), (2, 200), (3, 300)]
+		);
+		assert_eq!(TemplateModule::get_jurors(key.clone(), 3, 3), vec![(4, 400)]);
+		assert_eq!(TemplateModule::get_total_stake(key.clone()), Some(1000));
+		assert_eq!(TemplateModule::get_stake_of(key.clone(), 3), Some(300));
+		assert_eq!(TemplateModule::get_stake_of(key.clone(), 5), None);
+		assert_eq!(
+			TemplateModule::get_draw_probability(key.clone(), 4),
+			Some(Perbill::from_percent(40))
+		);
+		assert_eq!(TemplateModule::get_draw_probability(key.clone(), 5), Some(Perbill::zero()));
+	});
+}
+
+#[derive(Clone, Debug)]
+enum TreeOperation {
+	Set(u64, u64),
+	Draw(u64),
+	DrawMany(Vec<u64>, u32),
+}
+
+fn tree_operation() -> impl Strategy<Value = TreeOperation> {
+	let value = prop_oneof![Just(0u64), 1u64..1000, Just(u64::MAX / 3)];
+	prop_oneof![
+		4 => (0u64..20, value).prop_map(|(citizen_id, value)| TreeOperation::Set(citizen_id, value)),
+		1 => any::<u64>().prop_map(TreeOperation::Draw),
+		1 => (prop::collection::vec(any::<u64>(), 0..4), 0u32..5)
+			.prop_map(|(seeds, n)| TreeOperation::DrawMany(seeds, n)),
+	]
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(128))]
+
+	/// Stakes of the tree follow a model of the accounts after every operation and the invariants hold
+	#[test]
+	fn random_set_and_draw_keep_the_tree_invariants(
+		k in 2u64..6,
+		operations in prop::collection::vec(tree_operation(), 1..80),
+	) {
+		new_test_ext().execute_with(|| {
+			let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 10 };
+			assert_ok!(TemplateModule::create_tree(key.clone(), k));
+			let mut stakes = BTreeMap::new();
+			for operation in operations {
+				match operation {
+					TreeOperation::Set(citizen_id, value) => {
+						match TemplateModule::set(key.clone(), value, citizen_id) {
+							Ok(()) if value == 0 => {
+								stakes.remove(&citizen_id);
+							},
+							Ok(()) => {
+								stakes.insert(citizen_id, value);
+							},
+							Err(error) => assert_eq!(error, Error::<Test>::StorageOverflow.into()),
+						}
+					},
+					TreeOperation::Draw(draw_number) => match TemplateModule::draw(key.clone(), draw_number) {
+						Ok(citizen_id) => assert!(stakes.contains_key(&citizen_id)),
+						Err(error) => {
+							assert!(stakes.is_empty());
+							assert_eq!(error, Error::<Test>::EmptyTree.into());
+						},
+					},
+					TreeOperation::DrawMany(seeds, n) => {
+						let expected = if seeds.is_empty() { 0 } else { stakes.len().min(n as usize) };
+						let drawn = TemplateModule::draw_many(key.clone(), seeds, n).unwrap();
+						assert_eq!(drawn.len(), expected);
+						for (citizen_id, stake) in drawn {
+							assert_eq!(stakes.remove(&citizen_id), Some(stake));
+						}
+					},
+				}
+				assert_ok!(TemplateModule::do_try_state());
+				let total_stake = stakes.values().sum::<u64>();
+				assert_eq!(TemplateModule::total_stake(key.clone()), Ok(total_stake));
+				assert_eq!(
+					TemplateModule::stakes(key.clone()),
+					Ok(stakes.clone().into_iter().collect::<Vec<_>>())
+				);
+			}
+		});
+	}
+}
+
+#[test]
+fn corrupted_or_empty_tree_returns_errors() {
+	new_test_ext().execute_with(|| {
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 10 };
+		assert_noop!(
+			TemplateModule::create_tree(key.clone(), 1),
+			Error::<Test>::KMustGreaterThanOne
+		);
+		assert_ok!(TemplateModule::create_tree(key.clone(), 2));
+		assert_noop!(TemplateModule::draw(key.clone(), 5), Error::<Test>::EmptyTree);
+		assert_eq!(TemplateModule::draw_many(key.clone(), vec![5], 2), Ok(vec![]));
+		assert_ok!(TemplateModule::set(key.clone(), u64::MAX - 10, 1));
+		assert_noop!(TemplateModule::set(key.clone(), 20, 2), Error::<Test>::StorageOverflow);
+		assert_ok!(TemplateModule::set(key.clone(), 10, 1));
+		assert_ok!(TemplateModule::set(key.clone(), 20, 2));
+		assert_ok!(TemplateModule::set(key.clone(), 30, 3));
+		assert_ok!(TemplateModule::do_try_state());
+
+		// Root is more than the sum of the leaves, no leaf is found for the draw
+		TreeNodes::<Test>::insert(&key, 0, 100);
+		assert!(TemplateModule::do_try_state().is_err());
+		assert_noop!(TemplateModule::draw(key.clone(), 99), Error::<Test>::CorruptedTree);
+		TreeNodes::<Test>::insert(&key, 0, 60);
+
+		// Drawn leaf has no account
+		let index = TemplateModule::ids_to_node_indexes(key.clone(), 3).unwrap();
+		NodeIndexesToIds::<Test>::remove(&key, index);
+		assert!(TemplateModule::do_try_state().is_err());
+		assert_noop!(TemplateModule::draw(key.clone(), 59), Error::<Test>::CorruptedTree);
+		assert_noop!(
+			TemplateModule::draw_many(key.clone(), vec![59], 3),
+			Error::<Test>::CorruptedTree
+		);
+	});
+}
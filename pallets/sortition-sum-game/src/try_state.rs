@@ -0,0 +1,74 @@
+use crate::*;
+use frame_support::sp_std::collections::btree_set::BTreeSet;
+
+impl<T: Config> Pallet<T> {
+	/// Check the invariants of all the trees
+	pub fn do_try_state() -> Result<(), &'static str> {
+		for (key, tree) in <SortitionSumTrees<T>>::iter() {
+			Self::try_state_tree(&key, &tree)?;
+		}
+		Ok(())
+	}
+
+	/// Every parent is the sum of its children, every leaf is either vacant or has an account
+	/// Accounts and their leaves are kept in both the maps, vacant leaves have no stake
+	fn try_state_tree(
+		key: &SumTreeNameType<T>,
+		tree: &SortitionSumTree,
+	) -> Result<(), &'static str> {
+		ensure!(tree.k > 1, "k of the tree is less than two");
+		ensure!(tree.nodes_len > 0, "tree has no root node");
+		let is_leaf = |index: u64| Self::first_child(tree.k, index) >= tree.nodes_len;
+
+		for (index, _) in <TreeNodes<T>>::iter_prefix(key) {
+			ensure!(index < tree.nodes_len, "node is out of the tree");
+		}
+
+		let mut vacant_leaves = BTreeSet::new();
+		for (position, index) in <TreeStack<T>>::iter_prefix(key) {
+			ensure!(position < tree.stack_len, "vacant leaf is out of the stack");
+			ensure!(index != 0 && index < tree.nodes_len, "vacant node is out of the tree");
+			ensure!(is_leaf(index), "vacant node is not a leaf");
+			ensure!(<TreeNodes<T>>::get(key, index) == 0, "vacant leaf has a stake");
+			ensure!(vacant_leaves.insert(index), "leaf is vacant twice");
+		}
+		ensure!(vacant_leaves.len() as u64 == tree.stack_len, "stack length does not match");
+
+		for (index, citizen_id) in <NodeIndexesToIds<T>>::iter_prefix(key) {
+			ensure!(index != 0 && index < tree.nodes_len, "account is out of the tree");
+			ensure!(is_leaf(index), "account is not at a leaf");
+			ensure!(!vacant_leaves.contains(&index), "account is at a vacant leaf");
+			ensure!(
+				<IdsToNodeIndexes<T>>::get(key, &citizen_id) == Some(index),
+				"index of the account does not match"
+			);
+		}
+		for (citizen_id, index) in <IdsToNodeIndexes<T>>::iter_prefix(key) {
+			ensure!(
+				<NodeIndexesToIds<T>>::get(key, index) == Some(citizen_id),
+				"account of the index does not match"
+			);
+		}
+
+		for index in 0..tree.nodes_len {
+			let first_child = Self::first_child(tree.k, index);
+			if first_child < tree.nodes_len {
+				let last_child = first_child.saturating_add(tree.k).min(tree.nodes_len);
+				let children = (first_child..last_child)
+					.try_fold(0u64, |sum, child| sum.checked_add(<TreeNodes<T>>::get(key, child)))
+					.ok_or("sum of the children overflows")?;
+				ensure!(
+					children == <TreeNodes<T>>::get(key, index),
+					"node is not the sum of its children"
+				);
+			} else if index != 0 {
+				ensure!(
+					vacant_leaves.contains(&index) ||
+						<NodeIndexesToIds<T>>::contains_key(key, index),
+					"leaf is neither vacant nor has an account"
+				);
+			}
+		}
+		Ok(())
+	}
+}
@@ -1,29 +1,38 @@
 use frame_support::{pallet_prelude::*};
-use frame_support::sp_std::{vec::Vec, collections::btree_map::BTreeMap};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 type CitizenId = u64;
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, TypeInfo)]
-#[cfg_attr(feature = "std", derive(Debug))]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
 pub enum SumTreeName<AccountId, BlockNumber> {
     ProfileValidation { citizen_address: AccountId, block_number: BlockNumber},
     PositiveExternality {user_address: AccountId, block_number: BlockNumber },
     DepartmentRequiredFund {department_required_fund_id: u64, block_number: BlockNumber},
     ProjectTips { project_id: u64,  block_number: BlockNumber },
+    /// Tree of the jurors staked in a court of `schelling-game-shared`
+    Court { court_id: u64 },
 }
 
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, TypeInfo)]
+/// Sortition sum tree of `k` children per node, its nodes and accounts are kept in separate storage
+/// `nodes_len` is the number of nodes ever appended, `stack_len` the number of vacant leaves
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Debug))]
-pub struct SortitionSumTree<AccountId> {
+pub struct SortitionSumTree {
     pub k: u64,
-    pub stack: Vec<u64>,
-    pub nodes: Vec<u64>,
-    pub ids_to_node_indexes: BTreeMap<AccountId, u64>, // citizen id, node index
-    pub node_indexes_to_ids: BTreeMap<u64, AccountId>, // node index, citizen id
+    pub nodes_len: u64,
+    pub stack_len: u64,
 }
 
-
-
-
+/// Snapshot of a tree, stakes of the accounts at the snapshot are kept when they are changed after it
+/// `stakes_hash` is the hash of all the changes of the stakes of the tree before the snapshot
+#[derive(PartialEq, Eq, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct TreeSnapshot<BlockNumber, Hash> {
+    pub block_number: BlockNumber,
+    pub total_stake: u64,
+    pub stakes_hash: Hash,
+}
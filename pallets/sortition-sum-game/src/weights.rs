@@ -37,6 +37,11 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn do_something() -> Weight;
 	fn cause_error() -> Weight;
+	fn set(n: u32, ) -> Weight;
+	fn draw(n: u32, ) -> Weight;
+	fn draw_many(n: u32, ) -> Weight;
+	fn set_v0(n: u32, ) -> Weight;
+	fn migrate_tree(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_template using the Substrate node and recommended hardware.
@@ -63,6 +68,106 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	/// Proof Skipped: SortitionSumGame SortitionSumTrees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame IdsToNodeIndexes (r:1 w:1)
+	/// Proof Skipped: SortitionSumGame IdsToNodeIndexes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame TreeNodes (r:11 w:11)
+	/// Proof Skipped: SortitionSumGame TreeNodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame NodeIndexesToIds (r:0 w:1)
+	/// Proof Skipped: SortitionSumGame NodeIndexesToIds (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 1000]`.
+	fn set(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196 + n * (1 ±0)`
+		//  Estimated: `29516`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(44_212_000, 29516)
+			// Standard Error: 1_102
+			.saturating_add(Weight::from_parts(9_310, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:0)
+	/// Proof Skipped: SortitionSumGame SortitionSumTrees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame TreeNodes (r:21 w:0)
+	/// Proof Skipped: SortitionSumGame TreeNodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame NodeIndexesToIds (r:1 w:0)
+	/// Proof Skipped: SortitionSumGame NodeIndexesToIds (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 1000]`.
+	fn draw(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196 + n * (1 ±0)`
+		//  Estimated: `55766`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(56_804_000, 55766)
+			// Standard Error: 1_348
+			.saturating_add(Weight::from_parts(11_402, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+	}
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	/// Proof Skipped: SortitionSumGame SortitionSumTrees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame TreeNodes (r:1999 w:1099)
+	/// Proof Skipped: SortitionSumGame TreeNodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame NodeIndexesToIds (r:100 w:100)
+	/// Proof Skipped: SortitionSumGame NodeIndexesToIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame IdsToNodeIndexes (r:0 w:100)
+	/// Proof Skipped: SortitionSumGame IdsToNodeIndexes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame TreeStack (r:0 w:100)
+	/// Proof Skipped: SortitionSumGame TreeStack (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 100]`.
+	fn draw_many(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `108231`
+		//  Estimated: `5061756 + n * (2541 ±0)`
+		// Minimum execution time: 2_412_000_000 picoseconds.
+		Weight::from_parts(2_398_516_000, 5061756)
+			// Standard Error: 31_877
+			.saturating_add(Weight::from_parts(96_402_315, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2000_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((13_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2541).saturating_mul(n.into()))
+	}
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	/// Proof Skipped: SortitionSumGame SortitionSumTrees (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 1000]`.
+	fn set_v0(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `212 + n * (61 ±0)`
+		//  Estimated: `3677 + n * (61 ±0)`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(12_046_000, 3677)
+			// Standard Error: 9_627
+			.saturating_add(Weight::from_parts(1_873_591, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(Weight::from_parts(0, 61).saturating_mul(n.into()))
+	}
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	/// Proof Skipped: SortitionSumGame SortitionSumTrees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame TreeNodes (r:0 w:1000)
+	/// Proof Skipped: SortitionSumGame TreeNodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame IdsToNodeIndexes (r:0 w:1000)
+	/// Proof Skipped: SortitionSumGame IdsToNodeIndexes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame NodeIndexesToIds (r:1000 w:1000)
+	/// Proof Skipped: SortitionSumGame NodeIndexesToIds (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 1000]`.
+	fn migrate_tree(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `212 + n * (61 ±0)`
+		//  Estimated: `3677 + n * (2536 ±0)`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(20_514_000, 3677)
+			// Standard Error: 14_210
+			.saturating_add(Weight::from_parts(9_642_108, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2536).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -88,4 +193,104 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	/// Proof Skipped: SortitionSumGame SortitionSumTrees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame IdsToNodeIndexes (r:1 w:1)
+	/// Proof Skipped: SortitionSumGame IdsToNodeIndexes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame TreeNodes (r:11 w:11)
+	/// Proof Skipped: SortitionSumGame TreeNodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame NodeIndexesToIds (r:0 w:1)
+	/// Proof Skipped: SortitionSumGame NodeIndexesToIds (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 1000]`.
+	fn set(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196 + n * (1 ±0)`
+		//  Estimated: `29516`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(44_212_000, 29516)
+			// Standard Error: 1_102
+			.saturating_add(Weight::from_parts(9_310, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:0)
+	/// Proof Skipped: SortitionSumGame SortitionSumTrees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame TreeNodes (r:21 w:0)
+	/// Proof Skipped: SortitionSumGame TreeNodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame NodeIndexesToIds (r:1 w:0)
+	/// Proof Skipped: SortitionSumGame NodeIndexesToIds (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 1000]`.
+	fn draw(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `196 + n * (1 ±0)`
+		//  Estimated: `55766`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(56_804_000, 55766)
+			// Standard Error: 1_348
+			.saturating_add(Weight::from_parts(11_402, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(23_u64))
+	}
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	/// Proof Skipped: SortitionSumGame SortitionSumTrees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame TreeNodes (r:1999 w:1099)
+	/// Proof Skipped: SortitionSumGame TreeNodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame NodeIndexesToIds (r:100 w:100)
+	/// Proof Skipped: SortitionSumGame NodeIndexesToIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame IdsToNodeIndexes (r:0 w:100)
+	/// Proof Skipped: SortitionSumGame IdsToNodeIndexes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame TreeStack (r:0 w:100)
+	/// Proof Skipped: SortitionSumGame TreeStack (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 100]`.
+	fn draw_many(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `108231`
+		//  Estimated: `5061756 + n * (2541 ±0)`
+		// Minimum execution time: 2_412_000_000 picoseconds.
+		Weight::from_parts(2_398_516_000, 5061756)
+			// Standard Error: 31_877
+			.saturating_add(Weight::from_parts(96_402_315, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2000_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((13_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2541).saturating_mul(n.into()))
+	}
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	/// Proof Skipped: SortitionSumGame SortitionSumTrees (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 1000]`.
+	fn set_v0(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `212 + n * (61 ±0)`
+		//  Estimated: `3677 + n * (61 ±0)`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(12_046_000, 3677)
+			// Standard Error: 9_627
+			.saturating_add(Weight::from_parts(1_873_591, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(Weight::from_parts(0, 61).saturating_mul(n.into()))
+	}
+	/// Storage: SortitionSumGame SortitionSumTrees (r:1 w:1)
+	/// Proof Skipped: SortitionSumGame SortitionSumTrees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame TreeNodes (r:0 w:1000)
+	/// Proof Skipped: SortitionSumGame TreeNodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame IdsToNodeIndexes (r:0 w:1000)
+	/// Proof Skipped: SortitionSumGame IdsToNodeIndexes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SortitionSumGame NodeIndexesToIds (r:1000 w:1000)
+	/// Proof Skipped: SortitionSumGame NodeIndexesToIds (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 1000]`.
+	fn migrate_tree(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `212 + n * (61 ±0)`
+		//  Estimated: `3677 + n * (2536 ±0)`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(20_514_000, 3677)
+			// Standard Error: 14_210
+			.saturating_add(Weight::from_parts(9_642_108, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2536).saturating_mul(n.into()))
+	}
 }
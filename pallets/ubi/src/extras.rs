@@ -9,3 +9,13 @@ impl<T: Config> Pallet<T> {
 		input.saturated_into::<BlockNumberOf<T>>()
 	}
 }
+
+impl<T: Config> OnCitizenRecovered<T::AccountId> for Pallet<T> {
+	/// Recovered account can not get the ubi of the period the lost account got
+	fn on_citizen_recovered(old_address: T::AccountId, new_address: T::AccountId) {
+		let ubi_block_number = <CitizenUbiBlock<T>>::take(&old_address);
+		if ubi_block_number > <CitizenUbiBlock<T>>::get(&new_address) {
+			<CitizenUbiBlock<T>>::insert(&new_address, ubi_block_number);
+		}
+	}
+}
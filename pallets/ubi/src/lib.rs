@@ -22,7 +22,7 @@ use frame_support::sp_runtime::SaturatedConversion;
 use frame_support::sp_std::prelude::*;
 use frame_support::traits::{Currency, OnUnbalanced, ReservableCurrency};
 
-use shared_storage_link::SharedStorageLink;
+use shared_storage_link::{OnCitizenRecovered, SharedStorageLink};
 // use scale_info::prelude::format;
 pub type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
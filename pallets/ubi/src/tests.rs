@@ -1,5 +1,6 @@
 use crate::{mock::*, Error, Event};
 use frame_support::{assert_noop, assert_ok};
+use shared_storage_link::OnCitizenRecovered;
 
 #[test]
 fn it_works_for_default_value() {
@@ -16,3 +17,15 @@ fn correct_error_for_none_value() {
 		
 	});
 }
+
+#[test]
+fn recovered_account_keeps_the_ubi_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1296010);
+		assert_ok!(TemplateModule::fun_ubi(RuntimeOrigin::signed(1)));
+		assert_eq!(TemplateModule::citizen_got_ubi_block_number(1), 1296000);
+		TemplateModule::on_citizen_recovered(1, 3);
+		assert_eq!(TemplateModule::citizen_got_ubi_block_number(1), 0);
+		assert_eq!(TemplateModule::citizen_got_ubi_block_number(3), 1296000);
+	});
+}
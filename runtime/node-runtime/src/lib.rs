@@ -48,6 +48,9 @@ pub use sp_runtime::{Perbill, Permill};
 /// Import the template pallet.
 pub use pallet_template;
 
+use schelling_game_shared::types::{CommitVersion, Evidence, EvidenceId, JurorRecord};
+use sortition_sum_game::types::SumTreeName;
+
 /// An index to a block.
 pub type BlockNumber = u32;
 
@@ -105,7 +108,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 102,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -282,14 +285,62 @@ impl sortition_sum_game::Config for Runtime {
 	type WeightInfo = sortition_sum_game::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const NonCommitSlashFraction: Perbill = Perbill::from_percent(50);
+	pub const NonRevealSlashFraction: Perbill = Perbill::from_percent(50);
+	pub const MaxPeriodChangesPerBlock: u32 = 10;
+	pub const MaxVoteRemovalsPerBlock: u32 = 500;
+	pub const VoteCommitVersion: CommitVersion = CommitVersion::V1;
+	pub const EvidenceDeposit: Balance = 100 * EXISTENTIAL_DEPOSIT;
+	pub const MaxEvidencePerGame: u32 = 100;
+	pub const MaxDelegatorsPerJuror: u32 = 100;
+	pub const MinDecidedVotes: u64 = 5;
+	pub const MinCoherenceRate: Perbill = Perbill::from_percent(50);
+	pub const BonusPerCoherentVote: Perbill = Perbill::from_percent(2);
+	pub const MaxReputationBonus: Perbill = Perbill::from_percent(50);
+}
+
 impl schelling_game_shared::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = schelling_game_shared::weights::SubstrateWeight<Runtime>;
 	type Currency = Balances;
 	type RandomnessSource = RandomnessCollectiveFlip;
+	type DrawRandomness = schelling_game_shared::JurorBeacon<Runtime>;
+	type ReputationMultiplier = schelling_game_shared::CoherenceMultiplier<
+		MinDecidedVotes,
+		MinCoherenceRate,
+		BonusPerCoherentVote,
+		MaxReputationBonus,
+	>;
+	type OnGameVoided = (
+		ProfileValidation,
+		DepartmentFunding,
+		ProjectTips,
+		PositiveExternalityValidation,
+	);
+	type OnGameResolved = (
+		ProfileValidation,
+		DepartmentFunding,
+		ProjectTips,
+		PositiveExternalityValidation,
+	);
 	type Slash = ();
-	type Reward = ();
 	type SortitionSumGameSource = SortitionSumGame;
+	type NonCommitSlashFraction = NonCommitSlashFraction;
+	type NonRevealSlashFraction = NonRevealSlashFraction;
+	type MaxPeriodChangesPerBlock = MaxPeriodChangesPerBlock;
+	type MaxVoteRemovalsPerBlock = MaxVoteRemovalsPerBlock;
+	type PhaseDataOrigin = frame_system::EnsureRoot<AccountId>;
+	type VoteCommitVersion = VoteCommitVersion;
+	type EvidenceDeposit = EvidenceDeposit;
+	type MaxEvidencePerGame = MaxEvidencePerGame;
+	type MaxDelegatorsPerJuror = MaxDelegatorsPerJuror;
+}
+
+parameter_types! {
+	pub const RecoveryDeposit: Balance = 1000 * EXISTENTIAL_DEPOSIT;
+	pub const RecoveryDelay: BlockNumber = 7 * DAYS;
+	pub const MaxRecoveryFriends: u32 = 9;
 }
 
 impl profile_validation::Config for Runtime {
@@ -299,6 +350,11 @@ impl profile_validation::Config for Runtime {
 	type SchellingGameSharedSource = SchellingGameShared;
 	type Slash = ();
 	type Reward = ();
+	type SharedStorageSource = SharedStorage;
+	type OnCitizenRecovered = Ubi;
+	type RecoveryDeposit = RecoveryDeposit;
+	type RecoveryDelay = RecoveryDelay;
+	type MaxRecoveryFriends = MaxRecoveryFriends;
 }
 
 impl shared_storage::Config for Runtime {
@@ -330,6 +386,15 @@ impl project_tips::Config for Runtime {
 	type SchellingGameSharedSource = SchellingGameShared;
 }
 
+impl pallet_ubi::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_ubi::weights::SubstrateWeight<Runtime>;
+	type SharedStorageSource = SharedStorage;
+	type Currency = Balances;
+	type Reward = ();
+	type Slash = ();
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub struct Runtime
@@ -355,6 +420,7 @@ construct_runtime!(
 		PositiveExternalityValidation: positive_externality_validation,
 		DepartmentFunding: department_funding,
 		ProjectTips: project_tips,
+		Ubi: pallet_ubi,
 	}
 );
 
@@ -381,6 +447,13 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Migrations to run on the runtime upgrade.
+pub type Migrations = (
+	sortition_sum_game::migrations::v1::MigrateToV1<Runtime>,
+	profile_validation::migrations::v1::MigrateToV1<Runtime>,
+	positive_externality_validation::migrations::v1::MigrateToV1<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -388,6 +461,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -664,6 +738,58 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl schelling_game_shared_runtime_api::SchellingGameSharedApi<Block, AccountId, BlockNumber, Balance> for Runtime {
+		fn get_juror_record(who: AccountId) -> JurorRecord {
+			SchellingGameShared::juror_record(who)
+		}
+
+		fn get_game_evidence(key: SumTreeName<AccountId, BlockNumber>, offset: u64, limit: u16) -> Vec<(EvidenceId, Evidence<AccountId, Balance, BlockNumber>)> {
+			SchellingGameShared::get_game_evidence(key, offset, limit)
+		}
+
+		fn get_evidence_replies(key: SumTreeName<AccountId, BlockNumber>, evidence_id: EvidenceId, offset: u64, limit: u16) -> Vec<(EvidenceId, Evidence<AccountId, Balance, BlockNumber>)> {
+			SchellingGameShared::get_evidence_replies(key, evidence_id, offset, limit)
+		}
+	}
+
+	impl sortition_sum_game_runtime_api::SortitionSumGameApi<Block, AccountId, BlockNumber, Hash> for Runtime {
+		fn get_jurors(key: SumTreeName<AccountId, BlockNumber>, offset: u64, limit: u16) -> Vec<(AccountId, u64)> {
+			SortitionSumGame::get_jurors(key, offset, limit)
+		}
+
+		fn get_jurors_count(key: SumTreeName<AccountId, BlockNumber>) -> u64 {
+			SortitionSumGame::get_jurors_count(key)
+		}
+
+		fn total_stake(key: SumTreeName<AccountId, BlockNumber>) -> Option<u64> {
+			SortitionSumGame::get_total_stake(key)
+		}
+
+		fn stake_of(key: SumTreeName<AccountId, BlockNumber>, who: AccountId) -> Option<u64> {
+			SortitionSumGame::get_stake_of(key, who)
+		}
+
+		fn draw_probability(key: SumTreeName<AccountId, BlockNumber>, who: AccountId) -> Option<Perbill> {
+			SortitionSumGame::get_draw_probability(key, who)
+		}
+
+		fn get_snapshot_count(key: SumTreeName<AccountId, BlockNumber>) -> u32 {
+			SortitionSumGame::get_snapshot_count(key)
+		}
+
+		fn get_snapshot_total_stake(key: SumTreeName<AccountId, BlockNumber>, index: u32) -> Option<u64> {
+			SortitionSumGame::get_snapshot_total_stake(key, index)
+		}
+
+		fn get_snapshot_stake_of(key: SumTreeName<AccountId, BlockNumber>, index: u32, who: AccountId) -> Option<u64> {
+			SortitionSumGame::get_snapshot_stake_of(key, index, who)
+		}
+
+		fn get_snapshot_hash(key: SumTreeName<AccountId, BlockNumber>, index: u32) -> Option<Hash> {
+			SortitionSumGame::get_snapshot_hash(key, index)
+		}
+	}
+
 }
 
 #[cfg(test)]
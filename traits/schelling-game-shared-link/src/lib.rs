@@ -12,6 +12,7 @@ pub trait SchellingGameSharedLink {
 	type Period;
 	type PhaseData;
 	type WinningDecision;
+	type Content;
 
 	fn create_phase_data(
 		block_length: u64,
@@ -38,8 +39,10 @@ pub trait SchellingGameSharedLink {
 
 	fn set_to_evidence_period_link(
 		key: Self::SumTreeName,
+		game_type: Self::SchellingGameType,
 		now: Self::BlockNumber,
 	) -> DispatchResult;
+	fn get_phase_data_of_game_link(key: Self::SumTreeName) -> Self::PhaseData;
 	fn create_tree_helper_link(key: Self::SumTreeName, k: u64) -> DispatchResult;
 
 	fn set_to_staking_period_link(
@@ -56,6 +59,7 @@ pub trait SchellingGameSharedLink {
 
 	fn set_to_staking_period_pe_link(
 		key: Self::SumTreeName,
+		game_type: Self::SchellingGameType,
 		now: Self::BlockNumber,
 	) -> DispatchResult;
 	fn change_period_link(
@@ -136,6 +140,27 @@ pub trait SchellingGameSharedLink {
 
 	fn get_mean_value_link(key: Self::SumTreeName) -> i64;
 
+	fn set_score_game_link(key: Self::SumTreeName) -> DispatchResult;
+
+	fn set_number_of_choices_link(key: Self::SumTreeName, number_of_choices: u32)
+		-> DispatchResult;
+	fn commit_vote_for_multi_choice_link(
+		key: Self::SumTreeName,
+		who: Self::AccountId,
+		vote_commit: [u8; 32],
+	) -> DispatchResult;
+	fn reveal_vote_multi_choice_link(
+		key: Self::SumTreeName,
+		who: Self::AccountId,
+		choice: u32,
+		salt: Vec<u8>,
+	) -> DispatchResult;
+	fn get_incentives_multi_choice_link(
+		key: Self::SumTreeName,
+		phase_data: Self::PhaseData,
+	) -> DispatchResult;
+	fn get_winning_choice_link(key: Self::SumTreeName) -> Option<u32>;
+
 	fn get_all_incentives_two_choice_helper(
 		key: Self::SumTreeName,
 		phase_data: Self::PhaseData,
@@ -144,4 +169,59 @@ pub trait SchellingGameSharedLink {
 	fn get_drawn_jurors(key: Self::SumTreeName) -> Vec<(Self::AccountId, u64)>;
 
 	fn get_winning_decision_value_link(key: Self::SumTreeName) -> Self::WinningDecision;
+
+	fn appeal_helper_link(
+		key: Self::SumTreeName,
+		phase_data: Self::PhaseData,
+		who: Self::AccountId,
+		now: Self::BlockNumber,
+	) -> DispatchResult;
+
+	fn get_appeal_period_end_block_helper_link(
+		key: Self::SumTreeName,
+		phase_data: Self::PhaseData,
+		now: Self::BlockNumber,
+	) -> Option<u32>;
+
+	fn get_appeal_fee_link(key: Self::SumTreeName, phase_data: Self::PhaseData) -> Self::Balance;
+
+	fn set_court_of_game_link(key: Self::SumTreeName, court_id: u64) -> DispatchResult;
+
+	fn submit_evidence_link(
+		key: Self::SumTreeName,
+		who: Self::AccountId,
+		content: Self::Content,
+		reply_to: Option<u64>,
+	) -> DispatchResult;
+
+	fn get_game_evidence_ids_link(key: Self::SumTreeName, offset: u64, limit: u16) -> Vec<u64>;
+}
+
+/// Called when a game is voided because too few jurors were drawn before the drawing deadline
+/// Stakes of the jurors are returned later in `on_idle` of the shared pallet
+/// The consumer pallet returns its own deposits of the game
+pub trait OnGameVoided<SumTreeName> {
+	fn on_game_voided(key: SumTreeName);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(5)]
+impl<SumTreeName: Clone> OnGameVoided<SumTreeName> for Tuple {
+	fn on_game_voided(key: SumTreeName) {
+		for_tuples!( #( Tuple::on_game_voided(key.clone()); )* );
+	}
+}
+
+/// Called when a game reaches `Period::Execution` with the outcome of the game
+/// `Outcome` is the winning decision, the score or the choice of the game
+pub trait OnGameResolved<SumTreeName, SchellingGameType, Outcome> {
+	fn on_game_resolved(key: SumTreeName, game_type: SchellingGameType, outcome: Outcome);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(5)]
+impl<SumTreeName: Clone, SchellingGameType: Clone, Outcome: Clone>
+	OnGameResolved<SumTreeName, SchellingGameType, Outcome> for Tuple
+{
+	fn on_game_resolved(key: SumTreeName, game_type: SchellingGameType, outcome: Outcome) {
+		for_tuples!( #( Tuple::on_game_resolved(key.clone(), game_type.clone(), outcome.clone()); )* );
+	}
 }
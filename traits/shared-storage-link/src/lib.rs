@@ -9,5 +9,22 @@ pub trait SharedStorageLink {
 
 	fn get_approved_citizen_count_link() -> u64;
 	fn set_positive_externality_link(address: Self::AccountId, score: i64)-> DispatchResult;
+	/// Approval and positive externality score of the citizen move to the recovered account
+	fn move_citizen_link(
+		old_address: Self::AccountId,
+		new_address: Self::AccountId,
+	) -> DispatchResult;
 	
 }
+
+/// Storage of the citizen kept by account in other pallets moves to the recovered account
+pub trait OnCitizenRecovered<AccountId> {
+	fn on_citizen_recovered(old_address: AccountId, new_address: AccountId);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(5)]
+impl<AccountId: Clone> OnCitizenRecovered<AccountId> for Tuple {
+	fn on_citizen_recovered(old_address: AccountId, new_address: AccountId) {
+		for_tuples!( #( Tuple::on_citizen_recovered(old_address.clone(), new_address.clone()); )* );
+	}
+}
@@ -1,5 +1,5 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+use frame_support::{dispatch::DispatchResult, pallet_prelude::*, sp_std::vec::Vec};
 
 pub trait SortitionSumGameLink {
 	type SumTreeName;
@@ -11,5 +11,23 @@ pub trait SortitionSumGameLink {
 		citizen_id: Self::AccountId,
 	) -> Result<Option<u64>, DispatchError>;
 	fn draw_link(key: Self::SumTreeName, draw_number: u64) -> Result<Self::AccountId, DispatchError>;
-	fn remove_tree_link(key: Self::SumTreeName) -> DispatchResult;
+	/// Draw at most `n` distinct accounts with their stakes in one pass, the drawn accounts are removed from the tree
+	fn draw_many_link(
+		key: Self::SumTreeName,
+		seeds: Vec<u64>,
+		n: u32,
+	) -> Result<Vec<(Self::AccountId, u64)>, DispatchError>;
+	fn total_stake_link(key: Self::SumTreeName) -> Result<u64, DispatchError>;
+	fn stakes_link(key: Self::SumTreeName) -> Result<Vec<(Self::AccountId, u64)>, DispatchError>;
+	/// Remove at most `limit` accounts and nodes of the tree, returns the removed items and whether the tree is removed
+	fn remove_tree_link(key: Self::SumTreeName, limit: u32) -> (u32, bool);
+	/// Remove at most `limit` accounts from the tree, returns them with their stake and whether the tree is empty
+	fn drain_stakes_link(
+		key: Self::SumTreeName,
+		limit: u32,
+	) -> Result<(Vec<(Self::AccountId, u64)>, bool), DispatchError>;
+	/// Snapshot the stakes of the tree, returns the index of the snapshot
+	fn snapshot_tree_link(key: Self::SumTreeName) -> Result<u32, DispatchError>;
+	/// Remove the stakes of at most `limit` accounts of the snapshots, returns the removed stakes and whether all are removed
+	fn remove_snapshots_link(key: Self::SumTreeName, limit: u32) -> (u32, bool);
 }